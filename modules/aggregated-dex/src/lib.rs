@@ -423,6 +423,10 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for DexSwap<T> {
 		T::DEX::swap_with_specific_path(who, swap_path, limit)
 	}
 
+	fn get_swap_amount_by_path(swap_path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		T::DEX::get_swap_amount(swap_path, limit)
+	}
+
 	// DexSwap do not support swap by aggregated path.
 	fn swap_by_aggregated_path(
 		_who: &T::AccountId,
@@ -624,6 +628,10 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for EitherDexOrTaigaSwap
 		DexSwap::<T>::swap_by_path(who, swap_path, limit)
 	}
 
+	fn get_swap_amount_by_path(swap_path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		DexSwap::<T>::get_swap_amount_by_path(swap_path, limit)
+	}
+
 	// Both DexSwap and TaigaSwap do not support swap by aggregated path.
 	fn swap_by_aggregated_path(
 		_who: &T::AccountId,
@@ -736,6 +744,13 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for AggregatedSwap<T> {
 	) -> Result<(Balance, Balance), DispatchError> {
 		Pallet::<T>::do_aggregated_swap(who, swap_path, limit)
 	}
+
+	fn get_swap_amount_by_aggregated_path(
+		swap_path: &[SwapPath],
+		limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		Pallet::<T>::get_aggregated_swap_amount(swap_path, limit)
+	}
 }
 
 pub struct RebasedStableAssetErrorConvertor<T>(PhantomData<T>);
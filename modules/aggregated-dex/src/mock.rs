@@ -114,6 +114,7 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Admin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type AssetStatusProvider = ();
 }
 
 pub struct EnsurePoolAssetId;
@@ -19,6 +19,16 @@
 //! # Asset Registry Module
 //!
 //! Local and foreign assets management. The foreign assets can be updated without runtime upgrade.
+//!
+//! Registered assets are `Active` by default. Governance can move an asset to `Deprecated` to
+//! delist it: it can't be listed on the DEX or received via XCM any more, but holders can still
+//! transfer it and send it out. A `Frozen` asset can't be transferred at all. The status is
+//! exposed through `AssetStatusProvider` and is enforced by the consumers.
+//!
+//! Anyone can register a foreign asset by reserving `ForeignAssetRegistrationDeposit`. Such an
+//! asset is unverified: it can't be used to pay fees or as collateral until governance verifies
+//! it, which refunds the deposit. Governance can instead reject it, which slashes the deposit and
+//! removes the asset.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -27,13 +37,15 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, EnsureOrigin},
+	traits::{Currency, EnsureOrigin, ReservableCurrency},
 };
 use frame_system::pallet_prelude::*;
-use module_support::{AssetIdMapping, BuyWeightRate, EVMBridge, Erc20InfoMapping, InvokeContext, Ratio};
+use module_support::{
+	AssetIdMapping, AssetStatusProvider, BuyWeightRate, EVMBridge, Erc20InfoMapping, InvokeContext, Ratio,
+};
 use primitives::{
 	currency::{
-		AssetIds, AssetMetadata, CurrencyIdType, DexShare, DexShareType, Erc20Id, ForeignAssetId, Lease,
+		AssetIds, AssetMetadata, AssetStatus, CurrencyIdType, DexShare, DexShareType, Erc20Id, ForeignAssetId, Lease,
 		StableAssetPoolId, TokenInfo,
 	},
 	evm::{
@@ -44,7 +56,10 @@ use primitives::{
 	CurrencyId,
 };
 use scale_info::prelude::format;
-use sp_runtime::{traits::One, ArithmeticError, FixedPointNumber, FixedU128};
+use sp_runtime::{
+	traits::{One, Zero},
+	ArithmeticError, FixedPointNumber, FixedU128,
+};
 use sp_std::{boxed::Box, vec::Vec};
 
 use xcm::{v3::prelude::*, VersionedMultiLocation};
@@ -69,7 +84,7 @@ pub mod module {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Currency type for withdraw and balance storage.
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// The Currency ID for the staking currency
 		#[pallet::constant]
@@ -81,6 +96,11 @@ pub mod module {
 		/// Required origin for registering asset.
 		type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The deposit reserved to register a foreign asset without `RegisterOrigin`, refunded
+		/// once the asset is verified.
+		#[pallet::constant]
+		type ForeignAssetRegistrationDeposit: Get<BalanceOf<Self>>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -96,6 +116,10 @@ pub mod module {
 		AssetIdNotExists,
 		/// AssetId exists
 		AssetIdExisted,
+		/// The foreign asset is already verified
+		AlreadyVerified,
+		/// The minimal balance of the asset is zero
+		ZeroMinimalBalance,
 	}
 
 	#[pallet::event]
@@ -123,6 +147,35 @@ pub mod module {
 			asset_id: AssetIds,
 			metadata: AssetMetadata<BalanceOf<T>>,
 		},
+		/// The status of the asset changed.
+		AssetStatusChanged { asset_id: AssetIds, status: AssetStatus },
+		/// The foreign asset registered without governance, pending verification.
+		UnverifiedForeignAssetRegistered {
+			asset_id: ForeignAssetId,
+			asset_address: MultiLocation,
+			evm_address: EvmAddress,
+			metadata: AssetMetadata<BalanceOf<T>>,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		/// The foreign asset verified, the deposit is refunded.
+		ForeignAssetVerified {
+			asset_id: ForeignAssetId,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		/// The foreign asset rejected and removed, the deposit is slashed.
+		ForeignAssetRejected {
+			asset_id: ForeignAssetId,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		/// The foreign asset migrated to a new location.
+		ForeignAssetMigrated {
+			asset_id: ForeignAssetId,
+			old_location: MultiLocation,
+			new_location: MultiLocation,
+		},
 	}
 
 	/// Next available Foreign AssetId ID.
@@ -168,6 +221,22 @@ pub mod module {
 	pub type AssetMetadatas<T: Config> =
 		StorageMap<_, Twox64Concat, AssetIds, AssetMetadata<BalanceOf<T>>, OptionQuery>;
 
+	/// The lifecycle status of the assets, assets without an entry are `Active`.
+	///
+	/// AssetStatuses: map AssetIds => AssetStatus
+	#[pallet::storage]
+	#[pallet::getter(fn asset_statuses)]
+	pub type AssetStatuses<T: Config> = StorageMap<_, Twox64Concat, AssetIds, AssetStatus, ValueQuery>;
+
+	/// The foreign assets registered without governance and the deposit reserved from the
+	/// depositor.
+	///
+	/// UnverifiedForeignAssets: map ForeignAssetId => Option<(AccountId, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn unverified_foreign_assets)]
+	pub type UnverifiedForeignAssets<T: Config> =
+		StorageMap<_, Twox64Concat, ForeignAssetId, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
@@ -344,6 +413,132 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Set the lifecycle status of a registered asset.
+		///
+		/// - `asset_id`: the registered asset.
+		/// - `status`: the new status of the asset.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_asset_status())]
+		pub fn set_asset_status(origin: OriginFor<T>, asset_id: AssetIds, status: AssetStatus) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			Self::do_set_asset_status(&asset_id, status)?;
+
+			Self::deposit_event(Event::<T>::AssetStatusChanged { asset_id, status });
+			Ok(())
+		}
+
+		/// Migrate a foreign asset to a new location, keeping its `ForeignAssetId`, metadata
+		/// and status.
+		///
+		/// - `foreign_asset_id`: the registered foreign asset.
+		/// - `location`: the new location of the asset.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::migrate_foreign_asset_location())]
+		pub fn migrate_foreign_asset_location(
+			origin: OriginFor<T>,
+			foreign_asset_id: ForeignAssetId,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			let old_location = Self::do_migrate_foreign_asset_location(foreign_asset_id, &location)?;
+
+			Self::deposit_event(Event::<T>::ForeignAssetMigrated {
+				asset_id: foreign_asset_id,
+				old_location,
+				new_location: location,
+			});
+			Ok(())
+		}
+
+		/// Register a foreign asset without governance by reserving
+		/// `ForeignAssetRegistrationDeposit`. The asset is unverified until `verify_foreign_asset`.
+		///
+		/// - `location`: the location of the foreign asset.
+		/// - `metadata`: the metadata of the foreign asset.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::register_foreign_asset().saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+		pub fn register_unverified_foreign_asset(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!metadata.minimal_balance.is_zero(), Error::<T>::ZeroMinimalBalance);
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			let deposit = T::ForeignAssetRegistrationDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			let foreign_asset_id = Self::do_register_foreign_asset(&location, &metadata)?;
+			UnverifiedForeignAssets::<T>::insert(foreign_asset_id, (who.clone(), deposit));
+
+			// the ERC20 address is resolvable by `Erc20InfoMapping` once the metadata is registered.
+			let evm_address = EvmErc20InfoMapping::<T>::encode_evm_address(CurrencyId::ForeignAsset(foreign_asset_id))
+				.ok_or(Error::<T>::AssetIdNotExists)?;
+
+			Self::deposit_event(Event::<T>::UnverifiedForeignAssetRegistered {
+				asset_id: foreign_asset_id,
+				asset_address: location,
+				evm_address,
+				metadata: *metadata,
+				depositor: who,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Verify a foreign asset registered without governance and refund the deposit.
+		///
+		/// - `foreign_asset_id`: the unverified foreign asset.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::verify_foreign_asset())]
+		pub fn verify_foreign_asset(origin: OriginFor<T>, foreign_asset_id: ForeignAssetId) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				ForeignAssetLocations::<T>::contains_key(foreign_asset_id),
+				Error::<T>::AssetIdNotExists
+			);
+			let (depositor, deposit) =
+				UnverifiedForeignAssets::<T>::take(foreign_asset_id).ok_or(Error::<T>::AlreadyVerified)?;
+			T::Currency::unreserve(&depositor, deposit);
+
+			Self::deposit_event(Event::<T>::ForeignAssetVerified {
+				asset_id: foreign_asset_id,
+				depositor,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Reject a foreign asset registered without governance, the deposit is slashed and the
+		/// location, metadata and status of the asset are removed.
+		///
+		/// - `foreign_asset_id`: the unverified foreign asset.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::reject_foreign_asset())]
+		pub fn reject_foreign_asset(origin: OriginFor<T>, foreign_asset_id: ForeignAssetId) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				ForeignAssetLocations::<T>::contains_key(foreign_asset_id),
+				Error::<T>::AssetIdNotExists
+			);
+			let (depositor, deposit) =
+				UnverifiedForeignAssets::<T>::take(foreign_asset_id).ok_or(Error::<T>::AlreadyVerified)?;
+			Self::do_remove_foreign_asset(foreign_asset_id)?;
+			let _ = T::Currency::slash_reserved(&depositor, deposit);
+
+			Self::deposit_event(Event::<T>::ForeignAssetRejected {
+				asset_id: foreign_asset_id,
+				depositor,
+				deposit,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -422,6 +617,32 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	fn do_migrate_foreign_asset_location(
+		foreign_asset_id: ForeignAssetId,
+		location: &MultiLocation,
+	) -> Result<MultiLocation, DispatchError> {
+		ForeignAssetLocations::<T>::try_mutate(foreign_asset_id, |maybe_location| -> Result<_, DispatchError> {
+			let old_location = maybe_location.as_mut().ok_or(Error::<T>::AssetIdNotExists)?;
+
+			LocationToCurrencyIds::<T>::try_mutate(location, |maybe_currency_ids| -> DispatchResult {
+				ensure!(maybe_currency_ids.is_none(), Error::<T>::MultiLocationExisted);
+				*maybe_currency_ids = Some(CurrencyId::ForeignAsset(foreign_asset_id));
+				Ok(())
+			})?;
+			LocationToCurrencyIds::<T>::remove(*old_location);
+
+			Ok(sp_std::mem::replace(old_location, *location))
+		})
+	}
+
+	fn do_remove_foreign_asset(foreign_asset_id: ForeignAssetId) -> DispatchResult {
+		let location = ForeignAssetLocations::<T>::take(foreign_asset_id).ok_or(Error::<T>::AssetIdNotExists)?;
+		LocationToCurrencyIds::<T>::remove(location);
+		AssetMetadatas::<T>::remove(AssetIds::ForeignAssetId(foreign_asset_id));
+		AssetStatuses::<T>::remove(AssetIds::ForeignAssetId(foreign_asset_id));
+		Ok(())
+	}
+
 	fn do_register_stable_asset(metadata: &AssetMetadata<BalanceOf<T>>) -> Result<StableAssetPoolId, DispatchError> {
 		let stable_asset_id = Self::get_next_stable_asset_id()?;
 		AssetMetadatas::<T>::try_mutate(
@@ -522,6 +743,30 @@ impl<T: Config> Pallet<T> {
 			},
 		)
 	}
+
+	fn do_set_asset_status(asset_id: &AssetIds, status: AssetStatus) -> DispatchResult {
+		ensure!(
+			AssetMetadatas::<T>::contains_key(asset_id),
+			Error::<T>::AssetIdNotExists
+		);
+
+		if status == AssetStatus::Active {
+			AssetStatuses::<T>::remove(asset_id);
+		} else {
+			AssetStatuses::<T>::insert(asset_id, status);
+		}
+		Ok(())
+	}
+
+	fn dex_share_asset_ids(dex_share: DexShare) -> AssetIds {
+		match dex_share {
+			DexShare::Token(symbol) => AssetIds::NativeAssetId(CurrencyId::Token(symbol)),
+			DexShare::Erc20(address) => AssetIds::Erc20(address),
+			DexShare::LiquidCrowdloan(lease) => AssetIds::NativeAssetId(CurrencyId::LiquidCrowdloan(lease)),
+			DexShare::ForeignAsset(foreign_asset_id) => AssetIds::ForeignAssetId(foreign_asset_id),
+			DexShare::StableAssetPoolToken(stable_asset_id) => AssetIds::StableAssetId(stable_asset_id),
+		}
+	}
 }
 
 pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
@@ -540,6 +785,44 @@ impl<T: Config> AssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata<Bala
 	}
 }
 
+impl<T: Config> AssetStatusProvider<CurrencyId> for AssetIdMaps<T> {
+	// The status of the LP token is the most restrictive status of its underlying assets.
+	fn asset_status(currency_id: CurrencyId) -> AssetStatus {
+		match currency_id {
+			CurrencyId::Token(_) | CurrencyId::LiquidCrowdloan(_) => {
+				Pallet::<T>::asset_statuses(AssetIds::NativeAssetId(currency_id))
+			}
+			CurrencyId::Erc20(address) => Pallet::<T>::asset_statuses(AssetIds::Erc20(address)),
+			CurrencyId::StableAssetPoolToken(stable_asset_id) => {
+				Pallet::<T>::asset_statuses(AssetIds::StableAssetId(stable_asset_id))
+			}
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				Pallet::<T>::asset_statuses(AssetIds::ForeignAssetId(foreign_asset_id))
+			}
+			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
+				Pallet::<T>::asset_statuses(Pallet::<T>::dex_share_asset_ids(dex_share_0)).max(
+					Pallet::<T>::asset_statuses(Pallet::<T>::dex_share_asset_ids(dex_share_1)),
+				)
+			}
+		}
+	}
+
+	// Only foreign assets registered without governance can be unverified.
+	fn is_verified(currency_id: CurrencyId) -> bool {
+		let is_verified_dex_share = |dex_share: DexShare| match dex_share {
+			DexShare::ForeignAsset(foreign_asset_id) => !UnverifiedForeignAssets::<T>::contains_key(foreign_asset_id),
+			_ => true,
+		};
+		match currency_id {
+			CurrencyId::ForeignAsset(foreign_asset_id) => !UnverifiedForeignAssets::<T>::contains_key(foreign_asset_id),
+			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
+				is_verified_dex_share(dex_share_0) && is_verified_dex_share(dex_share_1)
+			}
+			_ => true,
+		}
+	}
+}
+
 fn key_to_currency(location: MultiLocation) -> Option<CurrencyId> {
 	match location {
 		MultiLocation {
@@ -561,6 +844,10 @@ where
 {
 	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
 		if let Some(CurrencyId::ForeignAsset(foreign_asset_id)) = Pallet::<T>::location_to_currency_ids(location) {
+			// unverified foreign assets can't be used to pay fees.
+			if UnverifiedForeignAssets::<T>::contains_key(foreign_asset_id) {
+				return None;
+			}
 			if let Some(asset_metadata) = Pallet::<T>::asset_metadatas(AssetIds::ForeignAssetId(foreign_asset_id)) {
 				let minimum_balance = asset_metadata.minimal_balance.into();
 				let rate = FixedU128::saturating_from_rational(minimum_balance, T::Currency::minimum_balance().into());
@@ -86,6 +86,7 @@ impl pallet_timestamp::Config for Runtime {
 
 parameter_types! {
 	pub NetworkContractSource: EvmAddress = alice_evm_addr();
+	pub const Hardfork: module_evm::EvmHardfork = module_evm::EvmHardfork::TransientStorage;
 }
 
 ord_parameter_types! {
@@ -108,12 +109,16 @@ impl module_evm::Config for Runtime {
 	type GasToWeight = ();
 	type ChargeTransactionPayment = module_support::mocks::MockReservedTransactionPayment<Balances>;
 	type NetworkContractOrigin = EnsureSignedBy<NetworkContractAccount, AccountId>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 
 	type DeveloperDeposit = ConstU128<1000>;
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type ContractUpgradeDelay = ConstU64<10>;
+	type SponsorshipDeposit = ConstU128<100>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
@@ -135,6 +140,7 @@ impl asset_registry::Config for Runtime {
 	type StakingCurrencyId = KSMCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type RegisterOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type ForeignAssetRegistrationDeposit = ConstU128<1000>;
 	type WeightInfo = ();
 }
 
@@ -24,7 +24,8 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	alice, deploy_contracts, deploy_contracts_same_prefix, erc20_address, erc20_address_not_exists,
-	erc20_address_same_prefix, AssetRegistry, CouncilAccount, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System,
+	erc20_address_same_prefix, AssetRegistry, Balances, CouncilAccount, ExtBuilder, Runtime, RuntimeEvent,
+	RuntimeOrigin, System,
 };
 use primitives::TokenSymbol;
 use sp_core::H160;
@@ -415,6 +416,336 @@ fn update_foreign_asset_should_not_work() {
 	});
 }
 
+#[test]
+fn migrate_foreign_asset_location_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let location: MultiLocation = MultiLocation::new(1, X1(Parachain(1000)));
+		let new_location: MultiLocation = MultiLocation::new(1, X1(Parachain(2000)));
+		let metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		assert_noop!(
+			AssetRegistry::migrate_foreign_asset_location(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				0,
+				Box::new(new_location.into()),
+			),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Box::new(location.into()),
+			Box::new(metadata.clone())
+		));
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			AssetIds::ForeignAssetId(0),
+			AssetStatus::Deprecated
+		));
+
+		assert_noop!(
+			AssetRegistry::migrate_foreign_asset_location(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				0,
+				Box::new(location.into()),
+			),
+			Error::<Runtime>::MultiLocationExisted
+		);
+
+		assert_ok!(AssetRegistry::migrate_foreign_asset_location(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			0,
+			Box::new(new_location.into()),
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::ForeignAssetMigrated {
+			asset_id: 0,
+			old_location: location,
+			new_location,
+		}));
+
+		assert_eq!(ForeignAssetLocations::<Runtime>::get(0), Some(new_location));
+		assert_eq!(LocationToCurrencyIds::<Runtime>::get(location), None);
+		assert_eq!(
+			LocationToCurrencyIds::<Runtime>::get(new_location),
+			Some(CurrencyId::ForeignAsset(0))
+		);
+		assert_eq!(
+			AssetMetadatas::<Runtime>::get(AssetIds::ForeignAssetId(0)),
+			Some(metadata)
+		);
+		assert_eq!(
+			AssetStatuses::<Runtime>::get(AssetIds::ForeignAssetId(0)),
+			AssetStatus::Deprecated
+		);
+	});
+}
+
+#[test]
+fn set_asset_status_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		assert_noop!(
+			AssetRegistry::set_asset_status(
+				RuntimeOrigin::signed(alice()),
+				AssetIds::ForeignAssetId(0),
+				AssetStatus::Frozen
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::set_asset_status(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				AssetIds::ForeignAssetId(0),
+				AssetStatus::Frozen
+			),
+			Error::<Runtime>::AssetIdNotExists
+		);
+
+		assert_ok!(AssetRegistry::register_foreign_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Box::new(MultiLocation::new(1, X1(Parachain(1000))).into()),
+			Box::new(metadata.clone())
+		));
+		assert_ok!(AssetRegistry::register_native_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			CurrencyId::Token(TokenSymbol::DOT),
+			Box::new(metadata)
+		));
+		assert_eq!(
+			AssetIdMaps::<Runtime>::asset_status(CurrencyId::ForeignAsset(0)),
+			AssetStatus::Active
+		);
+
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			AssetIds::ForeignAssetId(0),
+			AssetStatus::Deprecated
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetStatusChanged {
+			asset_id: AssetIds::ForeignAssetId(0),
+			status: AssetStatus::Deprecated,
+		}));
+		assert_eq!(
+			AssetIdMaps::<Runtime>::asset_status(CurrencyId::ForeignAsset(0)),
+			AssetStatus::Deprecated
+		);
+		assert!(!AssetIdMaps::<Runtime>::is_active(CurrencyId::ForeignAsset(0)));
+		assert!(!AssetIdMaps::<Runtime>::is_frozen(CurrencyId::ForeignAsset(0)));
+
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			AssetIds::NativeAssetId(CurrencyId::Token(TokenSymbol::DOT)),
+			AssetStatus::Frozen
+		));
+		assert!(AssetIdMaps::<Runtime>::is_frozen(CurrencyId::Token(TokenSymbol::DOT)));
+
+		// the LP token takes the most restrictive status of its underlying assets
+		assert_eq!(
+			AssetIdMaps::<Runtime>::asset_status(
+				CurrencyId::join_dex_share_currency_id(
+					CurrencyId::Token(TokenSymbol::DOT),
+					CurrencyId::ForeignAsset(0)
+				)
+				.unwrap()
+			),
+			AssetStatus::Frozen
+		);
+
+		assert_ok!(AssetRegistry::set_asset_status(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			AssetIds::ForeignAssetId(0),
+			AssetStatus::Active
+		));
+		assert!(!AssetStatuses::<Runtime>::contains_key(AssetIds::ForeignAssetId(0)));
+		assert!(AssetIdMaps::<Runtime>::is_active(CurrencyId::ForeignAsset(0)));
+	});
+}
+
+#[test]
+fn register_unverified_foreign_asset_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 10_000)])
+		.build()
+		.execute_with(|| {
+			let location = MultiLocation::new(1, X1(Parachain(1000)));
+			let metadata = AssetMetadata {
+				name: b"Token Name".to_vec(),
+				symbol: b"TN".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			};
+
+			assert_noop!(
+				AssetRegistry::register_unverified_foreign_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					Box::new(location.into()),
+					Box::new(metadata.clone())
+				),
+				pallet_balances::Error::<Runtime>::InsufficientBalance
+			);
+			assert_noop!(
+				AssetRegistry::register_unverified_foreign_asset(
+					RuntimeOrigin::signed(alice()),
+					Box::new(location.into()),
+					Box::new(AssetMetadata {
+						minimal_balance: 0,
+						..metadata.clone()
+					})
+				),
+				Error::<Runtime>::ZeroMinimalBalance
+			);
+
+			assert_ok!(AssetRegistry::register_unverified_foreign_asset(
+				RuntimeOrigin::signed(alice()),
+				Box::new(location.into()),
+				Box::new(metadata.clone())
+			));
+			let evm_address = EvmErc20InfoMapping::<Runtime>::encode_evm_address(CurrencyId::ForeignAsset(0)).unwrap();
+			System::assert_last_event(RuntimeEvent::AssetRegistry(
+				crate::Event::UnverifiedForeignAssetRegistered {
+					asset_id: 0,
+					asset_address: location,
+					evm_address,
+					metadata: metadata.clone(),
+					depositor: alice(),
+					deposit: 1000,
+				},
+			));
+			assert_eq!(
+				EvmErc20InfoMapping::<Runtime>::decode_evm_address(evm_address),
+				Some(CurrencyId::ForeignAsset(0))
+			);
+			assert_eq!(
+				LocationToCurrencyIds::<Runtime>::get(location),
+				Some(CurrencyId::ForeignAsset(0))
+			);
+			assert_eq!(UnverifiedForeignAssets::<Runtime>::get(0), Some((alice(), 1000)));
+			assert_eq!(Balances::reserved_balance(alice()), 1000);
+
+			// unverified asset can't be used to pay fees
+			assert!(!AssetIdMaps::<Runtime>::is_verified(CurrencyId::ForeignAsset(0)));
+			assert!(!AssetIdMaps::<Runtime>::is_verified(
+				CurrencyId::join_dex_share_currency_id(
+					CurrencyId::Token(TokenSymbol::ACA),
+					CurrencyId::ForeignAsset(0)
+				)
+				.unwrap()
+			));
+			assert_eq!(BuyWeightRateOfForeignAsset::<Runtime>::calculate_rate(location), None);
+
+			assert_noop!(
+				AssetRegistry::verify_foreign_asset(RuntimeOrigin::signed(alice()), 0),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AssetRegistry::verify_foreign_asset(RuntimeOrigin::signed(CouncilAccount::get()), 1),
+				Error::<Runtime>::AssetIdNotExists
+			);
+
+			assert_ok!(AssetRegistry::verify_foreign_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				0
+			));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::ForeignAssetVerified {
+				asset_id: 0,
+				depositor: alice(),
+				deposit: 1000,
+			}));
+			assert_eq!(UnverifiedForeignAssets::<Runtime>::get(0), None);
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+			assert!(AssetIdMaps::<Runtime>::is_verified(CurrencyId::ForeignAsset(0)));
+			assert!(BuyWeightRateOfForeignAsset::<Runtime>::calculate_rate(location).is_some());
+
+			assert_noop!(
+				AssetRegistry::verify_foreign_asset(RuntimeOrigin::signed(CouncilAccount::get()), 0),
+				Error::<Runtime>::AlreadyVerified
+			);
+		});
+}
+
+#[test]
+fn reject_foreign_asset_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 10_000)])
+		.build()
+		.execute_with(|| {
+			let location = MultiLocation::new(1, X1(Parachain(1000)));
+			let metadata = AssetMetadata {
+				name: b"Token Name".to_vec(),
+				symbol: b"TN".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			};
+
+			assert_ok!(AssetRegistry::register_unverified_foreign_asset(
+				RuntimeOrigin::signed(alice()),
+				Box::new(location.into()),
+				Box::new(metadata.clone())
+			));
+			assert_ok!(AssetRegistry::set_asset_status(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				AssetIds::ForeignAssetId(0),
+				AssetStatus::Frozen
+			));
+			let total_issuance = Balances::total_issuance();
+
+			assert_noop!(
+				AssetRegistry::reject_foreign_asset(RuntimeOrigin::signed(alice()), 0),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				AssetRegistry::reject_foreign_asset(RuntimeOrigin::signed(CouncilAccount::get()), 1),
+				Error::<Runtime>::AssetIdNotExists
+			);
+
+			assert_ok!(AssetRegistry::reject_foreign_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				0
+			));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::ForeignAssetRejected {
+				asset_id: 0,
+				depositor: alice(),
+				deposit: 1000,
+			}));
+			assert_eq!(UnverifiedForeignAssets::<Runtime>::get(0), None);
+			assert_eq!(ForeignAssetLocations::<Runtime>::get(0), None);
+			assert_eq!(LocationToCurrencyIds::<Runtime>::get(location), None);
+			assert_eq!(AssetMetadatas::<Runtime>::get(AssetIds::ForeignAssetId(0)), None);
+			assert!(!AssetStatuses::<Runtime>::contains_key(AssetIds::ForeignAssetId(0)));
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+			assert_eq!(Balances::free_balance(alice()), 9_000);
+			assert_eq!(Balances::total_issuance(), total_issuance - 1000);
+
+			// the location can be registered again
+			assert_ok!(AssetRegistry::register_foreign_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				Box::new(location.into()),
+				Box::new(metadata)
+			));
+			assert_eq!(
+				LocationToCurrencyIds::<Runtime>::get(location),
+				Some(CurrencyId::ForeignAsset(1))
+			);
+
+			// the verified asset can't be rejected
+			assert_noop!(
+				AssetRegistry::reject_foreign_asset(RuntimeOrigin::signed(CouncilAccount::get()), 1),
+				Error::<Runtime>::AlreadyVerified
+			);
+		});
+}
+
 #[test]
 fn register_stable_asset_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -54,6 +54,10 @@ pub trait WeightInfo {
 	fn update_erc20_asset() -> Weight;
 	fn register_native_asset() -> Weight;
 	fn update_native_asset() -> Weight;
+	fn set_asset_status() -> Weight;
+	fn migrate_foreign_asset_location() -> Weight;
+	fn verify_foreign_asset() -> Weight;
+	fn reject_foreign_asset() -> Weight;
 }
 
 /// Weights for module_asset_registry using the Acala node and recommended hardware.
@@ -116,6 +120,40 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: AssetRegistry AssetMetadatas (r:1 w:0)
+	// Storage: AssetRegistry AssetStatuses (r:0 w:1)
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(14_107_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetRegistry ForeignAssetLocations (r:1 w:1)
+	// Storage: AssetRegistry LocationToCurrencyIds (r:1 w:2)
+	fn migrate_foreign_asset_location() -> Weight {
+		Weight::from_parts(19_926_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: AssetRegistry ForeignAssetLocations (r:1 w:0)
+	// Storage: AssetRegistry UnverifiedForeignAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn verify_foreign_asset() -> Weight {
+		Weight::from_parts(25_136_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AssetRegistry ForeignAssetLocations (r:1 w:1)
+	// Storage: AssetRegistry UnverifiedForeignAssets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: AssetRegistry LocationToCurrencyIds (r:0 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:0 w:1)
+	// Storage: AssetRegistry AssetStatuses (r:0 w:1)
+	fn reject_foreign_asset() -> Weight {
+		Weight::from_parts(35_471_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -160,4 +198,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(14_107_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn migrate_foreign_asset_location() -> Weight {
+		Weight::from_parts(19_926_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn verify_foreign_asset() -> Weight {
+		Weight::from_parts(25_136_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn reject_foreign_asset() -> Weight {
+		Weight::from_parts(35_471_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
 }
@@ -34,9 +34,9 @@ use frame_system::{
 	pallet_prelude::*,
 };
 use module_support::{
-	AddressMapping, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, ExchangeRate, FractionalRate,
-	InvokeContext, LiquidateCollateral, LiquidationEvmBridge, Price, PriceProvider, Rate, Ratio, RiskManager, Swap,
-	SwapLimit,
+	AddressMapping, AssetStatusProvider, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, ExchangeRate,
+	FractionalRate, InvokeContext, LiquidateCollateral, LiquidationEvmBridge, Price, PriceProvider, Rate, Ratio,
+	RiskManager, Swap, SwapLimit,
 };
 use orml_traits::{Change, GetByKey, MultiCurrency};
 use orml_utilities::OffchainErr;
@@ -208,6 +208,9 @@ pub mod module {
 
 		type EvmAddressMapping: AddressMapping<Self::AccountId>;
 
+		/// The verification status of the assets, unverified assets can't be collateral.
+		type AssetStatusProvider: AssetStatusProvider<CurrencyId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -254,6 +257,8 @@ pub mod module {
 		CollateralContractNotFound,
 		/// Invalid rate
 		InvalidRate,
+		/// The collateral is pending verification
+		CollateralNotVerified,
 	}
 
 	#[pallet::event]
@@ -492,6 +497,10 @@ pub mod module {
 			maximum_total_debit_value: ChangeBalance,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::AssetStatusProvider::is_verified(currency_id),
+				Error::<T>::CollateralNotVerified
+			);
 
 			let mut collateral_params = Self::collateral_params(currency_id).unwrap_or_default();
 			if let Change::NewValue(maybe_rate) = interest_rate_per_sec {
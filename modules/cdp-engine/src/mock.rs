@@ -29,7 +29,7 @@ use frame_support::{
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockStableAsset, AuctionManager, EmergencyShutdown, SpecificJointsSwap};
 use orml_traits::parameter_type_with_key;
-use primitives::{DexShare, Moment, TokenSymbol, TradingPair};
+use primitives::{currency::AssetStatus, DexShare, Moment, TokenSymbol, TradingPair};
 use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
 	testing::TestXt,
@@ -267,6 +267,7 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type AssetStatusProvider = ();
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -282,6 +283,9 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type ContractSignatureVerifier = ();
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = ();
 	type WeightInfo = ();
 }
 
@@ -381,6 +385,20 @@ parameter_types! {
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 }
 
+parameter_types! {
+	pub static MockUnverifiedCurrencyId: Option<CurrencyId> = None;
+}
+
+impl AssetStatusProvider<CurrencyId> for MockUnverifiedCurrencyId {
+	fn asset_status(_currency_id: CurrencyId) -> AssetStatus {
+		AssetStatus::Active
+	}
+
+	fn is_verified(currency_id: CurrencyId) -> bool {
+		Self::get() != Some(currency_id)
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriceSource = MockPriceSource;
@@ -405,6 +423,7 @@ impl Config for Runtime {
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
+	type AssetStatusProvider = MockUnverifiedCurrencyId;
 	type WeightInfo = ();
 }
 
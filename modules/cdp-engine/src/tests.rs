@@ -2032,3 +2032,33 @@ fn liquidation_fails_if_insufficient_repayment() {
 		);
 	});
 }
+
+#[test]
+fn set_collateral_params_of_unverified_asset_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MockUnverifiedCurrencyId::set(Some(BTC));
+		assert_noop!(
+			CDPEngineModule::set_collateral_params(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(10000),
+			),
+			Error::<Runtime>::CollateralNotVerified
+		);
+
+		MockUnverifiedCurrencyId::set(None);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+	});
+}
@@ -35,7 +35,7 @@ use frame_support::{
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use module_support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext};
+use module_support::{evm::limits::erc20, AddressMapping, AssetStatusProvider, EVMBridge, InvokeContext};
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::{OnDust, TransferAll},
@@ -43,11 +43,11 @@ use orml_traits::{
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
 };
 use parity_scale_codec::Codec;
-use primitives::{evm::EvmAddress, CurrencyId};
+use primitives::{evm::EvmAddress, CurrencyId, Nonce};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
-	DispatchError, DispatchResult,
+	traits::{Bounded, CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
+	ArithmeticError, DispatchError, DispatchResult,
 };
 use sp_std::{fmt::Debug, marker, result, vec::Vec};
 
@@ -62,6 +62,18 @@ type AmountOf<T> =
 	<<T as Config>::MultiCurrency as MultiCurrencyExtended<<T as frame_system::Config>::AccountId>>::Amount;
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// The maximum number of named locks on an ERC20 balance.
+pub const MAX_ERC20_LOCKS: u32 = 50;
+
+/// A named lock on an ERC20 balance.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Erc20BalanceLock<Balance> {
+	/// An identifier for this lock. Only one lock may be in existence for each identifier.
+	pub id: LockIdentifier,
+	/// The amount which the free balance may not drop below when this lock is in effect.
+	pub amount: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -112,6 +124,9 @@ pub mod module {
 
 		/// Handler to burn or transfer account's dust
 		type OnDust: OnDust<Self::AccountId, CurrencyId, BalanceOf<Self>>;
+
+		/// The lifecycle status of the assets, transfers of frozen assets are rejected.
+		type AssetStatusProvider: AssetStatusProvider<CurrencyId>;
 	}
 
 	#[pallet::error]
@@ -128,6 +143,14 @@ pub mod module {
 		RealOriginNotFound,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// The allowance of the spender is not enough.
+		InsufficientAllowance,
+		/// The ERC20 escrow ledger does not match the on-contract balance of the holding account.
+		Erc20EscrowMismatch,
+		/// Too many ERC20 locks on the account.
+		TooManyErc20Locks,
+		/// The asset is frozen.
+		AssetFrozen,
 	}
 
 	#[pallet::event]
@@ -158,8 +181,68 @@ pub mod module {
 			who: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// The allowance of the spender is set.
+		Approval {
+			currency_id: CurrencyId,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
+	/// The amount of currency that the spender is allowed to transfer from the owner.
+	///
+	/// Allowances: double_map (CurrencyId, owner: AccountId), spender: AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(CurrencyId, T::AccountId),
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The nonces of the EIP-2612 permits of the owner.
+	///
+	/// PermitNonces: double_map CurrencyId, owner: AccountId => Nonce
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonces)]
+	pub type PermitNonces<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, Nonce, ValueQuery>;
+
+	/// The reserved ERC20 balance of an account, escrowed under `Erc20HoldingAccount`.
+	///
+	/// Erc20Reserves: double_map CurrencyId, AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_reserves)]
+	pub type Erc20Reserves<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The named ERC20 locks of an account. The largest lock is escrowed under `Erc20HoldingAccount`.
+	///
+	/// Erc20Locks: double_map CurrencyId, AccountId => Vec<Erc20BalanceLock>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_locks)]
+	pub type Erc20Locks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<Erc20BalanceLock<BalanceOf<T>>, ConstU32<MAX_ERC20_LOCKS>>,
+		ValueQuery,
+	>;
+
+	/// The total ERC20 balance escrowed under `Erc20HoldingAccount` for reserves and locks.
+	///
+	/// Erc20TotalEscrowed: map CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_total_escrowed)]
+	pub type Erc20TotalEscrowed<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -291,6 +374,306 @@ impl<T: Config> Pallet<T> {
 		let origin = T::EVMBridge::get_real_or_xcm_origin().ok_or(Error::<T>::RealOriginNotFound)?;
 		Ok(T::AddressMapping::get_or_create_evm_address(&origin))
 	}
+
+	fn erc20_balance_of(contract: EvmAddress, address: EvmAddress) -> BalanceOf<T> {
+		T::EVMBridge::balance_of(
+			InvokeContext {
+				contract,
+				sender: Default::default(),
+				origin: Default::default(),
+			},
+			address,
+		)
+		.unwrap_or_default()
+	}
+
+	/// The on-contract ERC20 balance of `who`, which excludes the escrowed reserves and locks.
+	fn erc20_usable_balance(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		T::AddressMapping::get_evm_address(who)
+			.map(|address| Self::erc20_balance_of(contract, address))
+			.unwrap_or_default()
+	}
+
+	/// The ERC20 balance held by `Erc20HoldingAccount` which is not escrowed for reserves and locks.
+	fn erc20_holding_available(currency_id: CurrencyId, contract: EvmAddress) -> BalanceOf<T> {
+		Self::erc20_balance_of(contract, T::Erc20HoldingAccount::get())
+			.saturating_sub(Erc20TotalEscrowed::<T>::get(currency_id))
+	}
+
+	/// Ensure the on-contract balance of `Erc20HoldingAccount` covers the escrow ledger.
+	pub fn ensure_erc20_escrow_consistent(currency_id: CurrencyId) -> DispatchResult {
+		match currency_id {
+			CurrencyId::Erc20(contract) => {
+				ensure!(
+					Self::erc20_balance_of(contract, T::Erc20HoldingAccount::get())
+						>= Erc20TotalEscrowed::<T>::get(currency_id),
+					Error::<T>::Erc20EscrowMismatch
+				);
+				Ok(())
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Move `amount` of ERC20 from `who` into the escrow under `Erc20HoldingAccount`.
+	fn erc20_escrow(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+		Self::erc20_escrow_from(currency_id, contract, address, address, amount)
+	}
+
+	/// Move `amount` of ERC20 from `sender` into the escrow under `Erc20HoldingAccount`.
+	#[transactional]
+	fn erc20_escrow_from(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		sender: EvmAddress,
+		origin: EvmAddress,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let holding = T::Erc20HoldingAccount::get();
+		let before = Self::erc20_balance_of(contract, holding);
+		T::EVMBridge::transfer(
+			InvokeContext {
+				contract,
+				sender,
+				origin: Self::get_evm_origin().unwrap_or(origin),
+			},
+			holding,
+			amount,
+		)?;
+		// tokens which charge fees or rebase on transfer can't be tracked by the ledger.
+		ensure!(
+			Self::erc20_balance_of(contract, holding) == before.saturating_add(amount),
+			Error::<T>::Erc20EscrowMismatch
+		);
+		Erc20TotalEscrowed::<T>::try_mutate(currency_id, |total| -> DispatchResult {
+			*total = total.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})
+	}
+
+	/// Release `amount` of ERC20 from the escrow under `Erc20HoldingAccount` to `to`.
+	#[transactional]
+	fn erc20_release(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		to: EvmAddress,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		Self::ensure_erc20_escrow_consistent(currency_id)?;
+		let remaining = Erc20TotalEscrowed::<T>::get(currency_id)
+			.checked_sub(&amount)
+			.ok_or(Error::<T>::Erc20EscrowMismatch)?;
+		let holding = T::Erc20HoldingAccount::get();
+		T::EVMBridge::transfer(
+			InvokeContext {
+				contract,
+				sender: holding,
+				origin: Self::get_evm_origin().unwrap_or(to),
+			},
+			to,
+			amount,
+		)?;
+		Erc20TotalEscrowed::<T>::insert(currency_id, remaining);
+		Ok(())
+	}
+
+	#[transactional]
+	fn erc20_reserve(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		value: BalanceOf<T>,
+	) -> DispatchResult {
+		Self::erc20_escrow(currency_id, contract, who, value)?;
+		Erc20Reserves::<T>::try_mutate(currency_id, who, |reserved| -> DispatchResult {
+			*reserved = reserved.checked_add(&value).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})
+	}
+
+	fn erc20_decrease_reserve(currency_id: CurrencyId, who: &T::AccountId, amount: BalanceOf<T>) {
+		Erc20Reserves::<T>::mutate_exists(currency_id, who, |reserved| {
+			let remaining = reserved.unwrap_or_default().saturating_sub(amount);
+			*reserved = Some(remaining).filter(|r| !r.is_zero());
+		});
+	}
+
+	/// The ERC20 reserved under the legacy reserve address of `who`, which is moved into the
+	/// escrow when the reserve of `who` is unreserved, slashed or repatriated.
+	fn erc20_legacy_reserved_balance(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		T::AddressMapping::get_evm_address(who)
+			.map(|address| Self::erc20_balance_of(contract, reserve_address(address)))
+			.unwrap_or_default()
+	}
+
+	/// Move the ERC20 reserved under the legacy reserve address of `who` into the escrow under
+	/// `Erc20HoldingAccount` and the reserve ledger.
+	#[transactional]
+	fn erc20_migrate_legacy_reserve(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let address = match T::AddressMapping::get_evm_address(who) {
+			Some(address) => address,
+			None => return Ok(()),
+		};
+		let legacy_address = reserve_address(address);
+		let amount = Self::erc20_balance_of(contract, legacy_address);
+		if amount.is_zero() {
+			return Ok(());
+		}
+		Self::erc20_escrow_from(currency_id, contract, legacy_address, address, amount)?;
+		Erc20Reserves::<T>::try_mutate(currency_id, who, |reserved| -> DispatchResult {
+			*reserved = reserved.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})
+	}
+
+	/// Unreserve up to `value` of ERC20 to `who`, returns the actual amount unreserved.
+	#[transactional]
+	fn erc20_unreserve(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		value: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::erc20_migrate_legacy_reserve(currency_id, contract, who)?;
+		let actual = Erc20Reserves::<T>::get(currency_id, who).min(value);
+		if actual.is_zero() {
+			return Ok(actual);
+		}
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+		Self::erc20_release(currency_id, contract, address, actual)?;
+		Self::erc20_decrease_reserve(currency_id, who, actual);
+		Ok(actual)
+	}
+
+	/// The amount of ERC20 locked for `who`, which is the largest of the locks.
+	pub fn erc20_locked_balance(currency_id: CurrencyId, who: &T::AccountId) -> BalanceOf<T> {
+		Erc20Locks::<T>::get(currency_id, who)
+			.iter()
+			.map(|lock| lock.amount)
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Replace the ERC20 locks of `who`, moving the difference of the largest lock in or out of the escrow.
+	#[transactional]
+	fn erc20_update_locks(
+		currency_id: CurrencyId,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		locks: Vec<Erc20BalanceLock<BalanceOf<T>>>,
+	) -> DispatchResult {
+		let locks: BoundedVec<_, ConstU32<MAX_ERC20_LOCKS>> =
+			locks.try_into().map_err(|_| Error::<T>::TooManyErc20Locks)?;
+		let old_locked = Self::erc20_locked_balance(currency_id, who);
+		let new_locked = locks.iter().map(|lock| lock.amount).max().unwrap_or_default();
+
+		if new_locked > old_locked {
+			Self::erc20_escrow(currency_id, contract, who, new_locked - old_locked)?;
+		} else if new_locked < old_locked {
+			let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+			Self::erc20_release(currency_id, contract, address, old_locked - new_locked)?;
+		}
+
+		if locks.is_empty() {
+			Erc20Locks::<T>::remove(currency_id, who);
+		} else {
+			Erc20Locks::<T>::insert(currency_id, who, locks);
+		}
+		Ok(())
+	}
+
+	/// The amount of `currency_id` that `spender` is allowed to transfer from `owner`.
+	pub fn allowance(currency_id: CurrencyId, owner: &T::AccountId, spender: &T::AccountId) -> BalanceOf<T> {
+		Allowances::<T>::get((currency_id, owner), spender)
+	}
+
+	/// Set the allowance of `spender` over the `owner`'s `currency_id`.
+	pub fn approve(currency_id: CurrencyId, owner: &T::AccountId, spender: &T::AccountId, amount: BalanceOf<T>) {
+		if amount.is_zero() {
+			Allowances::<T>::remove((currency_id, owner), spender);
+		} else {
+			Allowances::<T>::insert((currency_id, owner), spender, amount);
+		}
+
+		Self::deposit_event(Event::Approval {
+			currency_id,
+			owner: owner.clone(),
+			spender: spender.clone(),
+			amount,
+		});
+	}
+
+	/// Increase the allowance of `spender`, returns the new allowance.
+	pub fn increase_allowance(
+		currency_id: CurrencyId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let allowance = Self::allowance(currency_id, owner, spender).saturating_add(amount);
+		Self::approve(currency_id, owner, spender, allowance);
+		allowance
+	}
+
+	/// Decrease the allowance of `spender`, returns the new allowance.
+	pub fn decrease_allowance(
+		currency_id: CurrencyId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let allowance = Self::allowance(currency_id, owner, spender)
+			.checked_sub(&amount)
+			.ok_or(Error::<T>::InsufficientAllowance)?;
+		Self::approve(currency_id, owner, spender, allowance);
+		Ok(allowance)
+	}
+
+	/// Transfer `amount` of `currency_id` from `from` to `to` with the allowance of `spender`.
+	/// The max allowance is never spent.
+	#[transactional]
+	pub fn transfer_from(
+		currency_id: CurrencyId,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if spender != from {
+			let allowance = Self::allowance(currency_id, from, spender);
+			if allowance != BalanceOf::<T>::max_value() {
+				let remaining = allowance
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::InsufficientAllowance)?;
+				if remaining.is_zero() {
+					Allowances::<T>::remove((currency_id, from), spender);
+				} else {
+					Allowances::<T>::insert((currency_id, from), spender, remaining);
+				}
+			}
+		}
+
+		<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, from, to, amount)
+	}
+
+	/// Returns the current permit nonce of `owner` and increases it.
+	pub fn use_permit_nonce(currency_id: CurrencyId, owner: &T::AccountId) -> Nonce {
+		PermitNonces::<T>::mutate(currency_id, owner, |nonce| {
+			let current = *nonce;
+			*nonce = nonce.saturating_add(1);
+			current
+		})
+	}
 }
 
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
@@ -333,15 +716,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	fn free_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
-				}
-				Default::default()
+				Self::erc20_usable_balance(contract, who).saturating_add(Self::erc20_locked_balance(currency_id, who))
 			}
 			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::free_balance(who),
 			_ => <T::MultiCurrency as MultiCurrency<_>>::free_balance(currency_id, who),
@@ -384,6 +759,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 		if amount.is_zero() || from == to {
 			return Ok(());
 		}
+		ensure!(!T::AssetStatusProvider::is_frozen(currency_id), Error::<T>::AssetFrozen);
 
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
@@ -424,10 +800,11 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 				// deposit from erc20 holding account to receiver(who). in xcm case which receive erc20 from sibling
 				// parachain, we choose sibling parachain sovereign account to charge storage fee. we must make sure
 				// sibling parachain sovereign account has enough native token to charge storage fee.
+				// the escrowed reserves and locks can't be deposited.
 				let sender = T::Erc20HoldingAccount::get();
 				let from = T::AddressMapping::get_account_id(&sender);
 				ensure!(
-					!Self::free_balance(currency_id, &from).is_zero(),
+					Self::erc20_holding_available(currency_id, contract) >= amount,
 					Error::<T>::DepositFailed
 				);
 				let receiver = T::AddressMapping::get_or_create_evm_address(who);
@@ -545,7 +922,17 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Ok(());
+				}
+				let mut locks = Erc20Locks::<T>::get(currency_id, who).into_inner();
+				match locks.iter_mut().find(|lock| lock.id == lock_id) {
+					Some(lock) => lock.amount = amount,
+					None => locks.push(Erc20BalanceLock { id: lock_id, amount }),
+				}
+				Self::erc20_update_locks(currency_id, contract, who, locks)
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::set_lock(lock_id, who, amount)
 			}
@@ -560,7 +947,17 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Ok(());
+				}
+				let mut locks = Erc20Locks::<T>::get(currency_id, who).into_inner();
+				match locks.iter_mut().find(|lock| lock.id == lock_id) {
+					Some(lock) => lock.amount = lock.amount.max(amount),
+					None => locks.push(Erc20BalanceLock { id: lock_id, amount }),
+				}
+				Self::erc20_update_locks(currency_id, contract, who, locks)
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::extend_lock(lock_id, who, amount)
 			}
@@ -570,7 +967,11 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 
 	fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				let mut locks = Erc20Locks::<T>::get(currency_id, who).into_inner();
+				locks.retain(|lock| lock.id != lock_id);
+				Self::erc20_update_locks(currency_id, contract, who, locks)
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::remove_lock(lock_id, who)
 			}
@@ -592,7 +993,16 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 
 	fn slash_reserved(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
 		match currency_id {
-			CurrencyId::Erc20(_) => value,
+			CurrencyId::Erc20(contract) => {
+				if Self::erc20_migrate_legacy_reserve(currency_id, contract, who).is_err() {
+					return value;
+				}
+				// the slashed tokens are left in `Erc20HoldingAccount`, same as `withdraw`.
+				let actual = Erc20Reserves::<T>::get(currency_id, who).min(value);
+				Self::erc20_decrease_reserve(currency_id, who, actual);
+				Erc20TotalEscrowed::<T>::mutate(currency_id, |total| *total = total.saturating_sub(actual));
+				value - actual
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicReservableCurrency<_>>::slash_reserved(who, value)
 			}
@@ -602,20 +1012,8 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
-			CurrencyId::Erc20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					return T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						reserve_address(address),
-					)
-					.unwrap_or_default();
-				}
-				Default::default()
-			}
+			CurrencyId::Erc20(contract) => Erc20Reserves::<T>::get(currency_id, who)
+				.saturating_add(Self::erc20_legacy_reserved_balance(contract, who)),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicReservableCurrency<_>>::reserved_balance(who)
 			}
@@ -629,16 +1027,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				if value.is_zero() {
 					return Ok(());
 				}
-				let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
-				T::EVMBridge::transfer(
-					InvokeContext {
-						contract,
-						sender: address,
-						origin: Self::get_evm_origin().unwrap_or(address),
-					},
-					reserve_address(address),
-					value,
-				)
+				Self::erc20_reserve(currency_id, contract, who, value)
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicReservableCurrency<_>>::reserve(who, value)
@@ -653,32 +1042,9 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				if value.is_zero() {
 					return value;
 				}
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let sender = reserve_address(address);
-					let reserved_balance = T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						sender,
-					)
-					.unwrap_or_default();
-					let actual = reserved_balance.min(value);
-					match T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender,
-							origin: Self::get_evm_origin().unwrap_or(address),
-						},
-						address,
-						actual,
-					) {
-						Ok(_) => value - actual,
-						Err(_) => value,
-					}
-				} else {
-					value
+				match Self::erc20_unreserve(currency_id, contract, who, value) {
+					Ok(actual) => value - actual,
+					Err(_) => value,
 				}
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
@@ -709,43 +1075,25 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 					};
 				}
 
-				let slashed_address =
-					T::AddressMapping::get_evm_address(slashed).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let beneficiary_address = T::AddressMapping::get_or_create_evm_address(beneficiary);
-
-				let slashed_reserve_address = reserve_address(slashed_address);
-				let beneficiary_reserve_address = reserve_address(beneficiary_address);
-
-				let slashed_reserved_balance = T::EVMBridge::balance_of(
-					InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					},
-					slashed_reserve_address,
-				)
-				.unwrap_or_default();
-				let actual = slashed_reserved_balance.min(value);
+				Self::erc20_migrate_legacy_reserve(currency_id, contract, slashed)?;
+				let actual = Erc20Reserves::<T>::get(currency_id, slashed).min(value);
+				if actual.is_zero() {
+					return Ok(value);
+				}
 				match status {
-					BalanceStatus::Free => T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender: slashed_reserve_address,
-							origin: Self::get_evm_origin().unwrap_or(slashed_address),
-						},
-						beneficiary_address,
-						actual,
-					),
-					BalanceStatus::Reserved => T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender: slashed_reserve_address,
-							origin: Self::get_evm_origin().unwrap_or(slashed_address),
-						},
-						beneficiary_reserve_address,
-						actual,
-					),
-				}?;
+					BalanceStatus::Free => {
+						let beneficiary_address = T::AddressMapping::get_or_create_evm_address(beneficiary);
+						Self::erc20_release(currency_id, contract, beneficiary_address, actual)?;
+					}
+					// the tokens stay in escrow, only the ledger is updated.
+					BalanceStatus::Reserved => {
+						Erc20Reserves::<T>::try_mutate(currency_id, beneficiary, |reserved| -> DispatchResult {
+							*reserved = reserved.checked_add(&actual).ok_or(ArithmeticError::Overflow)?;
+							Ok(())
+						})?
+					}
+				}
+				Self::erc20_decrease_reserve(currency_id, slashed, actual);
 				Ok(value - actual)
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
@@ -813,7 +1161,7 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 		force: Fortitude,
 	) -> Self::Balance {
 		match asset_id {
-			CurrencyId::Erc20(_) => <Self as MultiCurrency<_>>::free_balance(asset_id, who),
+			CurrencyId::Erc20(contract) => Self::erc20_usable_balance(contract, who),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::Inspect<_>>::reducible_balance(who, preservation, force)
 			}
@@ -959,6 +1307,8 @@ impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 		preservation: Preservation,
 	) -> Result<Self::Balance, DispatchError> {
+		ensure!(!T::AssetStatusProvider::is_frozen(asset_id), Error::<T>::AssetFrozen);
+
 		match asset_id {
 			CurrencyId::Erc20(_) => {
 				// Event is deposited in `fn transfer`
@@ -1787,6 +2137,8 @@ impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// The address which escrowed the ERC20 reserves of `address` before they were moved under
+/// `Erc20HoldingAccount`.
 fn reserve_address(address: EvmAddress) -> EvmAddress {
 	let payload = (b"erc20:", address);
 	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
@@ -31,7 +31,7 @@ use frame_support::{
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockAddressMapping, AddressMapping};
 use orml_traits::{currency::MutationHooks, parameter_type_with_key};
-use primitives::{evm::convert_decimals_to_evm, CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{currency::AssetStatus, evm::convert_decimals_to_evm, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::H256;
 use sp_core::{H160, U256};
 use sp_runtime::{
@@ -151,6 +151,7 @@ impl pallet_timestamp::Config for Runtime {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice_evm_addr();
+	pub const Hardfork: module_evm::EvmHardfork = module_evm::EvmHardfork::TransientStorage;
 }
 
 ord_parameter_types! {
@@ -183,12 +184,16 @@ impl module_evm::Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_support::mocks::MockReservedTransactionPayment<Balances>;
 	type NetworkContractOrigin = EnsureSignedBy<NetworkContractAccount, AccountId>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 
 	type DeveloperDeposit = DeveloperDeposit;
 	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type ContractUpgradeDelay = ConstU64<10>;
+	type SponsorshipDeposit = ConstU128<100>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
@@ -205,6 +210,20 @@ parameter_types! {
 	pub Erc20HoldingAccount: H160 = primitives::evm::ERC20_HOLDING_ACCOUNT;
 }
 
+parameter_types! {
+	pub static MockFrozenCurrencyId: Option<CurrencyId> = None;
+}
+
+impl AssetStatusProvider<CurrencyId> for MockFrozenCurrencyId {
+	fn asset_status(currency_id: CurrencyId) -> AssetStatus {
+		if Self::get() == Some(currency_id) {
+			AssetStatus::Frozen
+		} else {
+			AssetStatus::Active
+		}
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Tokens;
@@ -217,6 +236,7 @@ impl Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = crate::TransferDust<Runtime, DustAccount>;
+	type AssetStatusProvider = MockFrozenCurrencyId;
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -263,6 +283,7 @@ pub fn eva_evm_addr() -> EvmAddress {
 }
 
 pub const ID_1: LockIdentifier = *b"1       ";
+pub const ID_2: LockIdentifier = *b"2       ";
 
 pub fn erc20_address() -> EvmAddress {
 	EvmAddress::from_str("0x5dddfce53ee040d9eb21afbc0ae1bb4dbb0ba643").unwrap()
@@ -24,10 +24,10 @@ use super::*;
 use crate::mock::Erc20HoldingAccount;
 use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, traits::WithdrawReasons};
 use mock::{
-	alice, bob, deploy_contracts, erc20_address, erc20_address_not_exist, eva, AccountId, AdaptedBasicCurrency,
-	Balances, CouncilAccount, Currencies, DustAccount, ExtBuilder, NativeCurrency, PalletBalances, Runtime,
-	RuntimeEvent, RuntimeOrigin, System, Tokens, ALICE_BALANCE, CHARLIE, DAVE, DOT, EVE, EVM, FERDIE, ID_1,
-	NATIVE_CURRENCY_ID, X_TOKEN_ID,
+	alice, alice_evm_addr, bob, deploy_contracts, erc20_address, erc20_address_not_exist, eva, AccountId,
+	AdaptedBasicCurrency, Balances, CouncilAccount, Currencies, DustAccount, ExtBuilder, MockFrozenCurrencyId,
+	NativeCurrency, PalletBalances, Runtime, RuntimeEvent, RuntimeOrigin, System, Tokens, ALICE_BALANCE, CHARLIE, DAVE,
+	DOT, EVE, EVM, FERDIE, ID_1, ID_2, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use module_support::mocks::MockAddressMapping;
 use module_support::EVM as EVMTrait;
@@ -913,8 +913,32 @@ fn erc20_slash_reserve_should_work() {
 			assert_ok!(Currencies::reserve(CurrencyId::Erc20(erc20_address()), &alice(), 100));
 			assert_eq!(
 				Currencies::slash_reserved(CurrencyId::Erc20(erc20_address()), &alice(), 10),
+				0
+			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				90
+			);
+			assert_eq!(
+				Currencies::slash_reserved(CurrencyId::Erc20(erc20_address()), &alice(), 100),
 				10
 			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE - 100
+			);
+
+			// the slashed tokens are left in the holding account and no longer escrowed
+			let erc20_holding_account = MockAddressMapping::get_account_id(&Erc20HoldingAccount::get());
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				100
+			);
+			assert_eq!(Currencies::erc20_total_escrowed(CurrencyId::Erc20(erc20_address())), 0);
 		});
 }
 
@@ -945,6 +969,26 @@ fn erc20_reserve_should_work() {
 				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
 				ALICE_BALANCE - 100
 			);
+
+			// the reserved tokens are escrowed under the holding account
+			let erc20_holding_account = MockAddressMapping::get_account_id(&Erc20HoldingAccount::get());
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				100
+			);
+			assert_eq!(
+				Currencies::erc20_total_escrowed(CurrencyId::Erc20(erc20_address())),
+				100
+			);
+			assert_ok!(Currencies::ensure_erc20_escrow_consistent(CurrencyId::Erc20(
+				erc20_address()
+			)));
+
+			// the escrowed tokens can't be deposited to others
+			assert_noop!(
+				Currencies::deposit(CurrencyId::Erc20(erc20_address()), &bob(), 1),
+				Error::<Runtime>::DepositFailed
+			);
 		});
 }
 
@@ -1021,24 +1065,215 @@ fn erc20_should_not_slash() {
 }
 
 #[test]
-fn erc20_should_not_be_lockable() {
+fn erc20_lock_should_work() {
 	ExtBuilder::default()
 		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 200000)])
 		.build()
 		.execute_with(|| {
 			deploy_contracts();
-			assert_noop!(
-				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+			let erc20_holding_account = MockAddressMapping::get_account_id(&Erc20HoldingAccount::get());
+
+			assert_ok!(Currencies::set_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				100
+			));
+			assert_eq!(
+				Currencies::erc20_locks(CurrencyId::Erc20(erc20_address()), alice()).into_inner(),
+				vec![Erc20BalanceLock { id: ID_1, amount: 100 }]
+			);
+			// locked tokens are still free balance, but can't be withdrawn
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE
+			);
+			assert_eq!(
+				Currencies::erc20_locked_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
 			);
 			assert_noop!(
-				Currencies::extend_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::ensure_can_withdraw(CurrencyId::Erc20(erc20_address()), &alice(), ALICE_BALANCE - 99),
+				Error::<Runtime>::BalanceTooLow
 			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				100
+			);
+
+			// the largest lock is escrowed
+			assert_ok!(Currencies::set_lock(
+				ID_2,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				50
+			));
+			assert_eq!(
+				Currencies::erc20_locked_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
+			);
+			assert_ok!(Currencies::extend_lock(
+				ID_2,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				150
+			));
+			assert_eq!(
+				Currencies::erc20_locked_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				150
+			);
+			assert_eq!(
+				Currencies::erc20_total_escrowed(CurrencyId::Erc20(erc20_address())),
+				150
+			);
+
+			assert_ok!(Currencies::remove_lock(
+				ID_2,
+				CurrencyId::Erc20(erc20_address()),
+				&alice()
+			));
+			assert_eq!(
+				Currencies::erc20_locked_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				100
+			);
+
+			assert_ok!(Currencies::remove_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice()
+			));
+			assert!(Currencies::erc20_locks(CurrencyId::Erc20(erc20_address()), alice()).is_empty());
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				0
+			);
+			assert_eq!(Currencies::erc20_total_escrowed(CurrencyId::Erc20(erc20_address())), 0);
+
+			// can't lock more than the usable balance
+			assert!(
+				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), ALICE_BALANCE + 1).is_err()
+			);
+		});
+}
+
+#[test]
+fn erc20_escrow_consistency_check_should_work() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 200000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			assert_ok!(Currencies::reserve(CurrencyId::Erc20(erc20_address()), &alice(), 100));
+
+			// the ledger claims more than the holding account has
+			Erc20TotalEscrowed::<Runtime>::insert(CurrencyId::Erc20(erc20_address()), 101);
 			assert_noop!(
-				Currencies::remove_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice()),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::ensure_erc20_escrow_consistent(CurrencyId::Erc20(erc20_address())),
+				Error::<Runtime>::Erc20EscrowMismatch
+			);
+			// nothing is released from an inconsistent escrow
+			assert_eq!(
+				Currencies::unreserve(CurrencyId::Erc20(erc20_address()), &alice(), 50),
+				50
+			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
+			);
+
+			Erc20TotalEscrowed::<Runtime>::insert(CurrencyId::Erc20(erc20_address()), 100);
+			assert_eq!(
+				Currencies::unreserve(CurrencyId::Erc20(erc20_address()), &alice(), 50),
+				0
+			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				50
+			);
+			assert_eq!(Currencies::erc20_total_escrowed(CurrencyId::Erc20(erc20_address())), 50);
+		});
+}
+
+#[test]
+fn erc20_legacy_reserve_should_work() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 200000),
+			(bob(), NATIVE_CURRENCY_ID, 100000),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let currency_id = CurrencyId::Erc20(erc20_address());
+			let legacy_address = reserve_address(alice_evm_addr());
+
+			// reserved under the legacy reserve address before the escrow
+			assert_ok!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				legacy_address,
+				100
+			));
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 100);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 100);
+			assert_eq!(Currencies::total_balance(currency_id, &alice()), ALICE_BALANCE);
+			assert_eq!(Currencies::erc20_total_escrowed(currency_id), 0);
+
+			// the legacy reserve is moved into the escrow once repatriated
+			assert_eq!(
+				Currencies::repatriate_reserved(currency_id, &alice(), &bob(), 40, BalanceStatus::Reserved),
+				Ok(0)
 			);
+			assert_eq!(Currencies::erc20_balance_of(erc20_address(), legacy_address), 0);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 60);
+			assert_eq!(Currencies::reserved_balance(currency_id, &bob()), 40);
+			assert_eq!(Currencies::erc20_total_escrowed(currency_id), 100);
+			assert_ok!(Currencies::ensure_erc20_escrow_consistent(currency_id));
+
+			assert_eq!(Currencies::unreserve(currency_id, &alice(), 60), 0);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 40);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 0);
+			assert_eq!(Currencies::erc20_total_escrowed(currency_id), 40);
+
+			// the legacy reserve is moved into the escrow once unreserved
+			assert_ok!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				legacy_address,
+				30
+			));
+			assert_eq!(Currencies::unreserve(currency_id, &alice(), 10), 0);
+			assert_eq!(Currencies::free_balance(currency_id, &alice()), ALICE_BALANCE - 60);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 20);
+			assert_eq!(Currencies::erc20_total_escrowed(currency_id), 60);
+
+			// the legacy reserve is moved into the escrow once slashed
+			assert_ok!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				legacy_address,
+				30
+			));
+			assert_eq!(Currencies::slash_reserved(currency_id, &alice(), 60), 10);
+			assert_eq!(Currencies::reserved_balance(currency_id, &alice()), 0);
+			assert_eq!(Currencies::erc20_total_escrowed(currency_id), 40);
 		});
 }
 
@@ -1251,7 +1486,7 @@ fn erc20_withdraw_deposit_works() {
 			// deposit failed, because erc20 holding account balance not enough
 			assert_noop!(
 				Currencies::deposit(CurrencyId::Erc20(erc20_address()), &bob(), 101),
-				module_evm_bridge::Error::<Runtime>::ExecutionRevert
+				Error::<Runtime>::DepositFailed
 			);
 		});
 }
@@ -3018,3 +3253,102 @@ fn transfer_erc20_will_charge_gas() {
 		);
 	});
 }
+
+#[test]
+fn allowance_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			Currencies::approve(X_TOKEN_ID, &alice(), &bob(), 50);
+			System::assert_last_event(RuntimeEvent::Currencies(crate::Event::Approval {
+				currency_id: X_TOKEN_ID,
+				owner: alice(),
+				spender: bob(),
+				amount: 50,
+			}));
+			assert_eq!(Currencies::allowance(X_TOKEN_ID, &alice(), &bob()), 50);
+			assert_eq!(Currencies::allowance(NATIVE_CURRENCY_ID, &alice(), &bob()), 0);
+
+			assert_eq!(Currencies::increase_allowance(X_TOKEN_ID, &alice(), &bob(), 10), 60);
+			assert_eq!(Currencies::decrease_allowance(X_TOKEN_ID, &alice(), &bob(), 20), Ok(40));
+			assert_noop!(
+				Currencies::decrease_allowance(X_TOKEN_ID, &alice(), &bob(), 41),
+				Error::<Runtime>::InsufficientAllowance
+			);
+
+			assert_noop!(
+				Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 41),
+				Error::<Runtime>::InsufficientAllowance
+			);
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &bob(), &alice(), &CHARLIE, 30));
+			assert_eq!(Currencies::allowance(X_TOKEN_ID, &alice(), &bob()), 10);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 70);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &CHARLIE), 30);
+
+			// the owner doesn't need allowance
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &alice(), &alice(), &CHARLIE, 20));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 50);
+
+			// max allowance is never spent
+			Currencies::approve(NATIVE_CURRENCY_ID, &alice(), &bob(), Bounded::max_value());
+			assert_ok!(Currencies::transfer_from(
+				NATIVE_CURRENCY_ID,
+				&bob(),
+				&alice(),
+				&CHARLIE,
+				30
+			));
+			assert_eq!(
+				Currencies::allowance(NATIVE_CURRENCY_ID, &alice(), &bob()),
+				Bounded::max_value()
+			);
+			assert_eq!(NativeCurrency::free_balance(&alice()), 70);
+		});
+}
+
+#[test]
+fn use_permit_nonce_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Currencies::permit_nonces(X_TOKEN_ID, alice()), 0);
+		assert_eq!(Currencies::use_permit_nonce(X_TOKEN_ID, &alice()), 0);
+		assert_eq!(Currencies::use_permit_nonce(X_TOKEN_ID, &alice()), 1);
+		assert_eq!(Currencies::permit_nonces(X_TOKEN_ID, alice()), 2);
+		assert_eq!(Currencies::permit_nonces(NATIVE_CURRENCY_ID, alice()), 0);
+	});
+}
+
+#[test]
+fn frozen_asset_transfer_should_fail() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), X_TOKEN_ID, 200000), (alice(), DOT, 200000)])
+		.build()
+		.execute_with(|| {
+			MockFrozenCurrencyId::set(Some(X_TOKEN_ID));
+
+			assert_noop!(
+				Currencies::transfer(RuntimeOrigin::signed(alice()), bob(), X_TOKEN_ID, 10000),
+				Error::<Runtime>::AssetFrozen
+			);
+			assert_noop!(
+				<Currencies as fungibles::Mutate<_>>::transfer(
+					X_TOKEN_ID,
+					&alice(),
+					&bob(),
+					10000,
+					Preservation::Preserve
+				),
+				Error::<Runtime>::AssetFrozen
+			);
+			assert_ok!(Currencies::transfer(RuntimeOrigin::signed(alice()), bob(), DOT, 10000));
+
+			MockFrozenCurrencyId::set(None);
+			assert_ok!(Currencies::transfer(
+				RuntimeOrigin::signed(alice()),
+				bob(),
+				X_TOKEN_ID,
+				10000
+			));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 10000);
+		});
+}
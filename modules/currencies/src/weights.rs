@@ -66,13 +66,15 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetStatuses (r:1 w:0)
+	// Proof Skipped: AssetRegistry AssetStatuses (max_values: None, max_size: None, mode: Measured)
 	fn transfer_non_native_currency() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2493`
 		//  Estimated: `13352`
 		// Minimum execution time: 86_216 nanoseconds.
 		Weight::from_parts(88_106_000, 13352)
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: System Account (r:1 w:1)
@@ -186,13 +188,15 @@ impl WeightInfo for () {
 	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetStatuses (r:1 w:0)
+	// Proof Skipped: AssetRegistry AssetStatuses (max_values: None, max_size: None, mode: Measured)
 	fn transfer_non_native_currency() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2493`
 		//  Estimated: `13352`
 		// Minimum execution time: 86_216 nanoseconds.
 		Weight::from_parts(88_106_000, 13352)
-			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().reads(5))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 	// Storage: System Account (r:1 w:1)
@@ -35,7 +35,9 @@
 
 use frame_support::{pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use module_support::{DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio, SwapLimit};
+use module_support::{
+	AssetStatusProvider, DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio, SwapLimit,
+};
 use orml_traits::{Happened, MultiCurrency, MultiCurrencyExtended};
 use parity_scale_codec::MaxEncodedLen;
 use primitives::{Balance, CurrencyId, TradingPair};
@@ -133,6 +135,9 @@ pub mod module {
 
 		/// Event handler which calls when update liquidity pool.
 		type OnLiquidityPoolUpdated: Happened<(TradingPair, Balance, Balance)>;
+
+		/// The lifecycle status of the assets, only active assets can be listed.
+		type AssetStatusProvider: AssetStatusProvider<CurrencyId>;
 	}
 
 	#[pallet::error]
@@ -183,6 +188,8 @@ pub mod module {
 		NotAllowedRefund,
 		/// Cannot swap
 		CannotSwap,
+		/// The asset is deprecated or frozen.
+		AssetNotActive,
 	}
 
 	#[pallet::event]
@@ -539,6 +546,7 @@ pub mod module {
 			};
 			check_asset_registry(currency_id_a)?;
 			check_asset_registry(currency_id_b)?;
+			Self::ensure_assets_active(trading_pair)?;
 
 			let (min_contribution, target_provision) = if currency_id_a == trading_pair.first() {
 				(
@@ -713,6 +721,7 @@ pub mod module {
 				}
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
 			}
+			Self::ensure_assets_active(trading_pair)?;
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
 			Self::deposit_event(Event::EnableTradingPair { trading_pair });
@@ -859,6 +868,15 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	fn ensure_assets_active(trading_pair: TradingPair) -> DispatchResult {
+		ensure!(
+			T::AssetStatusProvider::is_active(trading_pair.first())
+				&& T::AssetStatusProvider::is_active(trading_pair.second()),
+			Error::<T>::AssetNotActive
+		);
+		Ok(())
+	}
+
 	fn do_claim_dex_share(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -28,7 +28,7 @@ use frame_support::{
 use frame_system::EnsureSignedBy;
 use module_support::{mocks::MockErc20InfoMapping, SpecificJointsSwap};
 use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
-use primitives::{Amount, TokenSymbol};
+use primitives::{currency::AssetStatus, Amount, TokenSymbol};
 use sp_core::H256;
 use sp_runtime::{traits::IdentityLookup, BuildStorage};
 use sp_std::cell::RefCell;
@@ -138,6 +138,20 @@ impl Happened<(TradingPair, Balance, Balance)> for MockOnLiquidityPoolUpdated {
 	}
 }
 
+parameter_types! {
+	pub static MockDeprecatedCurrencyId: Option<CurrencyId> = None;
+}
+
+impl AssetStatusProvider<CurrencyId> for MockDeprecatedCurrencyId {
+	fn asset_status(currency_id: CurrencyId) -> AssetStatus {
+		if Self::get() == Some(currency_id) {
+			AssetStatus::Deprecated
+		} else {
+			AssetStatus::Active
+		}
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Tokens;
@@ -150,6 +164,7 @@ impl Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<2000>;
 	type OnLiquidityPoolUpdated = MockOnLiquidityPoolUpdated;
+	type AssetStatusProvider = MockDeprecatedCurrencyId;
 }
 
 parameter_types! {
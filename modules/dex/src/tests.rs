@@ -23,8 +23,9 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, ExtBuilder, ListingOrigin, Runtime,
-	RuntimeEvent, RuntimeOrigin, System, Tokens, ACA, ALICE, AUSD, AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
+	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, ExtBuilder, ListingOrigin,
+	MockDeprecatedCurrencyId, Runtime, RuntimeEvent, RuntimeOrigin, System, Tokens, ACA, ALICE, AUSD,
+	AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
 };
 use module_support::{Swap, SwapError};
 use orml_traits::MultiReservableCurrency;
@@ -1993,3 +1994,40 @@ fn specific_joint_swap_work() {
 			);
 		});
 }
+
+#[test]
+fn list_or_enable_trading_pair_of_deprecated_asset_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MockDeprecatedCurrencyId::set(Some(DOT));
+
+		assert_noop!(
+			DexModule::list_provisioning(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				1_000_000_000_000u128,
+				1_000_000_000_000u128,
+				5_000_000_000_000u128,
+				2_000_000_000_000u128,
+				10,
+			),
+			Error::<Runtime>::AssetNotActive
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(RuntimeOrigin::signed(ListingOrigin::get()), DOT, BTC),
+			Error::<Runtime>::AssetNotActive
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC
+		));
+
+		MockDeprecatedCurrencyId::set(None);
+		assert_ok!(DexModule::enable_trading_pair(
+			RuntimeOrigin::signed(ListingOrigin::get()),
+			DOT,
+			BTC
+		));
+	});
+}
@@ -24,14 +24,20 @@
 use frame_support::{
 	pallet_prelude::*,
 	traits::{Currency, ExistenceRequirement, LockIdentifier, LockableCurrency, OnUnbalanced, WithdrawReasons},
+	transactional,
 };
 use frame_system::pallet_prelude::*;
+use module_support::{EmissionVotes, IncentivesManager, PoolId};
 use orml_traits::{define_parameters, parameters::ParameterStore, Happened};
 use primitives::{
 	bonding::{self, BondingController},
-	Balance,
+	Balance, CurrencyId,
 };
-use sp_runtime::{traits::Saturating, Permill};
+use sp_runtime::{
+	traits::{Saturating, UniqueSaturatedInto, Zero},
+	PerThing, Permill, Perquintill,
+};
+use sp_std::{cmp::Ordering, prelude::*};
 
 pub use module::*;
 
@@ -44,6 +50,8 @@ pub use weights::WeightInfo;
 define_parameters! {
 	pub Parameters = {
 		InstantUnstakeFee: Permill = 0,
+		AutoCompoundFee: Permill = 1,
+		VeEarlyExitPenalty: Permill = 2,
 	}
 }
 
@@ -51,6 +59,19 @@ define_parameters! {
 pub mod module {
 	use super::*;
 
+	/// The vote-escrowed lock of native currency.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+	pub struct VeLock<BlockNumber> {
+		/// The amount of locked native currency.
+		#[codec(compact)]
+		pub amount: Balance,
+		/// The block number at which the lock expires.
+		pub end: BlockNumber,
+		/// The vote weight recorded at the last checkpoint.
+		#[codec(compact)]
+		pub weight: Balance,
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -62,6 +83,13 @@ pub mod module {
 		type OnBonded: Happened<(Self::AccountId, Balance)>;
 		type OnUnbonded: Happened<(Self::AccountId, Balance)>;
 		type OnUnstakeFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		type OnAutoCompoundFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The incentives manager to claim the rewards of `PoolId::Earning`.
+		type IncentivesManager: IncentivesManager<Self::AccountId, Balance, CurrencyId, PoolId>;
+
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
 
 		#[pallet::constant]
 		type MinBond: Get<Balance>;
@@ -71,6 +99,19 @@ pub mod module {
 		type MaxUnbondingChunks: Get<u32>;
 		#[pallet::constant]
 		type LockIdentifier: Get<LockIdentifier>;
+		/// The maximum number of accounts to auto-compound in `on_idle` per block.
+		#[pallet::constant]
+		type MaxAutoCompoundPerBlock: Get<u32>;
+		/// The maximum duration of the ve lock, the vote weight of the lock with the maximum
+		/// duration is equal to the locked amount.
+		#[pallet::constant]
+		type MaxVeLockDuration: Get<BlockNumberFor<Self>>;
+		/// The maximum number of `PoolId::Dex` an account can vote for.
+		#[pallet::constant]
+		type MaxDexVotes: Get<u32>;
+		/// The maximum number of ve locks to checkpoint in `on_idle` per block.
+		#[pallet::constant]
+		type MaxVeCheckpointPerBlock: Get<u32>;
 
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
@@ -86,6 +127,21 @@ pub mod module {
 		MaxUnlockChunksExceeded,
 		NotBonded,
 		NotAllowed,
+		/// The lock duration is zero or exceeds MaxVeLockDuration
+		InvalidLockDuration,
+		/// The account already has a ve lock
+		VeLockExists,
+		/// The account has no ve lock
+		NoVeLock,
+		/// The ve lock has expired
+		VeLockExpired,
+		/// The available balance is not enough
+		NotEnoughBalance,
+		/// The number of votes exceeds MaxDexVotes
+		TooManyVotes,
+		/// The votes are not for `PoolId::Dex` of dex share, contain duplicate pools, or their sum
+		/// exceeds 100%
+		InvalidVotes,
 	}
 
 	#[pallet::event]
@@ -112,6 +168,31 @@ pub mod module {
 			who: T::AccountId,
 			amount: Balance,
 		},
+		AutoCompoundUpdated {
+			who: T::AccountId,
+			enabled: bool,
+		},
+		AutoCompounded {
+			who: T::AccountId,
+			reward: Balance,
+			fee: Balance,
+			bonded: Balance,
+		},
+		VeLockUpdated {
+			who: T::AccountId,
+			amount: Balance,
+			end: BlockNumberFor<T>,
+			weight: Balance,
+		},
+		VeUnlocked {
+			who: T::AccountId,
+			amount: Balance,
+			penalty: Balance,
+		},
+		DexIncentivesVoted {
+			who: T::AccountId,
+			votes: Vec<(PoolId, Permill)>,
+		},
 	}
 
 	/// The earning bonding ledger.
@@ -121,12 +202,66 @@ pub mod module {
 	#[pallet::getter(fn ledger)]
 	pub type Ledger<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BondingLedgerOf<T>, OptionQuery>;
 
+	/// The accounts which enabled auto-compound of the earning rewards.
+	///
+	/// AutoCompound: map AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound)]
+	pub type AutoCompound<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The raw key of AutoCompound where the next batch of auto-compound starts from.
+	///
+	/// AutoCompoundCursor: Vec<u8>
+	#[pallet::storage]
+	pub type AutoCompoundCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The raw key of VeLocks to continue the checkpoint of ve locks in the next block.
+	///
+	/// VeCheckpointCursor: Vec<u8>
+	#[pallet::storage]
+	pub type VeCheckpointCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The vote-escrowed locks.
+	///
+	/// VeLocks: map AccountId => Option<VeLock>
+	#[pallet::storage]
+	#[pallet::getter(fn ve_locks)]
+	pub type VeLocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, VeLock<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The votes of the account to direct the incentive emissions among `PoolId::Dex`.
+	///
+	/// DexVotes: map AccountId => Vec<(PoolId, Permill)>
+	#[pallet::storage]
+	#[pallet::getter(fn dex_votes)]
+	pub type DexVotes<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Vec<(PoolId, Permill)>, ValueQuery>;
+
+	/// The vote weight of `PoolId::Dex`.
+	///
+	/// DexVoteWeights: map PoolId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn dex_vote_weights)]
+	pub type DexVoteWeights<T: Config> = StorageMap<_, Twox64Concat, PoolId, Balance, ValueQuery>;
+
+	/// The total vote weight of all `PoolId::Dex`.
+	///
+	/// TotalDexVoteWeight: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_dex_vote_weight)]
+	pub type TotalDexVoteWeight<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used_weight = Self::process_auto_compound(remaining_weight);
+			used_weight.saturating_add(Self::process_ve_checkpoint(
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -194,6 +329,7 @@ pub mod module {
 				T::OnUnstakeFee::on_unbalanced(unbalance);
 
 				T::OnUnbonded::happened(&(who.clone(), final_amount));
+				Self::remove_auto_compound_if_unbonded(&who);
 				Self::deposit_event(Event::InstantUnbonded {
 					who,
 					amount: final_amount,
@@ -235,6 +371,7 @@ pub mod module {
 				<Self as BondingController>::withdraw_unbonded(&who, frame_system::Pallet::<T>::block_number())?;
 
 			if let Some(change) = change {
+				Self::remove_auto_compound_if_unbonded(&who);
 				Self::deposit_event(Event::Withdrawn {
 					who,
 					amount: change.change,
@@ -243,10 +380,399 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Enable or disable auto-compound of the earning rewards.
+		/// The pending rewards of enabled accounts will be claimed and bonded in `on_idle`, by
+		/// paying a `AutoCompoundFee` fee.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if enabled {
+				ensure!(Ledger::<T>::contains_key(&who), Error::<T>::NotBonded);
+				AutoCompound::<T>::insert(&who, ());
+			} else {
+				AutoCompound::<T>::remove(&who);
+			}
+
+			Self::deposit_event(Event::AutoCompoundUpdated { who, enabled });
+			Ok(())
+		}
+
+		/// Lock `amount` tokens for `duration` blocks to receive the vote weight, which decays
+		/// linearly to zero when the lock expires.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::ve_lock().saturating_add(Pallet::<T>::dex_vote_weights_update_weight()))]
+		pub fn ve_lock(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: Balance,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!VeLocks::<T>::contains_key(&who), Error::<T>::VeLockExists);
+			ensure!(amount >= T::MinBond::get(), Error::<T>::BelowMinBondThreshold);
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxVeLockDuration::get(),
+				Error::<T>::InvalidLockDuration
+			);
+
+			let lock = VeLock {
+				amount: Zero::zero(),
+				end: frame_system::Pallet::<T>::block_number().saturating_add(duration),
+				weight: Zero::zero(),
+			};
+			Self::do_update_ve_lock(&who, lock, amount)
+		}
+
+		/// Increase the locked amount of the unexpired ve lock by `amount` tokens.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::ve_increase_amount().saturating_add(Pallet::<T>::dex_vote_weights_update_weight())
+		)]
+		pub fn ve_increase_amount(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let lock = VeLocks::<T>::get(&who).ok_or(Error::<T>::NoVeLock)?;
+			ensure!(
+				lock.end > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::VeLockExpired
+			);
+
+			Self::do_update_ve_lock(&who, lock, amount)
+		}
+
+		/// Extend the ve lock to expire after `duration` blocks from now.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::ve_extend().saturating_add(Pallet::<T>::dex_vote_weights_update_weight()))]
+		pub fn ve_extend(origin: OriginFor<T>, duration: BlockNumberFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut lock = VeLocks::<T>::get(&who).ok_or(Error::<T>::NoVeLock)?;
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			ensure!(
+				end > lock.end && duration <= T::MaxVeLockDuration::get(),
+				Error::<T>::InvalidLockDuration
+			);
+			lock.end = end;
+
+			Self::do_update_ve_lock(&who, lock, Zero::zero())
+		}
+
+		/// Unlock all tokens of the ve lock.
+		/// If the lock has not expired, a `VeEarlyExitPenalty` penalty is charged pro rata to
+		/// the remaining lock duration.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::ve_withdraw().saturating_add(Pallet::<T>::dex_vote_weights_update_weight()))]
+		pub fn ve_withdraw(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut lock = VeLocks::<T>::get(&who).ok_or(Error::<T>::NoVeLock)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let amount = lock.amount;
+			let penalty = if lock.end > now {
+				let penalty_ratio = T::ParameterStore::get(VeEarlyExitPenalty).ok_or(Error::<T>::NotAllowed)?;
+				penalty_ratio.mul_ceil(Self::ve_weight(amount, lock.end, now))
+			} else {
+				Zero::zero()
+			};
+
+			lock.amount = Zero::zero();
+			Self::checkpoint_ve_weight(&who, &mut lock, now);
+			VeLocks::<T>::remove(&who);
+			DexVotes::<T>::remove(&who);
+			Self::update_lock(&who, Self::ledger(&who).unwrap_or_default().total());
+
+			if !penalty.is_zero() {
+				let unbalance = T::Currency::withdraw(
+					&who,
+					penalty,
+					WithdrawReasons::TRANSFER,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				T::OnUnstakeFee::on_unbalanced(unbalance);
+			}
+
+			Self::deposit_event(Event::VeUnlocked {
+				who,
+				amount: amount.saturating_sub(penalty),
+				penalty,
+			});
+			Ok(())
+		}
+
+		/// Recalculate the decayed vote weight of the ve lock of `who`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::ve_checkpoint().saturating_add(Pallet::<T>::dex_vote_weights_update_weight()))]
+		pub fn ve_checkpoint(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let lock = VeLocks::<T>::get(&who).ok_or(Error::<T>::NoVeLock)?;
+			Self::do_update_ve_lock(&who, lock, Zero::zero())
+		}
+
+		/// Vote to direct the incentive emissions among `PoolId::Dex` with the vote weight of
+		/// the ve lock, replacing the previous votes.
+		///
+		/// - `votes`: the pools and the ratio of the vote weight for each pool.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::vote_dex_incentives(votes.len() as u32))]
+		pub fn vote_dex_incentives(origin: OriginFor<T>, votes: Vec<(PoolId, Permill)>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(votes.len() as u32 <= T::MaxDexVotes::get(), Error::<T>::TooManyVotes);
+			let total_ratio = votes
+				.iter()
+				.enumerate()
+				.try_fold(0u32, |total, (index, (pool_id, ratio))| {
+					ensure!(
+						matches!(pool_id, PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id()),
+						Error::<T>::InvalidVotes
+					);
+					ensure!(
+						!votes[..index].iter().any(|(voted_pool_id, _)| voted_pool_id == pool_id),
+						Error::<T>::InvalidVotes
+					);
+					Ok::<u32, Error<T>>(total.saturating_add(ratio.deconstruct()))
+				})?;
+			ensure!(total_ratio <= Permill::ACCURACY, Error::<T>::InvalidVotes);
+
+			// vote with the decayed vote weight, the expired lock has no vote weight.
+			let mut lock = VeLocks::<T>::get(&who).ok_or(Error::<T>::NoVeLock)?;
+			Self::checkpoint_ve_weight(&who, &mut lock, frame_system::Pallet::<T>::block_number());
+			VeLocks::<T>::insert(&who, &lock);
+			Self::update_dex_vote_weights(&Self::dex_votes(&who), lock.weight, Zero::zero());
+			Self::update_dex_vote_weights(&votes, Zero::zero(), lock.weight);
+			if votes.is_empty() {
+				DexVotes::<T>::remove(&who);
+			} else {
+				DexVotes::<T>::insert(&who, &votes);
+			}
+
+			Self::deposit_event(Event::DexIncentivesVoted { who, votes });
+			Ok(())
+		}
 	}
 }
 
-impl<T: Config> Pallet<T> {}
+impl<T: Config> Pallet<T> {
+	/// Auto-compound the accounts in AutoCompound in a batch bounded by `remaining_weight` and
+	/// `MaxAutoCompoundPerBlock`, the next batch continues from where this batch stops.
+	pub(crate) fn process_auto_compound(remaining_weight: Weight) -> Weight {
+		let base_weight = T::DbWeight::get().reads_writes(1, 1);
+		let weight_per_account = T::WeightInfo::auto_compound();
+		if remaining_weight.any_lt(base_weight.saturating_add(weight_per_account)) {
+			return Weight::zero();
+		}
+
+		let max_accounts: u64 = remaining_weight
+			.saturating_sub(base_weight)
+			.checked_div_per_component(&weight_per_account)
+			.unwrap_or(u64::MAX)
+			.min(T::MaxAutoCompoundPerBlock::get().into());
+
+		let mut iterator = match AutoCompoundCursor::<T>::take() {
+			Some(key) => AutoCompound::<T>::iter_keys_from(key),
+			None => AutoCompound::<T>::iter_keys(),
+		};
+
+		let mut count: u64 = 0;
+		while count < max_accounts {
+			match iterator.next() {
+				Some(who) => {
+					count += 1;
+					if let Err(e) = Self::do_auto_compound(&who) {
+						log::warn!(
+							target: "earning",
+							"auto-compound for {:?} failed: {:?}",
+							who, e
+						);
+					}
+				}
+				None => break,
+			}
+		}
+
+		if count == max_accounts {
+			AutoCompoundCursor::<T>::put(iterator.last_raw_key());
+		}
+
+		base_weight.saturating_add(weight_per_account.saturating_mul(count))
+	}
+
+	/// Checkpoint the ve locks in a batch bounded by `remaining_weight` and
+	/// `MaxVeCheckpointPerBlock` to decay their shares of `PoolId::Earning` and their votes even
+	/// if the owners never update them, the next batch continues from where this batch stops.
+	pub(crate) fn process_ve_checkpoint(remaining_weight: Weight) -> Weight {
+		let base_weight = T::DbWeight::get().reads_writes(1, 1);
+		let weight_per_lock = T::WeightInfo::ve_checkpoint().saturating_add(Self::dex_vote_weights_update_weight());
+		if remaining_weight.any_lt(base_weight.saturating_add(weight_per_lock)) {
+			return Weight::zero();
+		}
+
+		let max_locks: u64 = remaining_weight
+			.saturating_sub(base_weight)
+			.checked_div_per_component(&weight_per_lock)
+			.unwrap_or(u64::MAX)
+			.min(T::MaxVeCheckpointPerBlock::get().into());
+
+		let mut iterator = match VeCheckpointCursor::<T>::take() {
+			Some(key) => VeLocks::<T>::iter_from(key),
+			None => VeLocks::<T>::iter(),
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut count: u64 = 0;
+		while count < max_locks {
+			match iterator.next() {
+				Some((who, mut lock)) => {
+					count += 1;
+					if Self::ve_weight(lock.amount, lock.end, now) != lock.weight {
+						Self::checkpoint_ve_weight(&who, &mut lock, now);
+						VeLocks::<T>::insert(&who, &lock);
+					}
+				}
+				None => break,
+			}
+		}
+
+		if count == max_locks {
+			VeCheckpointCursor::<T>::put(iterator.last_raw_key());
+		}
+
+		base_weight.saturating_add(weight_per_lock.saturating_mul(count))
+	}
+
+	/// Claim the pending rewards of `PoolId::Earning` for `who`, charge the `AutoCompoundFee` fee
+	/// and bond the remaining rewards.
+	#[transactional]
+	pub fn do_auto_compound(who: &T::AccountId) -> DispatchResult {
+		let free_balance = T::Currency::free_balance(who);
+		T::IncentivesManager::claim_rewards(who.clone(), PoolId::Earning(T::GetNativeCurrencyId::get()))?;
+		let reward = T::Currency::free_balance(who).saturating_sub(free_balance);
+		if reward.is_zero() {
+			return Ok(());
+		}
+
+		let fee = T::ParameterStore::get(AutoCompoundFee)
+			.unwrap_or_default()
+			.mul_ceil(reward);
+		if !fee.is_zero() {
+			let unbalance =
+				T::Currency::withdraw(who, fee, WithdrawReasons::TRANSFER, ExistenceRequirement::KeepAlive)?;
+			T::OnAutoCompoundFee::on_unbalanced(unbalance);
+		}
+
+		let bonded = match <Self as BondingController>::bond(who, reward.saturating_sub(fee))? {
+			Some(change) => {
+				T::OnBonded::happened(&(who.clone(), change.change));
+				change.change
+			}
+			None => Zero::zero(),
+		};
+
+		Self::deposit_event(Event::AutoCompounded {
+			who: who.clone(),
+			reward,
+			fee,
+			bonded,
+		});
+		Ok(())
+	}
+
+	fn remove_auto_compound_if_unbonded(who: &T::AccountId) {
+		if !Ledger::<T>::contains_key(who) {
+			AutoCompound::<T>::remove(who);
+		}
+	}
+
+	/// The vote weight of `amount` tokens locked until `end`.
+	/// Note: weight = amount * (end - now) / MaxVeLockDuration
+	pub fn ve_weight(amount: Balance, end: BlockNumberFor<T>, now: BlockNumberFor<T>) -> Balance {
+		let remaining: u64 = end.saturating_sub(now).unique_saturated_into();
+		let max_duration: u64 = T::MaxVeLockDuration::get().unique_saturated_into();
+		Perquintill::from_rational(remaining.min(max_duration), max_duration).mul_floor(amount)
+	}
+
+	/// Lock `increase` more tokens for the ve lock, then recalculate the vote weight.
+	fn do_update_ve_lock(who: &T::AccountId, mut lock: VeLock<BlockNumberFor<T>>, increase: Balance) -> DispatchResult {
+		let ledger = Self::ledger(who).unwrap_or_default();
+		ensure!(
+			increase <= <Self as BondingController>::available_balance(who, &ledger),
+			Error::<T>::NotEnoughBalance
+		);
+
+		lock.amount = lock.amount.saturating_add(increase);
+		Self::checkpoint_ve_weight(who, &mut lock, frame_system::Pallet::<T>::block_number());
+		VeLocks::<T>::insert(who, &lock);
+		Self::update_lock(who, ledger.total());
+
+		Self::deposit_event(Event::VeLockUpdated {
+			who: who.clone(),
+			amount: lock.amount,
+			end: lock.end,
+			weight: lock.weight,
+		});
+		Ok(())
+	}
+
+	/// Recalculate the vote weight of the ve lock at `now`, and apply the change of the weight
+	/// to the shares of `PoolId::Earning` and the votes of `PoolId::Dex`.
+	fn checkpoint_ve_weight(who: &T::AccountId, lock: &mut VeLock<BlockNumberFor<T>>, now: BlockNumberFor<T>) {
+		let weight = Self::ve_weight(lock.amount, lock.end, now);
+		match weight.cmp(&lock.weight) {
+			Ordering::Greater => T::OnBonded::happened(&(who.clone(), weight.saturating_sub(lock.weight))),
+			Ordering::Less => T::OnUnbonded::happened(&(who.clone(), lock.weight.saturating_sub(weight))),
+			Ordering::Equal => {}
+		}
+
+		Self::update_dex_vote_weights(&Self::dex_votes(who), lock.weight, weight);
+		lock.weight = weight;
+	}
+
+	/// The weight of rewriting `DexVoteWeights` of up to `MaxDexVotes` pools and
+	/// `TotalDexVoteWeight` when the vote weight of a ve lock changes, which is not covered by
+	/// the benchmarks of the ve calls.
+	pub(crate) fn dex_vote_weights_update_weight() -> Weight {
+		let max_votes: u64 = T::MaxDexVotes::get().into();
+		T::DbWeight::get().reads_writes(max_votes.saturating_add(1), max_votes.saturating_add(1))
+	}
+
+	fn update_dex_vote_weights(votes: &[(PoolId, Permill)], old_weight: Balance, new_weight: Balance) {
+		for (pool_id, ratio) in votes {
+			let (old_vote, new_vote) = (ratio.mul_floor(old_weight), ratio.mul_floor(new_weight));
+			DexVoteWeights::<T>::mutate_exists(pool_id, |maybe_weight| {
+				let weight = maybe_weight
+					.unwrap_or_default()
+					.saturating_sub(old_vote)
+					.saturating_add(new_vote);
+				*maybe_weight = if weight.is_zero() { None } else { Some(weight) };
+			});
+			TotalDexVoteWeight::<T>::mutate(|total| *total = total.saturating_sub(old_vote).saturating_add(new_vote));
+		}
+	}
+
+	/// Set the lock of native currency for the bonded amount and the ve locked amount.
+	fn update_lock(who: &T::AccountId, bonded: Balance) {
+		let total = bonded.saturating_add(Self::ve_locks(who).map(|lock| lock.amount).unwrap_or_default());
+		if total.is_zero() {
+			T::Currency::remove_lock(T::LockIdentifier::get(), who);
+		} else {
+			T::Currency::set_lock(T::LockIdentifier::get(), who, total, WithdrawReasons::all());
+		}
+	}
+}
+
+impl<T: Config> EmissionVotes for Pallet<T> {
+	fn get_pool_vote_weight(pool_id: &PoolId) -> Balance {
+		Self::dex_vote_weights(pool_id)
+	}
+
+	fn get_total_vote_weight() -> Balance {
+		Self::total_dex_vote_weight()
+	}
+}
 
 impl<T: Config> BondingController for Pallet<T> {
 	type MinBond = T::MinBond;
@@ -258,15 +784,12 @@ impl<T: Config> BondingController for Pallet<T> {
 
 	fn available_balance(who: &Self::AccountId, ledger: &BondingLedgerOf<T>) -> Balance {
 		let free_balance = T::Currency::free_balance(who);
-		free_balance.saturating_sub(ledger.total())
+		let ve_locked = Self::ve_locks(who).map(|lock| lock.amount).unwrap_or_default();
+		free_balance.saturating_sub(ledger.total()).saturating_sub(ve_locked)
 	}
 
 	fn apply_ledger(who: &Self::AccountId, ledger: &BondingLedgerOf<T>) -> DispatchResult {
-		if ledger.is_empty() {
-			T::Currency::remove_lock(T::LockIdentifier::get(), who);
-		} else {
-			T::Currency::set_lock(T::LockIdentifier::get(), who, ledger.total(), WithdrawReasons::all());
-		}
+		Self::update_lock(who, ledger.total());
 		Ok(())
 	}
 
@@ -26,10 +26,12 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU128, ConstU32, ConstU64, Everything, Imbalance},
 };
+use module_support::Rate;
 use pallet_balances::NegativeImbalance;
-use primitives::mock_handler;
+use primitives::{mock_handler, DexShare, TokenSymbol};
 use sp_core::H256;
 use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 pub type AccountId = u128;
 
@@ -78,12 +80,16 @@ impl pallet_balances::Config for Runtime {
 
 parameter_types! {
 	pub const EarningLockIdentifier: LockIdentifier = *b"12345678";
+	pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
+	pub static MaxAutoCompoundPerBlock: u32 = 2;
+	pub static MaxVeCheckpointPerBlock: u32 = 2;
 }
 
 mock_handler! {
 	pub struct OnBonded<(AccountId, Balance)>;
 	pub struct OnUnbonded<(AccountId, Balance)>;
 	pub struct OnUnstakeFee<Balance>;
+	pub struct OnAutoCompoundFee<Balance>;
 }
 
 impl OnUnbalanced<NegativeImbalance<Runtime>> for OnUnstakeFee {
@@ -92,6 +98,53 @@ impl OnUnbalanced<NegativeImbalance<Runtime>> for OnUnstakeFee {
 	}
 }
 
+impl OnUnbalanced<NegativeImbalance<Runtime>> for OnAutoCompoundFee {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance<Runtime>) {
+		Self::push(amount.peek());
+	}
+}
+
+thread_local! {
+	static PENDING_REWARDS: RefCell<BTreeMap<AccountId, Balance>> = RefCell::new(BTreeMap::new());
+}
+
+pub fn set_pending_rewards(who: AccountId, amount: Balance) {
+	PENDING_REWARDS.with(|v| v.borrow_mut().insert(who, amount));
+}
+
+/// mock incentives which pays the pending rewards of `PoolId::Earning` by issuing native currency.
+pub struct MockIncentives;
+impl IncentivesManager<AccountId, Balance, CurrencyId, PoolId> for MockIncentives {
+	fn get_incentive_reward_amount(_: PoolId, _: CurrencyId) -> Balance {
+		Default::default()
+	}
+
+	fn deposit_dex_share(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_dex_share(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn claim_rewards(who: AccountId, pool_id: PoolId) -> DispatchResult {
+		assert_eq!(pool_id, PoolId::Earning(NATIVE_CURRENCY_ID));
+		let reward = PENDING_REWARDS
+			.with(|v| v.borrow_mut().remove(&who))
+			.unwrap_or_default();
+		let _ = Balances::deposit_creating(&who, reward);
+		Ok(())
+	}
+
+	fn get_claim_reward_deduction_rate(_: PoolId) -> Rate {
+		Default::default()
+	}
+
+	fn get_pending_rewards(_: PoolId, who: AccountId, _: Vec<CurrencyId>) -> Vec<Balance> {
+		vec![PENDING_REWARDS.with(|v| v.borrow().get(&who).copied().unwrap_or_default())]
+	}
+}
+
 pub struct ParameterStoreImpl;
 impl ParameterStore<Parameters> for ParameterStoreImpl {
 	fn get<K>(key: K) -> Option<K::Value>
@@ -108,6 +161,18 @@ impl ParameterStore<Parameters> for ParameterStoreImpl {
 					.ok()?
 					.into(),
 			),
+			ParametersKey::AutoCompoundFee(_) => Some(
+				ParametersValue::AutoCompoundFee(Permill::from_percent(1))
+					.try_into()
+					.ok()?
+					.into(),
+			),
+			ParametersKey::VeEarlyExitPenalty(_) => Some(
+				ParametersValue::VeEarlyExitPenalty(Permill::from_percent(50))
+					.try_into()
+					.ok()?
+					.into(),
+			),
 		}
 	}
 }
@@ -119,10 +184,17 @@ impl Config for Runtime {
 	type OnBonded = OnBonded;
 	type OnUnbonded = OnUnbonded;
 	type OnUnstakeFee = OnUnstakeFee;
+	type OnAutoCompoundFee = OnAutoCompoundFee;
+	type IncentivesManager = MockIncentives;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type MinBond = ConstU128<100>;
 	type UnbondingPeriod = ConstU64<3>;
 	type MaxUnbondingChunks = ConstU32<3>;
 	type LockIdentifier = EarningLockIdentifier;
+	type MaxAutoCompoundPerBlock = MaxAutoCompoundPerBlock;
+	type MaxVeLockDuration = ConstU64<100>;
+	type MaxDexVotes = ConstU32<2>;
+	type MaxVeCheckpointPerBlock = MaxVeCheckpointPerBlock;
 	type WeightInfo = ();
 }
 
@@ -139,6 +211,13 @@ construct_runtime!(
 pub struct ExtBuilder;
 
 pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const DOT_AUSD_LP: CurrencyId =
+	CurrencyId::DexShare(DexShare::Token(TokenSymbol::DOT), DexShare::Token(TokenSymbol::AUSD));
+pub const ACA_AUSD_LP: CurrencyId =
+	CurrencyId::DexShare(DexShare::Token(TokenSymbol::ACA), DexShare::Token(TokenSymbol::AUSD));
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
@@ -153,7 +232,7 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 1000)],
+			balances: vec![(ALICE, 1000), (BOB, 1000), (CHARLIE, 1000)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -26,6 +26,7 @@ use frame_support::{
 	traits::{
 		fungible::Inspect,
 		tokens::{Fortitude, Preservation},
+		Hooks,
 	},
 };
 use mock::*;
@@ -34,12 +35,14 @@ fn assert_no_handler_events() {
 	OnBonded::assert_empty();
 	OnUnbonded::assert_empty();
 	OnUnstakeFee::assert_empty();
+	OnAutoCompoundFee::assert_empty();
 }
 
 fn clear_handler_events() {
 	OnBonded::clear();
 	OnUnbonded::clear();
 	OnUnstakeFee::clear();
+	OnAutoCompoundFee::clear();
 }
 
 #[test]
@@ -237,3 +240,443 @@ fn rebond_works() {
 		assert_no_handler_events();
 	});
 }
+
+#[test]
+fn set_auto_compound_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Earning::set_auto_compound(RuntimeOrigin::signed(ALICE), true),
+			Error::<Runtime>::NotBonded
+		);
+
+		assert_ok!(Earning::bond(RuntimeOrigin::signed(ALICE), 1000));
+		assert_ok!(Earning::set_auto_compound(RuntimeOrigin::signed(ALICE), true));
+		System::assert_last_event(
+			Event::AutoCompoundUpdated {
+				who: ALICE,
+				enabled: true,
+			}
+			.into(),
+		);
+		assert_eq!(Earning::auto_compound(ALICE), Some(()));
+
+		assert_ok!(Earning::set_auto_compound(RuntimeOrigin::signed(ALICE), false));
+		System::assert_last_event(
+			Event::AutoCompoundUpdated {
+				who: ALICE,
+				enabled: false,
+			}
+			.into(),
+		);
+		assert_eq!(Earning::auto_compound(ALICE), None);
+
+		// auto-compound is removed once all tokens are unbonded and withdrawn
+		assert_ok!(Earning::set_auto_compound(RuntimeOrigin::signed(ALICE), true));
+		assert_ok!(Earning::unbond(RuntimeOrigin::signed(ALICE), 1000));
+		assert_eq!(Earning::auto_compound(ALICE), Some(()));
+		System::set_block_number(4);
+		assert_ok!(Earning::withdraw_unbonded(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(Earning::auto_compound(ALICE), None);
+	});
+}
+
+#[test]
+fn auto_compound_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		for who in [ALICE, BOB, CHARLIE] {
+			assert_ok!(Earning::bond(RuntimeOrigin::signed(who), 500));
+			assert_ok!(Earning::set_auto_compound(RuntimeOrigin::signed(who), true));
+		}
+		clear_handler_events();
+
+		set_pending_rewards(ALICE, 200);
+		set_pending_rewards(BOB, 100);
+		set_pending_rewards(CHARLIE, 300);
+
+		// not enough weight to auto-compound one account
+		assert_eq!(
+			Earning::on_idle(
+				1,
+				<() as WeightInfo>::auto_compound().saturating_sub(Weight::from_parts(1, 1))
+			),
+			Weight::zero()
+		);
+		assert_no_handler_events();
+
+		// only MaxAutoCompoundPerBlock accounts are processed in a batch
+		assert_eq!(
+			Earning::on_idle(1, Weight::MAX),
+			<() as WeightInfo>::auto_compound().saturating_mul(2)
+		);
+		assert!(AutoCompoundCursor::<Runtime>::exists());
+		let compounded = [ALICE, BOB, CHARLIE]
+			.into_iter()
+			.filter(|who| Earning::ledger(who).unwrap().active() > 500)
+			.count();
+		assert_eq!(compounded, 2);
+
+		// the next batch continues from the cursor
+		assert_eq!(Earning::on_idle(2, Weight::MAX), <() as WeightInfo>::auto_compound());
+		assert!(!AutoCompoundCursor::<Runtime>::exists());
+
+		System::assert_has_event(
+			Event::AutoCompounded {
+				who: ALICE,
+				reward: 200,
+				fee: 2,
+				bonded: 198,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::AutoCompounded {
+				who: BOB,
+				reward: 100,
+				fee: 1,
+				bonded: 99,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::AutoCompounded {
+				who: CHARLIE,
+				reward: 300,
+				fee: 3,
+				bonded: 297,
+			}
+			.into(),
+		);
+		assert_eq!(Earning::ledger(ALICE).unwrap().active(), 698);
+		assert_eq!(Earning::ledger(BOB).unwrap().active(), 599);
+		assert_eq!(Earning::ledger(CHARLIE).unwrap().active(), 797);
+		assert_eq!(Balances::free_balance(ALICE), 1198);
+		assert_eq!(
+			Balances::reducible_balance(&ALICE, Preservation::Expendable, Fortitude::Polite),
+			500
+		);
+
+		// no rewards to compound
+		clear_handler_events();
+		assert_ok!(Earning::do_auto_compound(&ALICE));
+		assert_eq!(Earning::ledger(ALICE).unwrap().active(), 698);
+		assert_no_handler_events();
+	});
+}
+
+#[test]
+fn ve_lock_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Earning::ve_lock(RuntimeOrigin::signed(ALICE), 10, 100),
+			Error::<Runtime>::BelowMinBondThreshold,
+		);
+		assert_noop!(
+			Earning::ve_lock(RuntimeOrigin::signed(ALICE), 400, 0),
+			Error::<Runtime>::InvalidLockDuration,
+		);
+		assert_noop!(
+			Earning::ve_lock(RuntimeOrigin::signed(ALICE), 400, 101),
+			Error::<Runtime>::InvalidLockDuration,
+		);
+
+		assert_ok!(Earning::bond(RuntimeOrigin::signed(ALICE), 500));
+		OnBonded::assert_eq_and_clear(vec![(ALICE, 500)]);
+		assert_noop!(
+			Earning::ve_lock(RuntimeOrigin::signed(ALICE), 600, 100),
+			Error::<Runtime>::NotEnoughBalance,
+		);
+
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(ALICE), 400, 100));
+		System::assert_last_event(
+			Event::VeLockUpdated {
+				who: ALICE,
+				amount: 400,
+				end: 101,
+				weight: 400,
+			}
+			.into(),
+		);
+		OnBonded::assert_eq_and_clear(vec![(ALICE, 400)]);
+		assert_eq!(
+			Earning::ve_locks(ALICE),
+			Some(VeLock {
+				amount: 400,
+				end: 101,
+				weight: 400,
+			})
+		);
+		assert_eq!(
+			Balances::reducible_balance(&ALICE, Preservation::Expendable, Fortitude::Polite),
+			100
+		);
+		assert_noop!(
+			Earning::ve_lock(RuntimeOrigin::signed(ALICE), 100, 100),
+			Error::<Runtime>::VeLockExists,
+		);
+
+		// the vote weight decays linearly
+		System::set_block_number(51);
+		assert_ok!(Earning::ve_checkpoint(RuntimeOrigin::signed(BOB), ALICE));
+		OnUnbonded::assert_eq_and_clear(vec![(ALICE, 200)]);
+		assert_eq!(Earning::ve_locks(ALICE).unwrap().weight, 200);
+
+		assert_noop!(
+			Earning::ve_increase_amount(RuntimeOrigin::signed(ALICE), 200),
+			Error::<Runtime>::NotEnoughBalance,
+		);
+		assert_ok!(Earning::ve_increase_amount(RuntimeOrigin::signed(ALICE), 100));
+		System::assert_last_event(
+			Event::VeLockUpdated {
+				who: ALICE,
+				amount: 500,
+				end: 101,
+				weight: 250,
+			}
+			.into(),
+		);
+		OnBonded::assert_eq_and_clear(vec![(ALICE, 50)]);
+		assert_eq!(
+			Balances::reducible_balance(&ALICE, Preservation::Expendable, Fortitude::Polite),
+			0
+		);
+
+		assert_noop!(
+			Earning::ve_extend(RuntimeOrigin::signed(ALICE), 40),
+			Error::<Runtime>::InvalidLockDuration,
+		);
+		assert_noop!(
+			Earning::ve_extend(RuntimeOrigin::signed(ALICE), 101),
+			Error::<Runtime>::InvalidLockDuration,
+		);
+		assert_ok!(Earning::ve_extend(RuntimeOrigin::signed(ALICE), 100));
+		System::assert_last_event(
+			Event::VeLockUpdated {
+				who: ALICE,
+				amount: 500,
+				end: 151,
+				weight: 500,
+			}
+			.into(),
+		);
+		OnBonded::assert_eq_and_clear(vec![(ALICE, 250)]);
+
+		System::set_block_number(151);
+		assert_noop!(
+			Earning::ve_increase_amount(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::VeLockExpired,
+		);
+		assert_ok!(Earning::ve_checkpoint(RuntimeOrigin::signed(ALICE), ALICE));
+		OnUnbonded::assert_eq_and_clear(vec![(ALICE, 500)]);
+
+		assert_noop!(
+			Earning::ve_increase_amount(RuntimeOrigin::signed(BOB), 100),
+			Error::<Runtime>::NoVeLock,
+		);
+		assert_noop!(
+			Earning::ve_extend(RuntimeOrigin::signed(BOB), 100),
+			Error::<Runtime>::NoVeLock,
+		);
+		assert_noop!(
+			Earning::ve_checkpoint(RuntimeOrigin::signed(ALICE), BOB),
+			Error::<Runtime>::NoVeLock,
+		);
+
+		assert_no_handler_events();
+	});
+}
+
+#[test]
+fn ve_withdraw_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Earning::ve_withdraw(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NoVeLock,
+		);
+
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(ALICE), 400, 100));
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(BOB), 400, 10));
+		clear_handler_events();
+
+		// withdraw before the lock expires is charged the penalty
+		System::set_block_number(51);
+		assert_ok!(Earning::ve_withdraw(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(
+			Event::VeUnlocked {
+				who: ALICE,
+				amount: 300,
+				penalty: 100,
+			}
+			.into(),
+		);
+		OnUnbonded::assert_eq_and_clear(vec![(ALICE, 400)]);
+		OnUnstakeFee::assert_eq_and_clear(vec![100]);
+		assert_eq!(Earning::ve_locks(ALICE), None);
+		assert_eq!(Balances::free_balance(ALICE), 900);
+		assert_eq!(
+			Balances::reducible_balance(&ALICE, Preservation::Expendable, Fortitude::Polite),
+			900
+		);
+
+		// withdraw after the lock expires is free
+		assert_ok!(Earning::ve_withdraw(RuntimeOrigin::signed(BOB)));
+		System::assert_last_event(
+			Event::VeUnlocked {
+				who: BOB,
+				amount: 400,
+				penalty: 0,
+			}
+			.into(),
+		);
+		OnUnbonded::assert_eq_and_clear(vec![(BOB, 40)]);
+		assert_eq!(Earning::ve_locks(BOB), None);
+		assert_eq!(
+			Balances::reducible_balance(&BOB, Preservation::Expendable, Fortitude::Polite),
+			1000
+		);
+
+		assert_no_handler_events();
+	});
+}
+
+#[test]
+fn vote_dex_incentives_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Earning::vote_dex_incentives(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(10)),
+					(PoolId::Dex(ACA_AUSD_LP), Permill::from_percent(10)),
+					(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(10)),
+				]
+			),
+			Error::<Runtime>::TooManyVotes,
+		);
+		assert_noop!(
+			Earning::vote_dex_incentives(
+				RuntimeOrigin::signed(ALICE),
+				vec![(PoolId::Loans(DOT_AUSD_LP), Permill::from_percent(10))]
+			),
+			Error::<Runtime>::InvalidVotes,
+		);
+		assert_noop!(
+			Earning::vote_dex_incentives(
+				RuntimeOrigin::signed(ALICE),
+				vec![(PoolId::Dex(NATIVE_CURRENCY_ID), Permill::from_percent(10))]
+			),
+			Error::<Runtime>::InvalidVotes,
+		);
+		assert_noop!(
+			Earning::vote_dex_incentives(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(10)),
+					(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(10)),
+				]
+			),
+			Error::<Runtime>::InvalidVotes,
+		);
+		assert_noop!(
+			Earning::vote_dex_incentives(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(60)),
+					(PoolId::Dex(ACA_AUSD_LP), Permill::from_percent(50)),
+				]
+			),
+			Error::<Runtime>::InvalidVotes,
+		);
+		assert_noop!(
+			Earning::vote_dex_incentives(
+				RuntimeOrigin::signed(ALICE),
+				vec![(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(10))]
+			),
+			Error::<Runtime>::NoVeLock,
+		);
+
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(ALICE), 400, 100));
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(BOB), 200, 50));
+
+		let votes = vec![
+			(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(50)),
+			(PoolId::Dex(ACA_AUSD_LP), Permill::from_percent(50)),
+		];
+		assert_ok!(Earning::vote_dex_incentives(
+			RuntimeOrigin::signed(ALICE),
+			votes.clone()
+		));
+		System::assert_last_event(Event::DexIncentivesVoted { who: ALICE, votes }.into());
+		assert_ok!(Earning::vote_dex_incentives(
+			RuntimeOrigin::signed(BOB),
+			vec![(PoolId::Dex(DOT_AUSD_LP), Permill::one())]
+		));
+		assert_eq!(Earning::get_pool_vote_weight(&PoolId::Dex(DOT_AUSD_LP)), 300);
+		assert_eq!(Earning::get_pool_vote_weight(&PoolId::Dex(ACA_AUSD_LP)), 200);
+		assert_eq!(Earning::get_total_vote_weight(), 500);
+
+		// the votes decay with the vote weight
+		System::set_block_number(26);
+		assert_ok!(Earning::ve_checkpoint(RuntimeOrigin::signed(ALICE), ALICE));
+		assert_eq!(Earning::dex_vote_weights(PoolId::Dex(DOT_AUSD_LP)), 250);
+		assert_eq!(Earning::dex_vote_weights(PoolId::Dex(ACA_AUSD_LP)), 150);
+		assert_eq!(Earning::total_dex_vote_weight(), 400);
+
+		// revote replaces the previous votes with the decayed vote weight
+		System::set_block_number(31);
+		assert_ok!(Earning::vote_dex_incentives(
+			RuntimeOrigin::signed(ALICE),
+			vec![(PoolId::Dex(ACA_AUSD_LP), Permill::one())]
+		));
+		assert_eq!(Earning::ve_locks(ALICE).unwrap().weight, 280);
+		assert_eq!(Earning::dex_vote_weights(PoolId::Dex(DOT_AUSD_LP)), 100);
+		assert_eq!(Earning::dex_vote_weights(PoolId::Dex(ACA_AUSD_LP)), 280);
+		assert_eq!(Earning::total_dex_vote_weight(), 380);
+
+		// withdraw removes the votes
+		assert_ok!(Earning::ve_withdraw(RuntimeOrigin::signed(BOB)));
+		assert_eq!(Earning::dex_votes(BOB), vec![]);
+		assert!(!DexVoteWeights::<Runtime>::contains_key(PoolId::Dex(DOT_AUSD_LP)));
+		assert_eq!(Earning::total_dex_vote_weight(), 280);
+
+		assert_ok!(Earning::vote_dex_incentives(RuntimeOrigin::signed(ALICE), vec![]));
+		assert!(!DexVoteWeights::<Runtime>::contains_key(PoolId::Dex(ACA_AUSD_LP)));
+		assert_eq!(Earning::total_dex_vote_weight(), 0);
+	});
+}
+
+#[test]
+fn on_idle_checkpoint_ve_locks() {
+	ExtBuilder::default().build().execute_with(|| {
+		let weight_per_lock =
+			<() as WeightInfo>::ve_checkpoint().saturating_add(Earning::dex_vote_weights_update_weight());
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(ALICE), 400, 100));
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(BOB), 200, 50));
+		assert_ok!(Earning::ve_lock(RuntimeOrigin::signed(CHARLIE), 100, 100));
+		assert_ok!(Earning::vote_dex_incentives(
+			RuntimeOrigin::signed(BOB),
+			vec![(PoolId::Dex(DOT_AUSD_LP), Permill::one())]
+		));
+		OnBonded::assert_eq_and_clear(vec![(ALICE, 400), (BOB, 100), (CHARLIE, 100)]);
+
+		// not enough weight to checkpoint one lock
+		assert_eq!(
+			Earning::process_ve_checkpoint(weight_per_lock.saturating_sub(Weight::from_parts(1, 1))),
+			Weight::zero()
+		);
+
+		// the lock of BOB has expired, only MaxVeCheckpointPerBlock locks are checkpointed in a batch
+		System::set_block_number(51);
+		assert_eq!(Earning::on_idle(51, Weight::MAX), weight_per_lock.saturating_mul(2));
+		assert!(VeCheckpointCursor::<Runtime>::exists());
+
+		// the next batch continues from the cursor
+		assert_eq!(Earning::on_idle(51, Weight::MAX), weight_per_lock);
+		assert!(!VeCheckpointCursor::<Runtime>::exists());
+
+		assert_eq!(Earning::ve_locks(ALICE).unwrap().weight, 200);
+		assert_eq!(Earning::ve_locks(BOB).unwrap().weight, 0);
+		assert_eq!(Earning::ve_locks(CHARLIE).unwrap().weight, 50);
+		assert_eq!(Earning::total_dex_vote_weight(), 0);
+		assert!(!DexVoteWeights::<Runtime>::contains_key(PoolId::Dex(DOT_AUSD_LP)));
+	});
+}
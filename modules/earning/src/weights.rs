@@ -51,6 +51,14 @@ pub trait WeightInfo {
 	fn unbond() -> Weight;
 	fn rebond() -> Weight;
 	fn withdraw_unbonded() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn auto_compound() -> Weight;
+	fn ve_lock() -> Weight;
+	fn ve_increase_amount() -> Weight;
+	fn ve_extend() -> Weight;
+	fn ve_withdraw() -> Weight;
+	fn ve_checkpoint() -> Weight;
+	fn vote_dex_incentives(c: u32, ) -> Weight;
 }
 
 /// Weights for module_earning using the Acala node and recommended hardware.
@@ -149,6 +157,196 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::AutoCompound` (r:0 w:1)
+	// Proof: `Earning::AutoCompound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2391`
+		//  Estimated: `5856`
+		// Minimum execution time: 21_347 nanoseconds.
+		Weight::from_parts(22_108_000, 5856)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:1)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Parameters::Parameters` (r:1 w:0)
+	// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	// Storage: `Earning::Ledger` (r:1 w:1)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3672`
+		//  Estimated: `11469`
+		// Minimum execution time: 164_218 nanoseconds.
+		Weight::from_parts(168_530_000, 11469)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2711`
+		//  Estimated: `6176`
+		// Minimum execution time: 58_412 nanoseconds.
+		Weight::from_parts(60_137_000, 6176)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_increase_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `6268`
+		// Minimum execution time: 61_094 nanoseconds.
+		Weight::from_parts(62_870_000, 6268)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_extend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `6268`
+		// Minimum execution time: 59_316 nanoseconds.
+		Weight::from_parts(61_002_000, 6268)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Parameters::Parameters` (r:1 w:0)
+	// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:1)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ve_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3021`
+		//  Estimated: `6486`
+		// Minimum execution time: 79_845 nanoseconds.
+		Weight::from_parts(81_913_000, 6486)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `6268`
+		// Minimum execution time: 52_778 nanoseconds.
+		Weight::from_parts(54_309_000, 6268)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:0)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:1)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVoteWeights` (r:10 w:10)
+	// Proof: `Earning::DexVoteWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::TotalDexVoteWeight` (r:1 w:1)
+	// Proof: `Earning::TotalDexVoteWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 10]`.
+	fn vote_dex_incentives(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2585 + c * (52 ±0)`
+		//  Estimated: `6050 + c * (2527 ±0)`
+		// Minimum execution time: 18_204 nanoseconds.
+		Weight::from_parts(19_532_118, 6050)
+			// Standard Error: 6_071
+			.saturating_add(Weight::from_parts(5_208_943, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(c.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -246,4 +444,194 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::AutoCompound` (r:0 w:1)
+	// Proof: `Earning::AutoCompound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2391`
+		//  Estimated: `5856`
+		// Minimum execution time: 21_347 nanoseconds.
+		Weight::from_parts(22_108_000, 5856)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: `Incentives::PendingMultiRewards` (r:1 w:1)
+	// Proof: `Incentives::PendingMultiRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::ClaimRewardDeductionRates` (r:1 w:0)
+	// Proof: `Incentives::ClaimRewardDeductionRates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:3 w:3)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Parameters::Parameters` (r:1 w:0)
+	// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	// Storage: `Earning::Ledger` (r:1 w:1)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3672`
+		//  Estimated: `11469`
+		// Minimum execution time: 164_218 nanoseconds.
+		Weight::from_parts(168_530_000, 11469)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2711`
+		//  Estimated: `6176`
+		// Minimum execution time: 58_412 nanoseconds.
+		Weight::from_parts(60_137_000, 6176)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_increase_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `6268`
+		// Minimum execution time: 61_094 nanoseconds.
+		Weight::from_parts(62_870_000, 6268)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_extend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `6268`
+		// Minimum execution time: 59_316 nanoseconds.
+		Weight::from_parts(61_002_000, 6268)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Parameters::Parameters` (r:1 w:0)
+	// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:1)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn ve_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3021`
+		//  Estimated: `6486`
+		// Minimum execution time: 79_845 nanoseconds.
+		Weight::from_parts(81_913_000, 6486)
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:1)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::Ledger` (r:1 w:0)
+	// Proof: `Earning::Ledger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Rewards::PoolInfos` (r:1 w:1)
+	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
+	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:0)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Locks` (r:1 w:1)
+	// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Freezes` (r:1 w:0)
+	// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn ve_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2803`
+		//  Estimated: `6268`
+		// Minimum execution time: 52_778 nanoseconds.
+		Weight::from_parts(54_309_000, 6268)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: `Earning::VeLocks` (r:1 w:0)
+	// Proof: `Earning::VeLocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVotes` (r:1 w:1)
+	// Proof: `Earning::DexVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::DexVoteWeights` (r:10 w:10)
+	// Proof: `Earning::DexVoteWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Earning::TotalDexVoteWeight` (r:1 w:1)
+	// Proof: `Earning::TotalDexVoteWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[0, 10]`.
+	fn vote_dex_incentives(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2585 + c * (52 ±0)`
+		//  Estimated: `6050 + c * (2527 ±0)`
+		// Minimum execution time: 18_204 nanoseconds.
+		Weight::from_parts(19_532_118, 6050)
+			// Standard Error: 6_071
+			.saturating_add(Weight::from_parts(5_208_943, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(c.into()))
+	}
 }
@@ -22,6 +22,18 @@
 //!
 //! Evm Accounts module provide a two way mapping between Substrate accounts and
 //! EVM accounts so user only have deal with one account / private key.
+//!
+//! Besides the primary EVM address created by `claim_account`, an account can link
+//! additional EVM addresses with `link_address`, e.g. hardware wallets and multisigs.
+//! All the linked addresses map to the account, while the account maps to its primary
+//! address. When an address is linked, the balances held by its default account are
+//! merged into the account. When an address is unlinked, it maps to its default account
+//! again and the balances stay with the account.
+//!
+//! A linked address is only an alias of the account. Transactions signed by a linked address
+//! are dispatched from the account, so their EVM calls run as the primary address of the
+//! account. Addresses that hold contract code or ERC20 balances can't be linked, because
+//! that state would no longer be reachable by the address.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -33,10 +45,10 @@ use frame_support::{
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use module_evm_utility_macro::keccak256;
-use module_support::{AddressMapping, EVMAccountsManager};
+use module_support::{AddressMapping, EVMAccountsManager, EvmAddressState};
 use orml_traits::currency::TransferAll;
 use parity_scale_codec::Encode;
-use primitives::{evm::EvmAddress, to_bytes, AccountIndex};
+use primitives::{evm::EvmAddress, signature::VerifyContractSignature, to_bytes, AccountIndex};
 use sp_core::crypto::AccountId32;
 use sp_core::{H160, H256};
 use sp_io::{
@@ -80,6 +92,16 @@ pub mod module {
 		/// Merge free balance from source to dest.
 		type TransferAll: TransferAll<Self::AccountId>;
 
+		/// Verify the EIP-1271 signatures of contract accounts.
+		type ContractSignatureVerifier: VerifyContractSignature;
+
+		/// The maximum number of additional EVM addresses linked to an account.
+		#[pallet::constant]
+		type MaxLinkedAddresses: Get<u32>;
+
+		/// The EVM state held by the addresses to link.
+		type AddressState: EvmAddressState<Self::AccountId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -93,6 +115,16 @@ pub mod module {
 			account_id: T::AccountId,
 			evm_address: EvmAddress,
 		},
+		/// An additional EVM address is linked to the account.
+		AddressLinked {
+			account_id: T::AccountId,
+			evm_address: EvmAddress,
+		},
+		/// An additional EVM address is unlinked from the account.
+		AddressUnlinked {
+			account_id: T::AccountId,
+			evm_address: EvmAddress,
+		},
 	}
 
 	/// Error for evm accounts module.
@@ -108,6 +140,16 @@ pub mod module {
 		InvalidSignature,
 		/// Account ref count is not zero
 		NonZeroRefCount,
+		/// AccountId has not mapped
+		AccountIdNotMapped,
+		/// Eth address is not linked to the account
+		EthAddressNotLinked,
+		/// Too many linked addresses
+		TooManyLinkedAddresses,
+		/// Eth address is a contract
+		EthAddressIsContract,
+		/// Eth address has ERC20 balances
+		EthAddressHasErc20Balance,
 	}
 
 	/// The Substrate Account for EvmAddresses
@@ -124,6 +166,21 @@ pub mod module {
 	#[pallet::getter(fn evm_addresses)]
 	pub type EvmAddresses<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+	/// The additional EvmAddresses linked to Substrate Accounts
+	///
+	/// LinkedAddresses: map AccountId => Vec<EvmAddress>
+	#[pallet::storage]
+	#[pallet::getter(fn linked_addresses)]
+	pub type LinkedAddresses<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<EvmAddress, T::MaxLinkedAddresses>, ValueQuery>;
+
+	/// The nonce of the EIP-712 signatures to link addresses to Substrate Accounts
+	///
+	/// LinkNonces: map AccountId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn link_nonces)]
+	pub type LinkNonces<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -157,6 +214,68 @@ pub mod module {
 			let address = Self::verify_eip712_signature(&who, &eth_signature).ok_or(Error::<T>::BadSignature)?;
 			ensure!(eth_address == address, Error::<T>::InvalidSignature);
 
+			Self::do_claim_account(who, eth_address)
+		}
+
+		/// Claim account mapping between Substrate accounts and a generated EVM
+		/// address based off of those accounts.
+		/// Ensure eth_address has not been mapped
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::claim_default_account())]
+		pub fn claim_default_account(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let _ = Self::do_claim_default_evm_address(who)?;
+			Ok(())
+		}
+
+		/// Link an additional EVM address to the caller's account, which must have claimed
+		/// its primary EVM address. The balances of the default account of `eth_address`
+		/// are merged into the caller's account. Ensure eth_address is not a contract and
+		/// has no ERC20 balances.
+		///
+		/// Transactions signed by `eth_address` are dispatched from the caller's account, so
+		/// their EVM calls run as the primary EVM address of the caller's account.
+		///
+		/// - `eth_address`: The address to link to the caller's account
+		/// - `eth_signature`: A signature of the current link nonce generated by the address to
+		///   prove ownership
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::link_address().saturating_add(T::AddressState::weight()))]
+		pub fn link_address(
+			origin: OriginFor<T>,
+			eth_address: EvmAddress,
+			eth_signature: Eip712Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdNotMapped);
+			ensure!(
+				!Accounts::<T>::contains_key(eth_address),
+				Error::<T>::EthAddressHasMapped
+			);
+
+			// recover evm address from signature
+			let nonce = LinkNonces::<T>::get(&who);
+			let address =
+				Self::verify_eip712_link_signature(&who, nonce, &eth_signature).ok_or(Error::<T>::BadSignature)?;
+			ensure!(eth_address == address, Error::<T>::InvalidSignature);
+
+			// the contract and the ERC20 balances of eth_address can't be used once it's linked
+			ensure!(
+				!T::AddressState::is_contract(&eth_address),
+				Error::<T>::EthAddressIsContract
+			);
+			ensure!(
+				!T::AddressState::has_erc20_balance(&eth_address),
+				Error::<T>::EthAddressHasErc20Balance
+			);
+
+			LinkedAddresses::<T>::try_mutate(&who, |addresses| {
+				addresses
+					.try_push(eth_address)
+					.map_err(|_| Error::<T>::TooManyLinkedAddresses)
+			})?;
+
 			// check if the evm padded address already exists
 			let account_id = T::AddressMapping::get_account_id(&eth_address);
 			if frame_system::Pallet::<T>::account_exists(&account_id) {
@@ -165,9 +284,9 @@ pub mod module {
 			}
 
 			Accounts::<T>::insert(eth_address, &who);
-			EvmAddresses::<T>::insert(&who, eth_address);
+			LinkNonces::<T>::insert(&who, nonce.wrapping_add(1));
 
-			Self::deposit_event(Event::ClaimAccount {
+			Self::deposit_event(Event::AddressLinked {
 				account_id: who,
 				evm_address: eth_address,
 			});
@@ -175,16 +294,71 @@ pub mod module {
 			Ok(())
 		}
 
-		/// Claim account mapping between Substrate accounts and a generated EVM
-		/// address based off of those accounts.
-		/// Ensure eth_address has not been mapped
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::claim_default_account())]
-		pub fn claim_default_account(origin: OriginFor<T>) -> DispatchResult {
+		/// Unlink an additional EVM address from the caller's account. The address maps to
+		/// its default account again, and the balances stay with the caller's account.
+		///
+		/// - `eth_address`: The linked address to unlink from the caller's account
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unlink_address())]
+		pub fn unlink_address(origin: OriginFor<T>, eth_address: EvmAddress) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let _ = Self::do_claim_default_evm_address(who)?;
+
+			LinkedAddresses::<T>::try_mutate_exists(&who, |maybe_addresses| -> DispatchResult {
+				let addresses = maybe_addresses.as_mut().ok_or(Error::<T>::EthAddressNotLinked)?;
+				let index = addresses
+					.iter()
+					.position(|address| *address == eth_address)
+					.ok_or(Error::<T>::EthAddressNotLinked)?;
+				addresses.remove(index);
+				if addresses.is_empty() {
+					*maybe_addresses = None;
+				}
+				Ok(())
+			})?;
+			Accounts::<T>::remove(eth_address);
+
+			Self::deposit_event(Event::AddressUnlinked {
+				account_id: who,
+				evm_address: eth_address,
+			});
+
 			Ok(())
 		}
+
+		/// Claim account mapping between Substrate accounts and smart contract wallets.
+		/// The signature is verified by EIP-1271 `isValidSignature` of the contract.
+		/// Ensure contract has not been mapped. The storage deposits of the contract are moved to
+		/// the caller's account, which becomes the account of the contract.
+		///
+		/// - `contract`: The contract address to bind to the caller's account
+		/// - `signature`: A signature of the claim message accepted by the contract
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::claim_account()
+			.saturating_add(T::ContractSignatureVerifier::weight())
+			.saturating_add(T::DbWeight::get().reads_writes(3, 3)))]
+		pub fn claim_contract_account(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			signature: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// ensure account_id and contract has not been mapped
+			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
+			ensure!(!Accounts::<T>::contains_key(contract), Error::<T>::EthAddressHasMapped);
+
+			let msg_hash = keccak_256(&Self::eip712_signable_message(&who));
+			ensure!(
+				T::ContractSignatureVerifier::is_valid_signature(contract, msg_hash, &signature),
+				Error::<T>::InvalidSignature
+			);
+
+			// the storage deposits are reserved by the account of the contract, which is changed
+			let contract_account_id = T::AddressMapping::get_account_id(&contract);
+			T::AddressState::transfer_storage_deposit(&contract_account_id, &who)?;
+
+			Self::do_claim_account(who, contract)
+		}
 	}
 }
 
@@ -213,6 +387,68 @@ impl<T: Config> Pallet<T> {
 		r
 	}
 
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Returns the hash of the claim message, which is signed by smart contract wallets.
+	pub fn eip712_claim_hash(who: &T::AccountId) -> [u8; 32] {
+		keccak_256(&Self::eip712_signable_message(who))
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Constructs a link message and signs it.
+	pub fn eth_sign_link(secret: &libsecp256k1::SecretKey, who: &T::AccountId, nonce: u32) -> Eip712Signature {
+		let msg = keccak_256(&Self::eip712_signable_link_message(who, nonce));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
+	fn verify_eip712_link_signature(who: &T::AccountId, nonce: u32, sig: &[u8; 65]) -> Option<H160> {
+		let msg = Self::eip712_signable_link_message(who, nonce);
+		let msg_hash = keccak_256(msg.as_slice());
+
+		recover_signer(sig, &msg_hash)
+	}
+
+	// Eip-712 message to be signed to link an address
+	fn eip712_signable_link_message(who: &T::AccountId, nonce: u32) -> Vec<u8> {
+		let domain_separator = Self::evm_account_domain_separator();
+		let payload_hash = Self::evm_account_link_payload_hash(who, nonce);
+
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&domain_separator);
+		msg.extend_from_slice(&payload_hash);
+		msg
+	}
+
+	fn evm_account_link_payload_hash(who: &T::AccountId, nonce: u32) -> [u8; 32] {
+		let tx_type_hash = keccak256!("LinkAddress(bytes substrateAddress,uint256 nonce)");
+		let mut tx_msg = tx_type_hash.to_vec();
+		tx_msg.extend_from_slice(&keccak_256(&who.encode()));
+		tx_msg.extend_from_slice(&to_bytes(nonce));
+		keccak_256(tx_msg.as_slice())
+	}
+
+	fn do_claim_account(who: T::AccountId, eth_address: EvmAddress) -> DispatchResult {
+		// check if the evm padded address already exists
+		let account_id = T::AddressMapping::get_account_id(&eth_address);
+		if frame_system::Pallet::<T>::account_exists(&account_id) {
+			// merge balance from `evm padded address` to `origin`
+			T::TransferAll::transfer_all(&account_id, &who)?;
+		}
+
+		Accounts::<T>::insert(eth_address, &who);
+		EvmAddresses::<T>::insert(&who, eth_address);
+
+		Self::deposit_event(Event::ClaimAccount {
+			account_id: who,
+			evm_address: eth_address,
+		});
+
+		Ok(())
+	}
+
 	fn verify_eip712_signature(who: &T::AccountId, sig: &[u8; 65]) -> Option<H160> {
 		let msg = Self::eip712_signable_message(who);
 		let msg_hash = keccak_256(msg.as_slice());
@@ -338,6 +574,7 @@ where
 	fn is_linked(account_id: &T::AccountId, evm: &EvmAddress) -> bool {
 		Self::get_evm_address(account_id).as_ref() == Some(evm)
 			|| &account_to_default_evm_address(account_id.into_ref()) == evm
+			|| LinkedAddresses::<T>::get(account_id).contains(evm)
 	}
 }
 
@@ -349,6 +586,10 @@ impl<T: Config> OnKilledAccount<T::AccountId> for CallKillAccount<T> {
 			Accounts::<T>::remove(evm_addr);
 			EvmAddresses::<T>::remove(who);
 		}
+		// remove mappings created by `link_address`
+		for evm_addr in LinkedAddresses::<T>::take(who) {
+			Accounts::<T>::remove(evm_addr);
+		}
 	}
 }
 
@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU128, ConstU64, Everything, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Everything, Nothing},
 };
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, Balance, CurrencyId, TokenSymbol};
@@ -121,9 +121,54 @@ impl Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type ContractSignatureVerifier = MockContractSignatureVerifier;
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = MockAddressState;
 	type WeightInfo = ();
 }
 
+pub fn contract_address() -> EvmAddress {
+	EvmAddress::from_low_u64_be(0x1271)
+}
+
+/// Accepts the signature of `contract_address` if it equals to the hash.
+pub struct MockContractSignatureVerifier;
+impl VerifyContractSignature for MockContractSignatureVerifier {
+	fn is_valid_signature(contract: EvmAddress, hash: [u8; 32], signature: &[u8]) -> bool {
+		contract == contract_address() && signature == hash.as_slice()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+parameter_types! {
+	pub static Contracts: Vec<EvmAddress> = vec![];
+	pub static Erc20Holders: Vec<EvmAddress> = vec![];
+	pub static StorageDepositTransfers: Vec<(AccountId, AccountId)> = vec![];
+}
+
+pub struct MockAddressState;
+impl EvmAddressState<AccountId> for MockAddressState {
+	fn is_contract(address: &EvmAddress) -> bool {
+		Contracts::get().contains(address)
+	}
+
+	fn has_erc20_balance(address: &EvmAddress) -> bool {
+		Erc20Holders::get().contains(address)
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn transfer_storage_deposit(from: &AccountId, to: &AccountId) -> DispatchResult {
+		StorageDepositTransfers::mutate(|v| v.push((from.clone(), to.clone())));
+		Ok(())
+	}
+}
+
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 construct_runtime!(
@@ -22,7 +22,10 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, bob, EvmAccountsModule, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System, ALICE, BOB};
+use mock::{
+	alice, bob, bob_account_id, contract_address, Balances, EvmAccountsModule, ExtBuilder, Runtime, RuntimeEvent,
+	RuntimeOrigin, System, ALICE, BOB,
+};
 use std::str::FromStr;
 
 #[test]
@@ -203,3 +206,187 @@ fn account_to_evm_with_create_default() {
 		);
 	});
 }
+
+#[test]
+fn link_and_unlink_address_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+		let bob_evm_account = EvmAccountsModule::eth_address(&bob());
+
+		// the primary address must be claimed first
+		assert_noop!(
+			EvmAccountsModule::link_address(
+				RuntimeOrigin::signed(ALICE),
+				bob_evm_account,
+				EvmAccountsModule::eth_sign_link(&bob(), &ALICE, 0)
+			),
+			Error::<Runtime>::AccountIdNotMapped
+		);
+		assert_ok!(EvmAccountsModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+
+		// the claim signature can't be used to link
+		assert_noop!(
+			EvmAccountsModule::link_address(
+				RuntimeOrigin::signed(ALICE),
+				bob_evm_account,
+				EvmAccountsModule::eth_sign(&bob(), &ALICE)
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+		assert_noop!(
+			EvmAccountsModule::link_address(
+				RuntimeOrigin::signed(ALICE),
+				alice_evm_account,
+				EvmAccountsModule::eth_sign_link(&alice(), &ALICE, 0)
+			),
+			Error::<Runtime>::EthAddressHasMapped
+		);
+
+		assert_eq!(Balances::free_balance(bob_account_id()), 100000);
+		assert_ok!(EvmAccountsModule::link_address(
+			RuntimeOrigin::signed(ALICE),
+			bob_evm_account,
+			EvmAccountsModule::eth_sign_link(&bob(), &ALICE, 0)
+		));
+		System::assert_last_event(RuntimeEvent::EvmAccountsModule(crate::Event::AddressLinked {
+			account_id: ALICE,
+			evm_address: bob_evm_account,
+		}));
+		assert_eq!(
+			EvmAccountsModule::linked_addresses(ALICE).into_inner(),
+			vec![bob_evm_account]
+		);
+		assert_eq!(EvmAccountsModule::link_nonces(ALICE), 1);
+
+		// balances of the default account are merged
+		assert_eq!(Balances::free_balance(bob_account_id()), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+
+		// all addresses map to the account, which maps to its primary address
+		assert_eq!(EvmAddressMapping::<Runtime>::get_account_id(&bob_evm_account), ALICE);
+		assert_eq!(EvmAddressMapping::<Runtime>::get_account_id(&alice_evm_account), ALICE);
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_evm_address(&ALICE),
+			Some(alice_evm_account)
+		);
+		assert!(EvmAddressMapping::<Runtime>::is_linked(&ALICE, &bob_evm_account));
+
+		// the primary address can't be unlinked
+		assert_noop!(
+			EvmAccountsModule::unlink_address(RuntimeOrigin::signed(ALICE), alice_evm_account),
+			Error::<Runtime>::EthAddressNotLinked
+		);
+		assert_ok!(EvmAccountsModule::unlink_address(
+			RuntimeOrigin::signed(ALICE),
+			bob_evm_account
+		));
+		System::assert_last_event(RuntimeEvent::EvmAccountsModule(crate::Event::AddressUnlinked {
+			account_id: ALICE,
+			evm_address: bob_evm_account,
+		}));
+		assert!(EvmAccountsModule::linked_addresses(ALICE).is_empty());
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_account_id(&bob_evm_account),
+			bob_account_id()
+		);
+		assert!(!EvmAddressMapping::<Runtime>::is_linked(&ALICE, &bob_evm_account));
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+
+		// the used signature can't be replayed
+		assert_noop!(
+			EvmAccountsModule::link_address(
+				RuntimeOrigin::signed(ALICE),
+				bob_evm_account,
+				EvmAccountsModule::eth_sign_link(&bob(), &ALICE, 0)
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn link_address_with_evm_state_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bob_evm_account = EvmAccountsModule::eth_address(&bob());
+		assert_ok!(EvmAccountsModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			EvmAccountsModule::eth_address(&alice()),
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+
+		Contracts::mutate(|v| v.push(bob_evm_account));
+		assert_noop!(
+			EvmAccountsModule::link_address(
+				RuntimeOrigin::signed(ALICE),
+				bob_evm_account,
+				EvmAccountsModule::eth_sign_link(&bob(), &ALICE, 0)
+			),
+			Error::<Runtime>::EthAddressIsContract
+		);
+
+		Contracts::mutate(|v| v.clear());
+		Erc20Holders::mutate(|v| v.push(bob_evm_account));
+		assert_noop!(
+			EvmAccountsModule::link_address(
+				RuntimeOrigin::signed(ALICE),
+				bob_evm_account,
+				EvmAccountsModule::eth_sign_link(&bob(), &ALICE, 0)
+			),
+			Error::<Runtime>::EthAddressHasErc20Balance
+		);
+
+		Erc20Holders::mutate(|v| v.clear());
+		assert_ok!(EvmAccountsModule::link_address(
+			RuntimeOrigin::signed(ALICE),
+			bob_evm_account,
+			EvmAccountsModule::eth_sign_link(&bob(), &ALICE, 0)
+		));
+	});
+}
+
+#[test]
+fn claim_contract_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = keccak_256(&EvmAccountsModule::eip712_signable_message(&ALICE)).to_vec();
+		let contract_account_id = EvmAddressMapping::<Runtime>::get_account_id(&contract_address());
+
+		assert_noop!(
+			EvmAccountsModule::claim_contract_account(RuntimeOrigin::signed(ALICE), contract_address(), vec![1u8; 65]),
+			Error::<Runtime>::InvalidSignature
+		);
+		// the signature of another account
+		assert_noop!(
+			EvmAccountsModule::claim_contract_account(
+				RuntimeOrigin::signed(BOB),
+				contract_address(),
+				signature.clone()
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+
+		assert_ok!(EvmAccountsModule::claim_contract_account(
+			RuntimeOrigin::signed(ALICE),
+			contract_address(),
+			signature.clone()
+		));
+		System::assert_last_event(RuntimeEvent::EvmAccountsModule(crate::Event::ClaimAccount {
+			account_id: ALICE,
+			evm_address: contract_address(),
+		}));
+		assert_eq!(EvmAddressMapping::<Runtime>::get_account_id(&contract_address()), ALICE);
+		assert_eq!(
+			EvmAddressMapping::<Runtime>::get_evm_address(&ALICE),
+			Some(contract_address())
+		);
+		assert_eq!(StorageDepositTransfers::get(), vec![(contract_account_id, ALICE)]);
+
+		assert_noop!(
+			EvmAccountsModule::claim_contract_account(RuntimeOrigin::signed(ALICE), contract_address(), signature),
+			Error::<Runtime>::AccountIdHasMapped
+		);
+	});
+}
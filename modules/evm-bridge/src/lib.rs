@@ -24,13 +24,16 @@ use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 use module_evm::{ExitReason, ExitSucceed};
 use module_support::{
-	evm::limits::{erc20, liquidation},
+	evm::{
+		interface_id,
+		limits::{erc1271, erc20, liquidation},
+	},
 	EVMBridge as EVMBridgeTrait, ExecutionMode, InvokeContext, LiquidationEvmBridge as LiquidationEvmBridgeT, EVM,
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use primitives::{evm::EvmAddress, Balance};
+use primitives::{evm::EvmAddress, signature::VerifyContractSignature, Balance};
 use sp_core::{H160, H256, U256};
-use sp_runtime::{ArithmeticError, DispatchError, SaturatedConversion};
+use sp_runtime::{traits::Convert, ArithmeticError, DispatchError, SaturatedConversion};
 use sp_std::vec::Vec;
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -46,6 +49,11 @@ pub enum Action {
 	TotalSupply = "totalSupply()",
 	BalanceOf = "balanceOf(address)",
 	Transfer = "transfer(address,uint256)",
+	Allowance = "allowance(address,address)",
+	Approve = "approve(address,uint256)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+	SupportsInterface = "supportsInterface(bytes4)",
+	IsValidSignature = "isValidSignature(bytes32,bytes)",
 	Liquidate = "liquidate(address,address,uint256,uint256)",
 	OnCollateralTransfer = "onCollateralTransfer(address,uint256)",
 	OnRepaymentRefund = "onRepaymentRefund(address,uint256)",
@@ -232,17 +240,99 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for EVMBridge<T> {
 		)?;
 
 		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::ensure_returned_true(info.value)
+	}
 
-		// return value is true.
-		let mut bytes = [0u8; 32];
-		U256::from(1).to_big_endian(&mut bytes);
+	// Calls the allowance method on an ERC20 contract using the given context
+	// and returns the amount the spender is allowed to transfer from the owner.
+	fn allowance(context: InvokeContext, owner: H160, spender: H160) -> Result<BalanceOf<T>, DispatchError> {
+		// ERC20.allowance method hash
+		let mut input = Into::<u32>::into(Action::Allowance).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(owner).as_bytes());
+		// append spender address
+		input.extend_from_slice(H256::from(spender).as_bytes());
 
-		// Check return value to make sure not calling on empty contracts.
-		ensure!(
-			!info.value.is_empty() && info.value == bytes,
-			Error::<T>::InvalidReturnValue
-		);
-		Ok(())
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			erc20::ALLOWANCE.gas,
+			erc20::ALLOWANCE.storage,
+			ExecutionMode::View,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		ensure!(info.value.len() == 32, Error::<T>::InvalidReturnValue);
+		// the allowance may be `type(uint256).max`, saturate it to the max balance.
+		let value: u128 = U256::from(info.value.as_slice()).try_into().unwrap_or(u128::MAX);
+		Ok(value.saturated_into())
+	}
+
+	// Calls the approve method on an ERC20 contract using the given context.
+	fn approve(context: InvokeContext, spender: H160, value: BalanceOf<T>) -> DispatchResult {
+		// ERC20.approve method hash
+		let mut input = Into::<u32>::into(Action::Approve).to_be_bytes().to_vec();
+		// append spender address
+		input.extend_from_slice(H256::from(spender).as_bytes());
+		// append amount to be approved
+		input.extend_from_slice(H256::from_uint(&U256::from(value.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() {
+			0
+		} else {
+			erc20::APPROVE.storage
+		};
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			erc20::APPROVE.gas,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::ensure_returned_true(info.value)
+	}
+
+	// Calls the transferFrom method on an ERC20 contract using the given context.
+	fn transfer_from(context: InvokeContext, from: H160, to: H160, value: BalanceOf<T>) -> DispatchResult {
+		// ERC20.transferFrom method hash
+		let mut input = Into::<u32>::into(Action::TransferFrom).to_be_bytes().to_vec();
+		// append sender address
+		input.extend_from_slice(H256::from(from).as_bytes());
+		// append receiver address
+		input.extend_from_slice(H256::from(to).as_bytes());
+		// append amount to be transferred
+		input.extend_from_slice(H256::from_uint(&U256::from(value.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() {
+			0
+		} else {
+			erc20::TRANSFER_FROM.storage
+		};
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			erc20::TRANSFER_FROM.gas,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+		Pallet::<T>::ensure_returned_true(info.value)
+	}
+
+	// Detects the interface support of a contract as specified by ERC-165.
+	fn supports_interface(context: InvokeContext, interface_id: u32) -> bool {
+		Pallet::<T>::call_supports_interface(context, interface_id::ERC165) == Some(true)
+			&& Pallet::<T>::call_supports_interface(context, interface_id::INVALID) == Some(false)
+			&& Pallet::<T>::call_supports_interface(context, interface_id) == Some(true)
 	}
 
 	fn get_origin() -> Option<AccountIdOf<T>> {
@@ -345,6 +435,57 @@ impl<T: Config> LiquidationEvmBridgeT for LiquidationEvmBridge<T> {
 	}
 }
 
+/// The EIP-1271 magic value returned by `isValidSignature` for valid signatures.
+pub const EIP1271_MAGIC_VALUE: u32 = 0x1626ba7e;
+
+pub struct Eip1271Verifier<T, GasToWeight>(sp_std::marker::PhantomData<(T, GasToWeight)>);
+
+impl<T: Config, GasToWeight: Convert<u64, Weight>> VerifyContractSignature for Eip1271Verifier<T, GasToWeight> {
+	// Calls the isValidSignature method on a contract in a read-only context.
+	fn is_valid_signature(contract: EvmAddress, hash: [u8; 32], signature: &[u8]) -> bool {
+		// EIP1271.isValidSignature method hash
+		let mut input = Into::<u32>::into(Action::IsValidSignature).to_be_bytes().to_vec();
+		// append hash
+		input.extend_from_slice(&hash);
+		// append the offset of signature
+		input.extend_from_slice(H256::from_low_u64_be(64).as_bytes());
+		// append the length of signature
+		input.extend_from_slice(H256::from_low_u64_be(signature.len() as u64).as_bytes());
+		// append signature, padded to 32 bytes
+		input.extend_from_slice(signature);
+		input.resize(input.len() + (32 - signature.len() % 32) % 32, 0);
+
+		let info = match T::EVM::execute(
+			InvokeContext {
+				contract,
+				sender: Default::default(),
+				origin: Default::default(),
+			},
+			input,
+			Default::default(),
+			erc1271::IS_VALID_SIGNATURE.gas,
+			erc1271::IS_VALID_SIGNATURE.storage,
+			ExecutionMode::View,
+		) {
+			Ok(info) => info,
+			Err(_) => return false,
+		};
+
+		if Pallet::<T>::handle_exit_reason(info.exit_reason).is_err() || info.value.len() != 32 {
+			return false;
+		}
+
+		// bytes4 is left aligned
+		let mut magic_value = [0u8; 32];
+		magic_value[0..4].copy_from_slice(&EIP1271_MAGIC_VALUE.to_be_bytes());
+		info.value == magic_value
+	}
+
+	fn weight() -> Weight {
+		GasToWeight::convert(erc1271::IS_VALID_SIGNATURE.gas)
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	fn handle_exit_reason(exit_reason: ExitReason) -> Result<(), DispatchError> {
 		match exit_reason {
@@ -357,6 +498,45 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	fn ensure_returned_true(output: Vec<u8>) -> DispatchResult {
+		// return value is true.
+		let mut bytes = [0u8; 32];
+		U256::from(1).to_big_endian(&mut bytes);
+
+		// Check return value to make sure not calling on empty contracts.
+		ensure!(!output.is_empty() && output == bytes, Error::<T>::InvalidReturnValue);
+		Ok(())
+	}
+
+	// Returns None if the call fails or the return value is not a bool.
+	fn call_supports_interface(context: InvokeContext, interface_id: u32) -> Option<bool> {
+		// ERC165.supportsInterface method hash
+		let mut input = Into::<u32>::into(Action::SupportsInterface).to_be_bytes().to_vec();
+		// append interface id, bytes4 is left aligned
+		input.extend_from_slice(&interface_id.to_be_bytes());
+		input.extend_from_slice(&[0u8; 28]);
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			erc20::SUPPORTS_INTERFACE.gas,
+			erc20::SUPPORTS_INTERFACE.storage,
+			ExecutionMode::View,
+		)
+		.ok()?;
+
+		Self::handle_exit_reason(info.exit_reason).ok()?;
+		if info.value.len() != 32 {
+			return None;
+		}
+		match U256::from(info.value.as_slice()) {
+			v if v.is_zero() => Some(false),
+			v if v == U256::one() => Some(true),
+			_ => None,
+		}
+	}
+
 	fn decode_string(output: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
 		// output is 32-byte aligned and consists of 3 parts:
 		// - part 1: 32 byte, the offset of its description is passed in the position of
@@ -201,6 +201,119 @@ fn should_transfer() {
 		});
 }
 
+#[test]
+fn should_approve_and_transfer_from() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000), (bob(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let context = InvokeContext {
+				contract: erc20_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(0)
+			);
+
+			// bob has no allowance
+			assert_err!(
+				EVMBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					alice_evm_addr(),
+					bob_evm_addr(),
+					10
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+
+			assert_ok!(EVMBridge::<Runtime>::approve(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_evm_addr(),
+					origin: alice_evm_addr(),
+				},
+				bob_evm_addr(),
+				100
+			));
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(100)
+			);
+
+			assert_ok!(EVMBridge::<Runtime>::transfer_from(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: bob_evm_addr(),
+					origin: bob_evm_addr(),
+				},
+				alice_evm_addr(),
+				bob_evm_addr(),
+				60
+			));
+			assert_eq!(EVMBridge::<Runtime>::balance_of(context, bob_evm_addr()), Ok(60));
+			assert_eq!(
+				EVMBridge::<Runtime>::balance_of(context, alice_evm_addr()),
+				Ok(ALICE_BALANCE - 60)
+			);
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(40)
+			);
+
+			// exceeds the allowance
+			assert_err!(
+				EVMBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					alice_evm_addr(),
+					bob_evm_addr(),
+					41
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+		});
+}
+
+#[test]
+fn should_detect_supported_interface() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let context = InvokeContext {
+				contract: erc20_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+
+			// the demo token doesn't implement ERC-165
+			assert!(!EVMBridge::<Runtime>::supports_interface(
+				context,
+				module_support::evm::interface_id::ERC20
+			));
+			// not a contract
+			assert!(!EVMBridge::<Runtime>::supports_interface(
+				InvokeContext {
+					contract: bob_evm_addr(),
+					sender: Default::default(),
+					origin: Default::default(),
+				},
+				module_support::evm::interface_id::ERC20
+			));
+		});
+}
+
 #[test]
 fn liquidation_works() {
 	ExtBuilder::default()
@@ -19,16 +19,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use primitives::evm::{AccessListItem, BlockLimits, CallInfo, CreateInfo, EstimateResourcesRequest};
-use sp_core::H160;
+use primitives::{
+	evm::{
+		AccessListItem, BlockLimits, CallInfo, ContractStorageUsage, CreateInfo, EstimateResourcesRequest,
+		EstimateResourcesResponse, EvmReceipt, StateOverride, TraceOutcome, TracerConfig,
+	},
+	CurrencyId,
+};
+use sp_core::{H160, H256, U256};
 use sp_runtime::{
 	codec::Codec,
-	traits::{MaybeDisplay, MaybeFromStr},
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
 };
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(8)]
 	pub trait EVMRuntimeRPCApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
@@ -56,5 +62,79 @@ sp_api::decl_runtime_apis! {
 		fn get_estimate_resources_request(data: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
 
 		fn block_limits() -> BlockLimits;
+
+		/// Apply the extrinsic and trace the EVM executions in it.
+		/// The extrinsics before it in the block must be applied first.
+		fn trace_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+			tracer_config: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError>;
+
+		/// Trace the call, or the create if `to` is None.
+		fn trace_call(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError>;
+
+		fn chain_id() -> u64;
+
+		/// The free balance in EVM decimals.
+		fn balance(address: H160) -> U256;
+
+		fn code_at(address: H160) -> Vec<u8>;
+
+		fn storage_at(address: H160, index: H256) -> H256;
+
+		/// The EVM receipts of the block, built from its events.
+		fn receipts() -> Vec<EvmReceipt>;
+
+		/// Estimate the lowest gas and storage limits of the call, or the create if `to` is None,
+		/// and the fee of the extrinsic with the estimated limits, in native currency and in
+		/// `fee_currency_id` if given.
+		fn estimate_resources(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			fee_currency_id: Option<CurrencyId>,
+		) -> Result<EstimateResourcesResponse, sp_runtime::DispatchError>;
+
+		/// Dry-run the call on top of the overridden account states, which are never persisted.
+		fn call_with_state_override(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			state_override: StateOverride,
+		) -> Result<CallInfo, sp_runtime::DispatchError>;
+
+		/// The storage usages and storage deposits of up to `limit` contracts, starting after the
+		/// contract `start` if provided.
+		fn contract_storage_usages(start: Option<H160>, limit: u32) -> Vec<ContractStorageUsage>;
+
+		/// Dry-run the call of `from` sponsored by `paymaster`, fails if `paymaster` does not
+		/// sponsor `from` for the call or can't pay for it.
+		fn sponsored_call(
+			from: H160,
+			paymaster: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+		) -> Result<CallInfo, sp_runtime::DispatchError>;
 	}
 }
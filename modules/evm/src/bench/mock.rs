@@ -181,6 +181,7 @@ impl FindAuthor<AccountId32> for AuthorGiven {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(1);
+	pub const Hardfork: EvmHardfork = EvmHardfork::TransientStorage;
 }
 
 ord_parameter_types! {
@@ -205,11 +206,15 @@ impl Config for Runtime {
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Runtime>;
 
 	type NetworkContractOrigin = EnsureSignedBy<NetworkContractAccount, AccountId32>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = ConstU128<1000>;
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type ContractUpgradeDelay = ConstU32<10>;
+	type SponsorshipDeposit = ConstU128<100>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 
 	type Runner = crate::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
@@ -221,6 +226,7 @@ impl Config for Runtime {
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::one();
+	pub MaxRateDeviation: Ratio = Ratio::from_percent(10);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"aca/trsy");
 	pub const TransactionPaymentPalletId: PalletId = PalletId(*b"aca/fees");
 	pub KaruraTreasuryAccount: AccountId32 = TreasuryPalletId::get().into_account_truncating();
@@ -263,6 +269,9 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
+	type DEXPriceSource = ();
+	type RateRefreshPeriod = ConstU32<10>;
+	type MaxRateDeviation = MaxRateDeviation;
 	type WeightInfo = ();
 	type PalletId = TransactionPaymentPalletId;
 	type TreasuryAccount = KaruraTreasuryAccount;
@@ -270,6 +279,7 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AssetStatusProvider = ();
 }
 
 pub struct MockDEXIncentives;
@@ -301,6 +311,7 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId32>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type AssetStatusProvider = ();
 }
 
 pub type SignedExtra = (frame_system::CheckWeight<Runtime>,);
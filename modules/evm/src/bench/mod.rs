@@ -78,7 +78,7 @@ fn whitelist_keys(b: &mut Bencher, from: H160, code: Vec<u8>) -> H160 {
 	let config = <Runtime as Config>::config();
 	let metadata = StackSubstateMetadata::new(21_000_000, 1_000_000, config);
 	let state = SubstrateStackState::<Runtime>::new(&vicinity, metadata);
-	let mut executor = StackExecutor::new_with_precompiles(state, config, &());
+	let mut executor = StackExecutor::new_with_precompiles(state, config, <Runtime as Config>::Hardfork::get(), &());
 
 	let mut runtime = EVMRuntime::new(Rc::new(code.clone()), Rc::new(Vec::new()), context, config);
 	let reason = executor.execute(&mut runtime);
@@ -22,6 +22,7 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
+use crate::runner::RunnerExtended;
 pub use crate::runner::{
 	stack::SubstrateStackState,
 	state::{PrecompileSet, StackExecutor, StackSubstateMetadata},
@@ -29,7 +30,9 @@ pub use crate::runner::{
 	Runner,
 };
 use frame_support::{
-	dispatch::{DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, Pays, PostDispatchInfo},
+	dispatch::{
+		DispatchClass, DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, Pays, PostDispatchInfo,
+	},
 	ensure,
 	error::BadOrigin,
 	pallet_prelude::*,
@@ -57,8 +60,9 @@ pub use orml_traits::{currency::TransferAll, MultiCurrency};
 use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 pub use primitives::{
 	evm::{
-		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, is_system_contract, CallInfo, CreateInfo,
-		EvmAddress, ExecutionInfo, Vicinity, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
+		convert_decimals_from_evm, convert_decimals_to_evm, decode_gas_limit, decode_revert_reason, is_system_contract,
+		CallInfo, ContractStorageUsage, CreateInfo, EstimateResourcesResponse, EvmAddress, EvmReceipt, ExecutionInfo,
+		StateOverride, Vicinity, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
 	},
 	task::TaskResult,
 	Balance, CurrencyId, Nonce, ReserveIdentifier,
@@ -99,6 +103,7 @@ pub type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 pub const RESERVE_ID_STORAGE_DEPOSIT: ReserveIdentifier = ReserveIdentifier::EvmStorageDeposit;
 pub const RESERVE_ID_DEVELOPER_DEPOSIT: ReserveIdentifier = ReserveIdentifier::EvmDeveloperDeposit;
+pub const RESERVE_ID_SPONSORSHIP_DEPOSIT: ReserveIdentifier = ReserveIdentifier::EvmSponsorshipDeposit;
 
 // Initially based on London hard fork configuration.
 static ACALA_CONFIG: EvmConfig = EvmConfig {
@@ -109,6 +114,39 @@ static ACALA_CONFIG: EvmConfig = EvmConfig {
 	..module_evm_utility::evm::Config::london()
 };
 
+/// The EVM rule sets enabled on top of the London based `EvmConfig`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug)]
+pub enum EvmHardfork {
+	London,
+	/// EIP-3651 warm `COINBASE`, EIP-3855 `PUSH0`, EIP-3860 initcode limit and metering.
+	Shanghai,
+	/// EIP-1153 transient storage, EIP-5656 `MCOPY`.
+	/// Note: not Cancun, EIP-6780 `SELFDESTRUCT` changes and the blob opcodes are not included.
+	TransientStorage,
+}
+
+impl EvmHardfork {
+	pub fn has_push0(&self) -> bool {
+		*self >= EvmHardfork::Shanghai
+	}
+
+	pub fn has_initcode_limit(&self) -> bool {
+		*self >= EvmHardfork::Shanghai
+	}
+
+	pub fn has_warm_coinbase(&self) -> bool {
+		*self >= EvmHardfork::Shanghai
+	}
+
+	pub fn has_transient_storage(&self) -> bool {
+		*self >= EvmHardfork::TransientStorage
+	}
+
+	pub fn has_mcopy(&self) -> bool {
+		*self >= EvmHardfork::TransientStorage
+	}
+}
+
 /// Create an empty contract `contract Empty { }`.
 pub const BASE_CREATE_GAS: u64 = 67_066;
 /// Call function that just set a storage `function store(uint256 num) public { number = num; }`.
@@ -154,6 +192,13 @@ fn call_weight<T: Config>(gas: u64) -> Weight {
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CALL_GAS)))
 }
 
+/// Helper method to calculate `batch_call` weight.
+fn batch_call_weight<T: Config>(calls: &[EvmCall<BalanceOf<T>>]) -> Weight {
+	calls.iter().fold(Weight::zero(), |acc, call| {
+		acc.saturating_add(call_weight::<T>(call.gas_limit))
+	})
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -211,6 +256,9 @@ pub mod module {
 			&ACALA_CONFIG
 		}
 
+		/// EVM hardfork rule sets enabled on top of `config()`.
+		type Hardfork: Get<EvmHardfork>;
+
 		/// Required origin for creating system contract.
 		type NetworkContractOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -231,6 +279,19 @@ pub mod module {
 
 		type FreePublicationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The delay before a proposed contract upgrade can be applied.
+		#[pallet::constant]
+		type ContractUpgradeDelay: Get<BlockNumberFor<Self>>;
+
+		/// Deposit for a sponsorship record of a paymaster.
+		#[pallet::constant]
+		type SponsorshipDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of contracts to check the storage deposit in `on_idle` per block,
+		/// zero to disable the check.
+		#[pallet::constant]
+		type StorageDepositCheckLimit: Get<u32>;
+
 		/// EVM execution runner.
 		type Runner: Runner<Self>;
 
@@ -272,6 +333,49 @@ pub mod module {
 		pub ref_count: u32,
 	}
 
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Default)]
+	pub struct SponsorQuota {
+		/// The remaining gas the paymaster covers.
+		pub gas: u64,
+		/// The remaining storage the paymaster pays the deposit for.
+		pub storage: u32,
+	}
+
+	#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct Sponsorship<AccountId, Balance> {
+		/// The remaining quota of the sponsorship.
+		pub quota: SponsorQuota,
+		/// The account that set the sponsorship and reserved the deposit.
+		pub depositor: AccountId,
+		/// The deposit for storing the sponsorship.
+		pub deposit: Balance,
+	}
+
+	#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
+	pub struct EvmCall<Balance> {
+		pub target: EvmAddress,
+		pub input: Vec<u8>,
+		#[codec(compact)]
+		pub value: Balance,
+		#[codec(compact)]
+		pub gas_limit: u64,
+		#[codec(compact)]
+		pub storage_limit: u32,
+		pub access_list: Vec<AccessListItem>,
+	}
+
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct ContractUpgrade<BlockNumber, Balance> {
+		/// The code hash of the new code.
+		pub code_hash: H256,
+		/// The block number from which the upgrade can be applied.
+		pub effective_block: BlockNumber,
+		/// The account that proposed the upgrade and reserved the deposit.
+		pub proposer: EvmAddress,
+		/// The deposit for storing the new code until the upgrade is applied or cancelled.
+		pub deposit: Balance,
+	}
+
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default, Serialize, Deserialize)]
 	/// Account definition used for genesis block construction.
 	pub struct GenesisAccount<Balance, Index> {
@@ -333,6 +437,51 @@ pub mod module {
 	#[pallet::getter(fn code_infos)]
 	pub type CodeInfos<T: Config> = StorageMap<_, Identity, H256, CodeInfo, OptionQuery>;
 
+	/// The pending code upgrades of EVM contracts.
+	///
+	/// ContractUpgrades: map EvmAddress => Option<ContractUpgrade<BlockNumber, Balance>>
+	#[pallet::storage]
+	#[pallet::getter(fn contract_upgrades)]
+	pub type ContractUpgrades<T: Config> =
+		StorageMap<_, Twox64Concat, EvmAddress, ContractUpgrade<BlockNumberFor<T>, BalanceOf<T>>, OptionQuery>;
+
+	/// The published contracts whose maintainers opted in to upgrade the code with
+	/// `propose_contract_upgrade` when publishing.
+	///
+	/// UpgradeableContracts: map EvmAddress => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn upgradeable_contracts)]
+	pub type UpgradeableContracts<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, (), OptionQuery>;
+
+	/// The sponsorship a paymaster provides for a user.
+	///
+	/// Sponsorships: double_map paymaster: EvmAddress, user: EvmAddress => Option<Sponsorship>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	pub type Sponsorships<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EvmAddress,
+		Twox64Concat,
+		EvmAddress,
+		Sponsorship<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The raw key of ContractStorageSizes where the next batch of storage deposit check starts
+	/// from.
+	///
+	/// StorageDepositCheckCursor: Vec<u8>
+	#[pallet::storage]
+	pub type StorageDepositCheckCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The contracts reported with insufficient storage deposit, which are reported again only
+	/// after the storage deposit became sufficient.
+	///
+	/// InsufficientStorageDeposits: map EvmAddress => Option<()>
+	#[pallet::storage]
+	pub type InsufficientStorageDeposits<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, (), OptionQuery>;
+
 	/// Next available system contract address.
 	///
 	/// NetworkContractIndex: u64
@@ -408,7 +557,7 @@ pub mod module {
 					};
 					let metadata = StackSubstateMetadata::new(210_000, 1000, T::config());
 					let state = SubstrateStackState::<T>::new(&vicinity, metadata);
-					let mut executor = StackExecutor::new_with_precompiles(state, T::config(), &());
+					let mut executor = StackExecutor::new_with_precompiles(state, T::config(), T::Hardfork::get(), &());
 
 					let mut runtime =
 						evm::Runtime::new(Rc::new(account.code.clone()), Rc::new(Vec::new()), context, T::config());
@@ -488,6 +637,41 @@ pub mod module {
 		ContractSetCode { contract: EvmAddress },
 		/// Selfdestructed contract code.
 		ContractSelfdestructed { contract: EvmAddress },
+		/// Proposed contract code upgrade.
+		ContractUpgradeProposed {
+			contract: EvmAddress,
+			code_hash: H256,
+			effective_block: BlockNumberFor<T>,
+		},
+		/// Cancelled contract code upgrade.
+		ContractUpgradeCancelled { contract: EvmAddress, code_hash: H256 },
+		/// Upgraded contract code.
+		ContractUpgraded { contract: EvmAddress, code_hash: H256 },
+		/// Updated the sponsorship of a paymaster for a user.
+		SponsorshipUpdated {
+			paymaster: EvmAddress,
+			user: EvmAddress,
+			quota: Option<SponsorQuota>,
+		},
+		/// The paymaster paid for a call of the user.
+		CallSponsored {
+			paymaster: EvmAddress,
+			user: EvmAddress,
+			used_gas: u64,
+			used_storage: i32,
+		},
+		/// The reserved storage deposit of the contract is below the requirement.
+		ContractStorageDepositInsufficient {
+			contract: EvmAddress,
+			required: BalanceOf<T>,
+			reserved: BalanceOf<T>,
+		},
+		/// Topped up the storage deposit of the contract.
+		ContractStorageDepositToppedUp {
+			contract: EvmAddress,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -526,6 +710,16 @@ pub mod module {
 		StrictCallFailed,
 		/// Caller is not externally owned account
 		NotEOA,
+		/// Contract upgrade not found
+		ContractUpgradeNotFound,
+		/// Contract upgrade is still timelocked
+		ContractUpgradeNotReady,
+		/// The user is not sponsored by the paymaster
+		NotSponsored,
+		/// Exceeds the quota sponsored by the paymaster
+		SponsorQuotaExceeded,
+		/// The storage deposit of the contract is sufficient
+		StorageDepositSufficient,
 	}
 
 	#[pallet::pallet]
@@ -534,6 +728,10 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::check_storage_deposits(remaining_weight)
+		}
+
 		fn integrity_test() {
 			assert!(convert_decimals_from_evm(T::StorageDepositPerByte::get()).is_some());
 		}
@@ -1179,6 +1377,23 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Mark a given contract as published and keep its code upgradeable by the maintainer
+		/// with `propose_contract_upgrade`. The code of the contracts published by
+		/// `publish_contract` or `publish_free` can not be upgraded.
+		///
+		/// - `contract`: The contract to mark as published, the caller must the contract's
+		///   maintainer
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::publish_contract().saturating_add(T::DbWeight::get().writes(1)))]
+		pub fn publish_upgradeable_contract(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_publish_contract(who, contract)?;
+			UpgradeableContracts::<T>::insert(contract, ());
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractPublished { contract });
+			Ok(().into())
+		}
+
 		/// Mark the caller's address to allow contract development.
 		/// This allows the address to interact with non-published contracts.
 		#[pallet::call_index(10)]
@@ -1233,6 +1448,218 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Propose to upgrade the code of a contract. The upgrade can be applied after
+		/// `ContractUpgradeDelay` blocks and replaces the pending upgrade of the contract if any.
+		///
+		/// - `contract`: The contract to upgrade, the caller must be the contract's maintainer,
+		///   must not be marked as published unless published by `publish_upgradeable_contract`
+		/// - `code`: The new code of the contract
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_contract_upgrade(code.len() as u32))]
+		pub fn propose_contract_upgrade(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			code: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let maintainer = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
+			let upgrade = Self::do_propose_contract_upgrade(&maintainer, contract, code)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractUpgradeProposed {
+				contract,
+				code_hash: upgrade.code_hash,
+				effective_block: upgrade.effective_block,
+			});
+
+			Ok(().into())
+		}
+
+		/// Cancel the pending code upgrade of a contract.
+		///
+		/// - `contract`: The contract of the upgrade, the caller must be the contract's maintainer
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_contract_upgrade())]
+		pub fn cancel_contract_upgrade(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let maintainer = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::AddressNotMapped)?;
+			let code_hash = Self::do_cancel_contract_upgrade(&maintainer, contract)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractUpgradeCancelled { contract, code_hash });
+
+			Ok(().into())
+		}
+
+		/// Apply the pending code upgrade of a contract once the timelock has passed. The
+		/// contract storage is kept and the storage deposit for the code size change is
+		/// charged from or refunded to the maintainer as `set_code`.
+		///
+		/// - `contract`: The contract of the upgrade
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::apply_contract_upgrade())]
+		pub fn apply_contract_upgrade(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let code_hash = Self::do_apply_contract_upgrade(contract)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractUpgraded { contract, code_hash });
+
+			Ok(().into())
+		}
+
+		/// Set the quota a paymaster sponsors for a user, `None` to remove the sponsorship.
+		///
+		/// The caller reserves `SponsorshipDeposit` for a new sponsorship, which is returned when
+		/// the sponsorship is removed.
+		///
+		/// - `paymaster`: the paymaster, the caller must be the paymaster or the maintainer if the
+		///   paymaster is a contract
+		/// - `user`: the sponsored user
+		/// - `quota`: the gas and storage the paymaster covers for the user
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship())]
+		pub fn set_sponsorship(
+			origin: OriginFor<T>,
+			paymaster: EvmAddress,
+			user: EvmAddress,
+			quota: Option<SponsorQuota>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let caller = T::AddressMapping::get_or_create_evm_address(&who);
+			ensure!(
+				caller == paymaster || Self::contract_maintainer(&paymaster).ok() == Some(caller),
+				Error::<T>::NoPermission
+			);
+
+			Sponsorships::<T>::try_mutate_exists(paymaster, user, |maybe_sponsorship| -> DispatchResult {
+				match (maybe_sponsorship.take(), quota) {
+					(Some(mut sponsorship), Some(quota)) => {
+						sponsorship.quota = quota;
+						*maybe_sponsorship = Some(sponsorship);
+					}
+					(None, Some(quota)) => {
+						let deposit = T::SponsorshipDeposit::get();
+						T::Currency::reserve_named(&RESERVE_ID_SPONSORSHIP_DEPOSIT, &who, deposit)?;
+						*maybe_sponsorship = Some(Sponsorship {
+							quota,
+							depositor: who.clone(),
+							deposit,
+						});
+					}
+					(Some(sponsorship), None) => {
+						// should always be able to unreserve the amount
+						// but otherwise we will just ignore the issue here.
+						let err_amount = T::Currency::unreserve_named(
+							&RESERVE_ID_SPONSORSHIP_DEPOSIT,
+							&sponsorship.depositor,
+							sponsorship.deposit,
+						);
+						debug_assert!(err_amount.is_zero());
+					}
+					(None, None) => {}
+				}
+				Ok(())
+			})?;
+
+			Pallet::<T>::deposit_event(Event::<T>::SponsorshipUpdated { paymaster, user, quota });
+
+			Ok(().into())
+		}
+
+		/// Issue an EVM call operation paid by a paymaster. This is same as the evm.strict_call
+		/// but the paymaster pays the call fee and the storage fee within the quota it sponsors
+		/// for the caller. The caller is still the `tx.origin` of the call and reserves the
+		/// storage deposit, the paymaster transfers the deposit of the used storage to the caller.
+		///
+		/// - `paymaster`: the paymaster sponsoring the caller
+		/// - `target`: the contract address to call
+		/// - `input`: the data supplied for the call
+		/// - `value`: the amount sent for payable calls
+		/// - `gas_limit`: the maximum gas the call can use
+		/// - `storage_limit`: the total bytes the contract's storage can increase by
+		#[pallet::call_index(20)]
+		#[pallet::weight(call_weight::<T>(*gas_limit))]
+		pub fn sponsored_call(
+			origin: OriginFor<T>,
+			paymaster: EvmAddress,
+			target: EvmAddress,
+			input: Vec<u8>,
+			#[pallet::compact] value: BalanceOf<T>,
+			#[pallet::compact] gas_limit: u64,
+			#[pallet::compact] storage_limit: u32,
+			access_list: Vec<AccessListItem>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let source = T::AddressMapping::get_or_create_evm_address(&who);
+
+			Self::ensure_eoa(&source)?;
+
+			let used_gas = Self::do_strict_call(
+				source,
+				Some(paymaster),
+				EvmCall {
+					target,
+					input,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list,
+				},
+			)?;
+
+			Ok(PostDispatchInfo {
+				actual_weight: Some(call_weight::<T>(used_gas)),
+				pays_fee: Pays::No,
+			})
+		}
+
+		/// Issue several EVM call operations atomically. Returns error and reverts all the
+		/// calls if any of them failed.
+		///
+		/// - `calls`: the calls to issue in order
+		/// - `paymaster`: the paymaster paying for all the calls if any, see `sponsored_call`
+		#[pallet::call_index(21)]
+		#[pallet::weight(batch_call_weight::<T>(calls))]
+		pub fn batch_call(
+			origin: OriginFor<T>,
+			calls: Vec<EvmCall<BalanceOf<T>>>,
+			paymaster: Option<EvmAddress>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let source = T::AddressMapping::get_or_create_evm_address(&who);
+
+			Self::ensure_eoa(&source)?;
+
+			let mut actual_weight = Weight::zero();
+			for call in calls {
+				let used_gas = Self::do_strict_call(source, paymaster, call).map_err(|mut e| {
+					e.post_info.actual_weight =
+						Some(actual_weight.saturating_add(e.post_info.actual_weight.unwrap_or_default()));
+					e
+				})?;
+				actual_weight = actual_weight.saturating_add(call_weight::<T>(used_gas));
+			}
+
+			Ok(PostDispatchInfo {
+				actual_weight: Some(actual_weight),
+				pays_fee: if paymaster.is_some() { Pays::No } else { Pays::Yes },
+			})
+		}
+
+		/// Top up the storage deposit of a contract to the requirement of the current storage
+		/// deposit per byte. The deposit belongs to the contract and is refunded to the maintainer
+		/// when the contract is removed.
+		///
+		/// - `contract`: the contract to top up
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up_storage_deposit())]
+		pub fn top_up_storage_deposit(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let amount = Self::do_top_up_storage_deposit(&who, contract)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::ContractStorageDepositToppedUp { contract, who, amount });
+
+			Ok(().into())
+		}
+
 		/// Issue an EVM call operation in `Utility::batch_all`. This is same as the evm.call but
 		/// returns error when it failed. The current evm.call always success and emit event to
 		/// indicate it failed.
@@ -1258,53 +1685,23 @@ pub mod module {
 
 			Self::ensure_eoa(&source)?;
 
-			match T::Runner::call(
+			let used_gas = Self::do_strict_call(
 				source,
-				source,
-				target,
-				input,
-				value,
-				gas_limit,
-				storage_limit,
-				access_list.into_iter().map(|v| (v.address, v.storage_keys)).collect(),
-				T::config(),
-			) {
-				Err(e) => Err(DispatchErrorWithPostInfo {
-					post_info: ().into(),
-					error: e,
-				}),
-				Ok(info) => {
-					let used_gas: u64 = info.used_gas.unique_saturated_into();
-
-					if info.exit_reason.is_succeed() {
-						Pallet::<T>::deposit_event(Event::<T>::Executed {
-							from: source,
-							contract: target,
-							logs: info.logs,
-							used_gas,
-							used_storage: info.used_storage,
-						});
+				None,
+				EvmCall {
+					target,
+					input,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list,
+				},
+			)?;
 
-						Ok(PostDispatchInfo {
-							actual_weight: Some(call_weight::<T>(used_gas)),
-							pays_fee: Pays::Yes,
-						})
-					} else {
-						log::debug!(
-							target: "evm",
-							"batch_call failed: [from: {:?}, contract: {:?}, exit_reason: {:?}, output: {:?}, logs: {:?}, used_gas: {:?}]",
-							source, target, info.exit_reason, info.value, info.logs, used_gas
-						);
-						Err(DispatchErrorWithPostInfo {
-							post_info: PostDispatchInfo {
-								actual_weight: Some(call_weight::<T>(used_gas)),
-								pays_fee: Pays::Yes,
-							},
-							error: Error::<T>::StrictCallFailed.into(),
-						})
-					}
-				}
-			}
+			Ok(PostDispatchInfo {
+				actual_weight: Some(call_weight::<T>(used_gas)),
+				pays_fee: Pays::Yes,
+			})
 		}
 	}
 }
@@ -1379,6 +1776,12 @@ impl<T: Config> Pallet<T> {
 				});
 
 				let _total_size = ContractStorageSizes::<T>::take(contract);
+				InsufficientStorageDeposits::<T>::remove(contract);
+
+				if let Some(upgrade) = ContractUpgrades::<T>::take(contract) {
+					Self::release_contract_upgrade(&upgrade);
+				}
+				UpgradeableContracts::<T>::remove(contract);
 
 				// schedule to remove
 				T::IdleScheduler::schedule(
@@ -1517,6 +1920,274 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Build the EVM receipt from the event deposited by the extrinsic at `extrinsic_index`.
+	pub fn receipt_from_event(extrinsic_index: u32, event: Event<T>) -> Option<EvmReceipt> {
+		let (from, to, contract_address, logs, used_gas, used_storage, status) = match event {
+			Event::Created {
+				from,
+				contract,
+				logs,
+				used_gas,
+				used_storage,
+			} => (from, None, Some(contract), logs, used_gas, used_storage, true),
+			Event::CreatedFailed {
+				from,
+				contract,
+				logs,
+				used_gas,
+				used_storage,
+				..
+			} => (from, None, Some(contract), logs, used_gas, used_storage, false),
+			Event::Executed {
+				from,
+				contract,
+				logs,
+				used_gas,
+				used_storage,
+			} => (from, Some(contract), None, logs, used_gas, used_storage, true),
+			Event::ExecutedFailed {
+				from,
+				contract,
+				logs,
+				used_gas,
+				used_storage,
+				..
+			} => (from, Some(contract), None, logs, used_gas, used_storage, false),
+			_ => return None,
+		};
+
+		Some(EvmReceipt {
+			extrinsic_index,
+			from,
+			to,
+			contract_address,
+			logs,
+			used_gas,
+			used_storage,
+			status,
+		})
+	}
+
+	/// Estimate the resources of the call, or the create if `to` is None. Binary search the
+	/// lowest gas limit and then the lowest storage limit the execution succeeds with, within
+	/// `gas_limit` and `storage_limit`. The fees are left to the caller as they are charged by
+	/// the transaction payment.
+	pub fn estimate_resources(
+		from: H160,
+		to: Option<H160>,
+		data: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+	) -> Result<EstimateResourcesResponse, DispatchError> {
+		let execute = |gas_limit, storage_limit, estimate| {
+			Self::simulate(from, to, &data, value, gas_limit, storage_limit, &access_list, estimate)
+		};
+		// the execution fails with `OutOfStorage` error if the storage limit is too low
+		let succeed = |gas_limit, storage_limit| {
+			execute(gas_limit, storage_limit, false).map_or(false, |(reason, ..)| reason.is_succeed())
+		};
+
+		let (exit_reason, output, used_gas, used_storage) = execute(gas_limit, storage_limit, true)?;
+		let (gas, storage) = if exit_reason.is_succeed() {
+			// `used_gas` is the lower bound, the sub calls can't use all the remaining gas
+			let gas = if succeed(used_gas, storage_limit) {
+				used_gas
+			} else {
+				let (mut low, mut high) = (used_gas, gas_limit);
+				while low.saturating_add(1) < high {
+					let mid = low.saturating_add(high) / 2;
+					if succeed(mid, storage_limit) {
+						high = mid;
+					} else {
+						low = mid;
+					}
+				}
+				high
+			};
+
+			// the net storage is the lower bound, the storage may be released after used
+			let min_storage = used_storage.max(0) as u32;
+			let storage = if succeed(gas, min_storage) {
+				min_storage
+			} else {
+				let (mut low, mut high) = (min_storage, storage_limit);
+				while low.saturating_add(1) < high {
+					let mid = low.saturating_add(high) / 2;
+					if succeed(gas, mid) {
+						high = mid;
+					} else {
+						low = mid;
+					}
+				}
+				high
+			};
+
+			(gas, storage)
+		} else {
+			(gas_limit, storage_limit)
+		};
+
+		let (exit_reason, output, used_gas, used_storage) = if exit_reason.is_succeed() {
+			execute(gas, storage, false)?
+		} else {
+			(exit_reason, output, used_gas, used_storage)
+		};
+		let revert_reason = match exit_reason {
+			ExitReason::Revert(_) => decode_revert_reason(&output),
+			_ => None,
+		};
+		let weight = match to {
+			Some(_) => call_weight::<T>(gas),
+			None => create_weight::<T>(gas),
+		};
+
+		Ok(EstimateResourcesResponse {
+			gas,
+			used_gas,
+			storage,
+			used_storage,
+			weight,
+			fee: Zero::zero(),
+			fee_in_currency: None,
+			exit_reason,
+			output,
+			revert_reason,
+		})
+	}
+
+	/// Execute the call, or the create if `to` is None, and discard the changes.
+	/// Returns (exit_reason, output, used_gas, used_storage).
+	fn simulate(
+		from: H160,
+		to: Option<H160>,
+		data: &[u8],
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: &[(H160, Vec<H256>)],
+		estimate: bool,
+	) -> Result<(ExitReason, Vec<u8>, u64, i32), DispatchError> {
+		let mut config = T::config().clone();
+		config.estimate = estimate;
+
+		frame_support::storage::with_transaction(|| {
+			let result = match to {
+				Some(to) => runner::stack::Runner::<T>::rpc_call(
+					from,
+					from,
+					to,
+					data.to_vec(),
+					value,
+					gas_limit,
+					storage_limit,
+					access_list.to_vec(),
+					&config,
+				)
+				.map(|info| (info.exit_reason, info.value, info.used_gas, info.used_storage)),
+				None => runner::stack::Runner::<T>::rpc_create(
+					from,
+					data.to_vec(),
+					value,
+					gas_limit,
+					storage_limit,
+					access_list.to_vec(),
+					&config,
+				)
+				// the revert data of create is not returned
+				.map(|info| (info.exit_reason, vec![], info.used_gas, info.used_storage)),
+			};
+
+			TransactionOutcome::Rollback(result.map(|(exit_reason, output, used_gas, used_storage)| {
+				(exit_reason, output, used_gas.unique_saturated_into(), used_storage)
+			}))
+		})
+	}
+
+	/// Override the account states for a dry-run call. The changes must be discarded, so it must
+	/// be called in a transaction which is rolled back, e.g. `simulate_execution`.
+	pub fn apply_state_override(state_override: StateOverride) -> DispatchResult {
+		for (address, account_override) in state_override {
+			if let Some(balance) = account_override.balance {
+				let balance = TryInto::<BalanceOf<T>>::try_into(balance)
+					.ok()
+					.and_then(convert_decimals_from_evm)
+					.ok_or(Error::<T>::InvalidDecimals)?;
+				let account_id = T::AddressMapping::get_account_id(&address);
+				let _ = T::Currency::make_free_balance_be(&account_id, balance);
+			}
+
+			if let Some(nonce) = account_override.nonce {
+				Accounts::<T>::mutate(address, |maybe_account_info| {
+					maybe_account_info
+						.get_or_insert_with(|| AccountInfo::new(Default::default(), None))
+						.nonce = nonce.into();
+				});
+			}
+
+			if let Some(code) = account_override.code {
+				let bounded_code: BoundedVec<u8, MaxCodeSize> =
+					code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
+				let code_hash = code_hash(bounded_code.as_slice());
+				let code_size = bounded_code.len() as u32;
+
+				let contract_info = if bounded_code.is_empty() {
+					None
+				} else {
+					CodeInfos::<T>::mutate_exists(code_hash, |maybe_code_info| {
+						if let Some(code_info) = maybe_code_info.as_mut() {
+							code_info.ref_count = code_info.ref_count.saturating_add(1);
+						} else {
+							*maybe_code_info = Some(CodeInfo {
+								code_size,
+								ref_count: 1,
+							});
+							Codes::<T>::insert(code_hash, bounded_code);
+						}
+					});
+
+					// keep the maintainer of the existing contract
+					let maintainer = Self::accounts(address)
+						.and_then(|account_info| account_info.contract_info)
+						.map_or(address, |contract_info| contract_info.maintainer);
+					Some(ContractInfo {
+						code_hash,
+						maintainer,
+						published: true,
+					})
+				};
+
+				Accounts::<T>::mutate(address, |maybe_account_info| {
+					maybe_account_info
+						.get_or_insert_with(|| AccountInfo::new(Default::default(), None))
+						.contract_info = contract_info;
+				});
+			}
+
+			if let Some(state) = account_override.state {
+				AccountStorages::<T>::drain_prefix(address).for_each(drop);
+				for (index, value) in state {
+					if !value.is_zero() {
+						AccountStorages::<T>::insert(address, index, value);
+					}
+				}
+			}
+
+			if let Some(state_diff) = account_override.state_diff {
+				for (index, value) in state_diff {
+					if value.is_zero() {
+						AccountStorages::<T>::remove(address, index);
+					} else {
+						AccountStorages::<T>::insert(address, index, value);
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
 	/// Get the author using the FindAuthor trait.
 	pub fn find_author() -> H160 {
 		let digest = <frame_system::Pallet<T>>::digest();
@@ -1566,6 +2237,23 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Moves the storage deposits reserved by `from` to `to`, e.g. when the account of a
+	/// contract is claimed by another account.
+	pub fn transfer_storage_deposit(from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+		let amount = T::Currency::reserved_balance_named(&RESERVE_ID_STORAGE_DEPOSIT, from);
+		if !amount.is_zero() {
+			let val = T::Currency::repatriate_reserved_named(
+				&RESERVE_ID_STORAGE_DEPOSIT,
+				from,
+				to,
+				amount,
+				BalanceStatus::Reserved,
+			)?;
+			debug_assert!(val.is_zero());
+		}
+		Ok(())
+	}
+
 	pub fn update_contract_storage_size(address: &EvmAddress, change: i32) {
 		if change == 0 {
 			return;
@@ -1677,57 +2365,491 @@ impl<T: Config> Pallet<T> {
 				T::NetworkContractSource::get()
 			};
 
-			let old_code_info = Self::code_infos(contract_info.code_hash).ok_or(Error::<T>::ContractNotFound)?;
-
-			let bounded_code: BoundedVec<u8, MaxCodeSize> =
-				code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
-			let code_hash = code_hash(bounded_code.as_slice());
-			let code_size = bounded_code.len() as u32;
-			// The code_hash of the same contract is definitely different.
-			// The `contract_info.code_hash` hashed by on_contract_initialization which constructed.
-			// Still check it here.
-			if code_hash == contract_info.code_hash {
-				return Ok(());
+			Self::update_contract_code(&source, &contract, contract_info, code)
+		})
+	}
+
+	/// Replace the code of a contract and keep the contract storage.
+	///
+	/// - Charge the storage deposit for the code size change from or refund it to `source`.
+	/// - Update codes info.
+	/// - Save `code` if not saved yet.
+	fn update_contract_code(
+		source: &EvmAddress,
+		contract: &EvmAddress,
+		contract_info: &mut ContractInfo,
+		code: Vec<u8>,
+	) -> DispatchResult {
+		let old_code_info = Self::code_infos(contract_info.code_hash).ok_or(Error::<T>::ContractNotFound)?;
+
+		let bounded_code: BoundedVec<u8, MaxCodeSize> =
+			code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
+		let code_hash = code_hash(bounded_code.as_slice());
+		let code_size = bounded_code.len() as u32;
+		// The code_hash of the same contract is definitely different.
+		// The `contract_info.code_hash` hashed by on_contract_initialization which constructed.
+		// Still check it here.
+		if code_hash == contract_info.code_hash {
+			return Ok(());
+		}
+
+		let storage_size_changed: i32 =
+			code_size.saturating_add(T::NewContractExtraBytes::get()) as i32 - old_code_info.code_size as i32;
+
+		if storage_size_changed.is_positive() {
+			Self::reserve_storage(source, storage_size_changed as u32)?;
+		}
+		Self::charge_storage(source, contract, storage_size_changed)?;
+		Self::update_contract_storage_size(contract, storage_size_changed);
+
+		// try remove old codes
+		CodeInfos::<T>::mutate_exists(contract_info.code_hash, |maybe_code_info| -> DispatchResult {
+			let code_info = maybe_code_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
+			code_info.ref_count = code_info.ref_count.saturating_sub(1);
+			if code_info.ref_count == 0 {
+				Codes::<T>::remove(contract_info.code_hash);
+				*maybe_code_info = None;
 			}
+			Ok(())
+		})?;
 
-			let storage_size_changed: i32 =
-				code_size.saturating_add(T::NewContractExtraBytes::get()) as i32 - old_code_info.code_size as i32;
+		CodeInfos::<T>::mutate_exists(code_hash, |maybe_code_info| {
+			if let Some(code_info) = maybe_code_info.as_mut() {
+				code_info.ref_count = code_info.ref_count.saturating_add(1);
+			} else {
+				let new = CodeInfo {
+					code_size,
+					ref_count: 1,
+				};
+				*maybe_code_info = Some(new);
 
-			if storage_size_changed.is_positive() {
-				Self::reserve_storage(&source, storage_size_changed as u32)?;
+				Codes::<T>::insert(code_hash, bounded_code);
 			}
-			Self::charge_storage(&source, &contract, storage_size_changed)?;
-			Self::update_contract_storage_size(&contract, storage_size_changed);
+		});
+		// update code_hash
+		contract_info.code_hash = code_hash;
 
-			// try remove old codes
-			CodeInfos::<T>::mutate_exists(contract_info.code_hash, |maybe_code_info| -> DispatchResult {
-				let code_info = maybe_code_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
-				code_info.ref_count = code_info.ref_count.saturating_sub(1);
-				if code_info.ref_count == 0 {
-					Codes::<T>::remove(contract_info.code_hash);
-					*maybe_code_info = None;
-				}
-				Ok(())
-			})?;
+		Ok(())
+	}
 
-			CodeInfos::<T>::mutate_exists(code_hash, |maybe_code_info| {
-				if let Some(code_info) = maybe_code_info.as_mut() {
-					code_info.ref_count = code_info.ref_count.saturating_add(1);
-				} else {
-					let new = CodeInfo {
-						code_size,
-						ref_count: 1,
-					};
-					*maybe_code_info = Some(new);
+	/// Issue an EVM call and return error if it failed, returns the used gas.
+	///
+	/// - If `paymaster` is provided, ensures the call is within the quota the paymaster sponsors
+	///   for `source`, and charges the paymaster the call fee and the storage fee after the call.
+	///   `source` remains the origin of the call and reserves the storage deposit.
+	fn do_strict_call(
+		source: EvmAddress,
+		paymaster: Option<EvmAddress>,
+		call: EvmCall<BalanceOf<T>>,
+	) -> Result<u64, DispatchErrorWithPostInfo> {
+		if let Some(paymaster) = paymaster {
+			Self::ensure_sponsored(paymaster, source, call.gas_limit, call.storage_limit)?;
+		}
+
+		let target = call.target;
+		match T::Runner::call(
+			source,
+			source,
+			target,
+			call.input,
+			call.value,
+			call.gas_limit,
+			call.storage_limit,
+			call.access_list
+				.into_iter()
+				.map(|v| (v.address, v.storage_keys))
+				.collect(),
+			T::config(),
+		) {
+			Err(e) => Err(DispatchErrorWithPostInfo {
+				post_info: ().into(),
+				error: e,
+			}),
+			Ok(info) => {
+				let used_gas: u64 = info.used_gas.unique_saturated_into();
 
-					Codes::<T>::insert(code_hash, bounded_code);
+				if info.exit_reason.is_succeed() {
+					Pallet::<T>::deposit_event(Event::<T>::Executed {
+						from: source,
+						contract: target,
+						logs: info.logs,
+						used_gas,
+						used_storage: info.used_storage,
+					});
+
+					if let Some(paymaster) = paymaster {
+						Self::charge_sponsor(paymaster, source, used_gas, info.used_storage)?;
+					}
+
+					Ok(used_gas)
+				} else {
+					log::debug!(
+						target: "evm",
+						"strict_call failed: [from: {:?}, paymaster: {:?}, contract: {:?}, exit_reason: {:?}, output: {:?}, logs: {:?}, used_gas: {:?}]",
+						source, paymaster, target, info.exit_reason, info.value, info.logs, used_gas
+					);
+					Err(DispatchErrorWithPostInfo {
+						post_info: PostDispatchInfo {
+							actual_weight: Some(call_weight::<T>(used_gas)),
+							pays_fee: Pays::Yes,
+						},
+						error: Error::<T>::StrictCallFailed.into(),
+					})
 				}
-			});
-			// update code_hash
-			contract_info.code_hash = code_hash;
+			}
+		}
+	}
 
+	/// Ensures `paymaster` sponsors `user` for the call with the gas and storage limits.
+	fn ensure_sponsored(paymaster: EvmAddress, user: EvmAddress, gas_limit: u64, storage_limit: u32) -> DispatchResult {
+		let quota = Self::sponsorships(paymaster, user)
+			.ok_or(Error::<T>::NotSponsored)?
+			.quota;
+		ensure!(
+			gas_limit <= quota.gas && storage_limit <= quota.storage,
+			Error::<T>::SponsorQuotaExceeded
+		);
+		Ok(())
+	}
+
+	/// Deduct the used gas and storage from the sponsor quota, charge the paymaster the call fee
+	/// and transfer the deposit of the used storage from the paymaster to the user.
+	fn charge_sponsor(paymaster: EvmAddress, user: EvmAddress, used_gas: u64, used_storage: i32) -> DispatchResult {
+		let used_storage_size = used_storage.max(0) as u32;
+		Sponsorships::<T>::try_mutate(paymaster, user, |maybe_sponsorship| -> DispatchResult {
+			let quota = &mut maybe_sponsorship.as_mut().ok_or(Error::<T>::NotSponsored)?.quota;
+			quota.gas = quota
+				.gas
+				.checked_sub(used_gas)
+				.ok_or(Error::<T>::SponsorQuotaExceeded)?;
+			quota.storage = quota
+				.storage
+				.checked_sub(used_storage_size)
+				.ok_or(Error::<T>::SponsorQuotaExceeded)?;
 			Ok(())
-		})
+		})?;
+
+		let paymaster_account = T::AddressMapping::get_account_id(&paymaster);
+		T::ChargeTransactionPayment::charge_fee(
+			&paymaster_account,
+			0,
+			call_weight::<T>(used_gas),
+			Zero::zero(),
+			Pays::Yes,
+			DispatchClass::Normal,
+		)
+		.map_err(|_| Error::<T>::ChargeFeeFailed)?;
+
+		if used_storage_size > 0 {
+			T::Currency::transfer(
+				&paymaster_account,
+				&T::AddressMapping::get_account_id(&user),
+				Self::get_storage_deposit_per_byte().saturating_mul(used_storage_size.into()),
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		Pallet::<T>::deposit_event(Event::<T>::CallSponsored {
+			paymaster,
+			user,
+			used_gas,
+			used_storage,
+		});
+
+		Ok(())
+	}
+
+	/// Dry-run the call of `from` sponsored by `paymaster`, fails if `paymaster` does not sponsor
+	/// `from` for the gas and storage limits or can't pay for the call. The sponsor is charged, so
+	/// it must be called in a transaction which is rolled back, e.g. `simulate_execution`.
+	pub fn sponsored_rpc_call(
+		from: EvmAddress,
+		paymaster: EvmAddress,
+		to: EvmAddress,
+		data: Vec<u8>,
+		value: BalanceOf<T>,
+		gas_limit: u64,
+		storage_limit: u32,
+		access_list: Vec<(H160, Vec<H256>)>,
+	) -> Result<CallInfo, DispatchError> {
+		Self::ensure_sponsored(paymaster, from, gas_limit, storage_limit)?;
+
+		let info = T::Runner::rpc_call(
+			from,
+			from,
+			to,
+			data,
+			value,
+			gas_limit,
+			storage_limit,
+			access_list,
+			T::config(),
+		)?;
+		if info.exit_reason.is_succeed() {
+			Self::charge_sponsor(
+				paymaster,
+				from,
+				info.used_gas.unique_saturated_into(),
+				info.used_storage,
+			)?;
+		}
+		Ok(info)
+	}
+
+	/// The storage usage and storage deposit of the contract with `storage_size`.
+	fn contract_storage_usage(contract: EvmAddress, storage_size: u32) -> ContractStorageUsage {
+		let contract_acc = T::AddressMapping::get_account_id(&contract);
+		ContractStorageUsage {
+			contract,
+			storage_size,
+			required_deposit: Self::get_storage_deposit_per_byte().saturating_mul(storage_size.into()),
+			reserved_deposit: T::Currency::reserved_balance_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_acc),
+		}
+	}
+
+	/// The storage usages of up to `limit` contracts, starting after the contract `start` if
+	/// provided. The contracts are in storage order, use the last contract of a page as the
+	/// `start` of the next page.
+	pub fn contract_storage_usages(start: Option<EvmAddress>, limit: u32) -> Vec<ContractStorageUsage> {
+		let iterator = match start {
+			Some(contract) => ContractStorageSizes::<T>::iter_from(ContractStorageSizes::<T>::hashed_key_for(contract)),
+			None => ContractStorageSizes::<T>::iter(),
+		};
+
+		iterator
+			.take(limit as usize)
+			.map(|(contract, storage_size)| Self::contract_storage_usage(contract, storage_size))
+			.collect()
+	}
+
+	/// Check the storage deposits of the contracts in a batch bounded by `remaining_weight` and
+	/// `StorageDepositCheckLimit`, and report the contracts which reserved less than the
+	/// requirement of the current storage deposit per byte. A contract is reported once until its
+	/// storage deposit became sufficient again.
+	pub(crate) fn check_storage_deposits(remaining_weight: Weight) -> Weight {
+		let base_weight = T::DbWeight::get().reads_writes(1, 1);
+		// read the storage size, the reserved balance and the reported flag
+		let read_weight = T::DbWeight::get().reads(3);
+		// update the reported flag and deposit the event
+		let report_weight = T::DbWeight::get().writes(2);
+		let weight_per_contract = read_weight.saturating_add(report_weight);
+		if T::StorageDepositCheckLimit::get().is_zero()
+			|| remaining_weight.any_lt(base_weight.saturating_add(weight_per_contract))
+		{
+			return Weight::zero();
+		}
+
+		let max_contracts: u64 = remaining_weight
+			.saturating_sub(base_weight)
+			.checked_div_per_component(&weight_per_contract)
+			.unwrap_or(u64::MAX)
+			.min(T::StorageDepositCheckLimit::get().into());
+
+		let mut iterator = match StorageDepositCheckCursor::<T>::take() {
+			Some(key) => ContractStorageSizes::<T>::iter_from(key),
+			None => ContractStorageSizes::<T>::iter(),
+		};
+
+		let mut count: u64 = 0;
+		let mut reports: u64 = 0;
+		while count < max_contracts {
+			match iterator.next() {
+				Some((contract, storage_size)) => {
+					count += 1;
+					let usage = Self::contract_storage_usage(contract, storage_size);
+					let insufficient = usage.reserved_deposit < usage.required_deposit;
+					if insufficient == InsufficientStorageDeposits::<T>::contains_key(contract) {
+						continue;
+					}
+
+					reports += 1;
+					if insufficient {
+						InsufficientStorageDeposits::<T>::insert(contract, ());
+						Pallet::<T>::deposit_event(Event::<T>::ContractStorageDepositInsufficient {
+							contract,
+							required: usage.required_deposit,
+							reserved: usage.reserved_deposit,
+						});
+					} else {
+						InsufficientStorageDeposits::<T>::remove(contract);
+					}
+				}
+				None => break,
+			}
+		}
+		if count == max_contracts {
+			StorageDepositCheckCursor::<T>::put(iterator.last_raw_key());
+		}
+
+		base_weight
+			.saturating_add(read_weight.saturating_mul(count))
+			.saturating_add(report_weight.saturating_mul(reports))
+	}
+
+	/// Top up the storage deposit of the contract from `who`, returns the amount topped up.
+	fn do_top_up_storage_deposit(who: &T::AccountId, contract: EvmAddress) -> Result<BalanceOf<T>, DispatchError> {
+		Self::contract_maintainer(&contract)?;
+
+		let usage = Self::contract_storage_usage(contract, Self::contract_storage_sizes(contract));
+		let amount = usage.required_deposit.saturating_sub(usage.reserved_deposit);
+		ensure!(!amount.is_zero(), Error::<T>::StorageDepositSufficient);
+
+		let contract_acc = T::AddressMapping::get_account_id(&contract);
+		T::Currency::transfer(who, &contract_acc, amount, ExistenceRequirement::KeepAlive)?;
+		T::Currency::reserve_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_acc, amount)?;
+		InsufficientStorageDeposits::<T>::remove(contract);
+
+		Ok(amount)
+	}
+
+	/// Propose a code upgrade of a contract.
+	///
+	/// - Ensures signer is maintainer.
+	/// - Ensures the contract is not published or is published as upgradeable.
+	/// - Reserve the deposit for the code from the maintainer until the upgrade is applied or
+	///   cancelled.
+	/// - Save `code` if not saved yet and hold a reference until the upgrade is applied or
+	///   cancelled.
+	fn do_propose_contract_upgrade(
+		maintainer: &EvmAddress,
+		contract: EvmAddress,
+		code: Vec<u8>,
+	) -> Result<ContractUpgrade<BlockNumberFor<T>, BalanceOf<T>>, DispatchError> {
+		ensure!(
+			Self::contract_maintainer(&contract)? == *maintainer,
+			Error::<T>::NoPermission
+		);
+		Self::ensure_contract_upgradeable(&contract)?;
+
+		let bounded_code: BoundedVec<u8, MaxCodeSize> =
+			code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
+		let code_hash = code_hash(bounded_code.as_slice());
+
+		if let Some(pending) = ContractUpgrades::<T>::take(contract) {
+			Self::release_contract_upgrade(&pending);
+		}
+
+		let deposit = Self::get_storage_deposit_per_byte().saturating_mul((bounded_code.len() as u32).into());
+		T::ChargeTransactionPayment::reserve_fee(
+			&T::AddressMapping::get_account_id(maintainer),
+			deposit,
+			Some(RESERVE_ID_STORAGE_DEPOSIT),
+		)?;
+
+		CodeInfos::<T>::mutate_exists(code_hash, |maybe_code_info| {
+			if let Some(code_info) = maybe_code_info.as_mut() {
+				code_info.ref_count = code_info.ref_count.saturating_add(1);
+			} else {
+				let new = CodeInfo {
+					code_size: bounded_code.len() as u32,
+					ref_count: 1,
+				};
+				*maybe_code_info = Some(new);
+
+				Codes::<T>::insert(code_hash, bounded_code);
+			}
+		});
+
+		let upgrade = ContractUpgrade {
+			code_hash,
+			effective_block: frame_system::Pallet::<T>::block_number().saturating_add(T::ContractUpgradeDelay::get()),
+			proposer: *maintainer,
+			deposit,
+		};
+		ContractUpgrades::<T>::insert(contract, upgrade);
+
+		Ok(upgrade)
+	}
+
+	/// Cancel the pending code upgrade of a contract.
+	///
+	/// - Ensures signer is maintainer.
+	/// - Release the reference to the proposed code and the deposit.
+	fn do_cancel_contract_upgrade(maintainer: &EvmAddress, contract: EvmAddress) -> Result<H256, DispatchError> {
+		ensure!(
+			Self::contract_maintainer(&contract)? == *maintainer,
+			Error::<T>::NoPermission
+		);
+
+		let upgrade = ContractUpgrades::<T>::take(contract).ok_or(Error::<T>::ContractUpgradeNotFound)?;
+		Self::release_contract_upgrade(&upgrade);
+
+		Ok(upgrade.code_hash)
+	}
+
+	/// Apply the pending code upgrade of a contract.
+	///
+	/// - Ensures the timelock has passed and the contract is still upgradeable.
+	/// - Release the reference to the proposed code and the deposit.
+	/// - Set the code the same way as `set_code` does, the storage deposit for the code size
+	///   change is charged from or refunded to the maintainer.
+	fn do_apply_contract_upgrade(contract: EvmAddress) -> Result<H256, DispatchError> {
+		let upgrade = Self::contract_upgrades(contract).ok_or(Error::<T>::ContractUpgradeNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= upgrade.effective_block,
+			Error::<T>::ContractUpgradeNotReady
+		);
+		Self::ensure_contract_upgradeable(&contract)?;
+
+		let code = Codes::<T>::get(upgrade.code_hash).into_inner();
+		ContractUpgrades::<T>::remove(contract);
+		Self::release_contract_upgrade(&upgrade);
+
+		Accounts::<T>::try_mutate(contract, |maybe_account_info| -> DispatchResult {
+			let account_info = maybe_account_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
+			let contract_info = account_info
+				.contract_info
+				.as_mut()
+				.ok_or(Error::<T>::ContractNotFound)?;
+			let maintainer = contract_info.maintainer;
+
+			Self::update_contract_code(&maintainer, &contract, contract_info, code)
+		})?;
+
+		Ok(upgrade.code_hash)
+	}
+
+	fn contract_maintainer(contract: &EvmAddress) -> Result<EvmAddress, DispatchError> {
+		Self::accounts(contract)
+			.and_then(|account_info| account_info.contract_info)
+			.map(|contract_info| contract_info.maintainer)
+			.ok_or_else(|| Error::<T>::ContractNotFound.into())
+	}
+
+	/// Ensure the contract is not published, or published by `publish_upgradeable_contract`.
+	fn ensure_contract_upgradeable(contract: &EvmAddress) -> DispatchResult {
+		let published = Self::accounts(contract)
+			.and_then(|account_info| account_info.contract_info)
+			.map(|contract_info| contract_info.published)
+			.ok_or(Error::<T>::ContractNotFound)?;
+		ensure!(
+			!published || UpgradeableContracts::<T>::contains_key(contract),
+			Error::<T>::ContractAlreadyPublished
+		);
+		Ok(())
+	}
+
+	/// Release the reference to the proposed code of the upgrade, remove the code once it is not
+	/// referenced, and unreserve the deposit of the proposer.
+	fn release_contract_upgrade(upgrade: &ContractUpgrade<BlockNumberFor<T>, BalanceOf<T>>) {
+		CodeInfos::<T>::mutate_exists(upgrade.code_hash, |maybe_code_info| {
+			if let Some(code_info) = maybe_code_info.as_mut() {
+				code_info.ref_count = code_info.ref_count.saturating_sub(1);
+				if code_info.ref_count == 0 {
+					Codes::<T>::remove(upgrade.code_hash);
+					*maybe_code_info = None;
+				}
+			}
+		});
+
+		// should always be able to unreserve the amount
+		// but otherwise we will just ignore the issue here.
+		let err_amount = T::ChargeTransactionPayment::unreserve_fee(
+			&T::AddressMapping::get_account_id(&upgrade.proposer),
+			upgrade.deposit,
+			Some(RESERVE_ID_STORAGE_DEPOSIT),
+		);
+		debug_assert!(err_amount.is_zero());
 	}
 
 	/// Selfdestruct a contract at a given address.
@@ -2063,6 +3185,11 @@ impl<T: Config> EVMManager<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	fn disable_account_contract_development(who: T::AccountId) -> sp_runtime::DispatchResult {
 		Pallet::<T>::do_disable_contract_development(&who)
 	}
+
+	fn query_contract_upgrade(contract: EvmAddress) -> Option<(H256, u64)> {
+		Pallet::<T>::contract_upgrades(contract)
+			.map(|upgrade| (upgrade.code_hash, upgrade.effective_block.unique_saturated_into()))
+	}
 }
 
 pub struct CallKillAccount<T>(PhantomData<T>);
@@ -183,6 +183,7 @@ impl FindAuthor<AccountId32> for AuthorGiven {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice();
+	pub static Hardfork: EvmHardfork = EvmHardfork::TransientStorage;
 }
 
 ord_parameter_types! {
@@ -195,6 +196,7 @@ ord_parameter_types! {
 pub const NEW_CONTRACT_EXTRA_BYTES: u32 = 100;
 pub const DEVELOPER_DEPOSIT: u128 = 1000;
 pub const PUBLICATION_FEE: u128 = 200;
+pub const SPONSORSHIP_DEPOSIT: u128 = 100;
 impl Config for Runtime {
 	type AddressMapping = MockAddressMapping;
 	type Currency = Balances;
@@ -210,11 +212,15 @@ impl Config for Runtime {
 	type ChargeTransactionPayment = module_support::mocks::MockReservedTransactionPayment<Balances>;
 
 	type NetworkContractOrigin = EnsureSignedBy<NetworkContractAccount, AccountId32>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = ConstU128<DEVELOPER_DEPOSIT>;
 	type PublicationFee = ConstU128<PUBLICATION_FEE>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type ContractUpgradeDelay = ConstU64<10>;
+	type SponsorshipDeposit = ConstU128<SPONSORSHIP_DEPOSIT>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 
 	type Runner = crate::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
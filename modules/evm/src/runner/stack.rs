@@ -86,7 +86,7 @@ impl<T: Config> Runner<T> {
 
 		let metadata = StackSubstateMetadata::new(gas_limit, storage_limit, config);
 		let state = SubstrateStackState::new(&vicinity, metadata);
-		let mut executor = StackExecutor::new_with_precompiles(state, config, precompiles);
+		let mut executor = StackExecutor::new_with_precompiles(state, config, T::Hardfork::get(), precompiles);
 
 		ensure!(
 			convert_decimals_from_evm(
@@ -431,6 +431,8 @@ struct SubstrateStackSubstate<'config> {
 	storage_logs: Vec<(H160, i32)>,
 	parent: Option<Box<SubstrateStackSubstate<'config>>>,
 	known_original_storage: BTreeMap<(H160, H256), H256>,
+	// EIP-1153, discarded at the end of the transaction and never charged storage deposit.
+	transient_storage: BTreeMap<(H160, H256), H256>,
 }
 
 impl<'config> SubstrateStackSubstate<'config> {
@@ -450,6 +452,7 @@ impl<'config> SubstrateStackSubstate<'config> {
 			logs: Vec::new(),
 			storage_logs: Vec::new(),
 			known_original_storage: BTreeMap::new(),
+			transient_storage: BTreeMap::new(),
 		};
 		mem::swap(&mut entering, self);
 
@@ -471,6 +474,7 @@ impl<'config> SubstrateStackSubstate<'config> {
 		})?;
 		self.logs.append(&mut exited.logs);
 		self.deletes.append(&mut exited.deletes);
+		self.transient_storage.append(&mut exited.transient_storage);
 
 		exited.storage_logs.push((target, storage));
 		self.storage_logs.append(&mut exited.storage_logs);
@@ -545,6 +549,20 @@ impl<'config> SubstrateStackSubstate<'config> {
 		}
 		self.known_original_storage.insert((address, index), value);
 	}
+
+	pub fn transient_storage(&self, address: H160, index: H256) -> H256 {
+		if let Some(value) = self.transient_storage.get(&(address, index)) {
+			return *value;
+		}
+		self.parent
+			.as_ref()
+			.map(|parent| parent.transient_storage(address, index))
+			.unwrap_or_default()
+	}
+
+	pub fn set_transient_storage(&mut self, address: H160, index: H256, value: H256) {
+		self.transient_storage.insert((address, index), value);
+	}
 }
 
 #[cfg(feature = "evm-tests")]
@@ -590,6 +608,7 @@ impl<'vicinity, 'config, T: Config> SubstrateStackState<'vicinity, 'config, T> {
 				storage_logs: Vec::new(),
 				parent: None,
 				known_original_storage: BTreeMap::new(),
+				transient_storage: BTreeMap::new(),
 			},
 			_marker: PhantomData,
 		}
@@ -778,6 +797,14 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 		<AccountStorages<T>>::drain_prefix(address).for_each(drop);
 	}
 
+	fn transient_storage(&self, address: H160, index: H256) -> H256 {
+		self.substate.transient_storage(address, index)
+	}
+
+	fn set_transient_storage(&mut self, address: H160, index: H256, value: H256) {
+		self.substate.set_transient_storage(address, index, value)
+	}
+
 	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) {
 		self.substate.log(address, topics, data)
 	}
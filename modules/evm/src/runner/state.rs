@@ -18,15 +18,15 @@
 
 // Synchronize with https://github.com/rust-blockchain/evm/blob/6534c1dd/src/executor/stack/executor.rs
 
-use crate::{encode_revert_message, StorageMeter};
+use crate::{encode_revert_message, EvmHardfork, StorageMeter};
 use core::{cmp::min, convert::Infallible};
 use module_evm_utility::{
 	ethereum::Log,
 	evm::{
 		backend::Backend, Capture, Config, Context, CreateScheme, ExitError, ExitFatal, ExitReason, ExitRevert,
-		ExitSucceed, Opcode, Runtime, Stack, Transfer,
+		ExitSucceed, Machine, Opcode, Runtime, Stack, Transfer,
 	},
-	evm_gasometer::{self as gasometer, Gasometer, StorageTarget},
+	evm_gasometer::{self as gasometer, GasCost, Gasometer, MemoryCost, StorageTarget},
 	evm_runtime::Handler,
 };
 pub use primitives::{
@@ -46,39 +46,31 @@ use sp_std::{
 	vec::Vec,
 };
 
-macro_rules! event {
-	($x:expr) => {};
+/// Opcodes introduced after London, they are executed by `Handler::other`.
+pub mod opcode {
+	use super::Opcode;
+
+	pub const TLOAD: Opcode = Opcode(0x5c);
+	pub const TSTORE: Opcode = Opcode(0x5d);
+	pub const MCOPY: Opcode = Opcode(0x5e);
+	pub const PUSH0: Opcode = Opcode(0x5f);
 }
 
-#[cfg(feature = "tracing")]
-mod tracing {
-	pub struct Tracer;
-	impl module_evm_utility::evm::tracing::EventListener for Tracer {
-		fn event(&mut self, event: module_evm_utility::evm::tracing::Event) {
-			frame_support::log::debug!(
-				target: "evm", "evm tracing: {:?}", event
-			);
-		}
-	}
-	impl module_evm_utility::evm_runtime::tracing::EventListener for Tracer {
-		fn event(&mut self, event: module_evm_utility::evm_runtime::tracing::Event) {
-			frame_support::log::debug!(
-				target: "evm", "evm_runtime tracing: {:?}", event
-			);
-		}
-	}
-	impl module_evm_utility::evm_gasometer::tracing::EventListener for Tracer {
-		fn event(&mut self, event: module_evm_utility::evm_gasometer::tracing::Event) {
-			frame_support::log::debug!(
-				target: "evm", "evm_gasometer tracing: {:?}", event
-			);
+/// Gas cost of `PUSH0`.
+const G_BASE: u64 = 2;
+/// EIP-3860: gas cost per word of the initcode.
+const INITCODE_WORD_COST: u64 = 2;
+
+macro_rules! event {
+	($x:expr) => {
+		#[cfg(feature = "tracing")]
+		{
+			use crate::runner::tracing::{self, Event::*};
+			tracing::event($x);
 		}
-	}
+	};
 }
 
-#[cfg(feature = "tracing")]
-use tracing::*;
-
 macro_rules! emit_exit {
 	($reason:expr) => {{
 		let reason = $reason;
@@ -148,6 +140,8 @@ pub struct StackSubstateMetadata<'config> {
 	target: Option<H160>,
 	// save the call contract address, publish status will sync from it.
 	origin_code_address: Option<H160>,
+	// the address of the executing context, transient storage is keyed by it.
+	context_address: Option<H160>,
 	// this is needed only for evm-tests to keep track of dirty accounts
 	#[cfg(feature = "evm-tests")]
 	pub dirty_accounts: std::cell::RefCell<BTreeSet<H160>>,
@@ -169,6 +163,7 @@ impl<'config> StackSubstateMetadata<'config> {
 			caller: None,
 			target: None,
 			origin_code_address: None,
+			context_address: None,
 			#[cfg(feature = "evm-tests")]
 			dirty_accounts: std::cell::RefCell::new(BTreeSet::new()),
 		}
@@ -221,6 +216,7 @@ impl<'config> StackSubstateMetadata<'config> {
 			caller: None,
 			target: None,
 			origin_code_address: self.origin_code_address,
+			context_address: None,
 			#[cfg(feature = "evm-tests")]
 			dirty_accounts: std::cell::RefCell::new(BTreeSet::new()),
 		}
@@ -307,6 +303,14 @@ impl<'config> StackSubstateMetadata<'config> {
 	pub fn origin_code_address_mut(&mut self) -> &mut Option<H160> {
 		&mut self.origin_code_address
 	}
+
+	pub fn context_address(&self) -> &Option<H160> {
+		&self.context_address
+	}
+
+	pub fn context_address_mut(&mut self) -> &mut Option<H160> {
+		&mut self.context_address
+	}
 }
 
 pub trait CustomStackState {
@@ -331,6 +335,8 @@ pub trait StackState<'config>: Backend + CustomStackState {
 	fn inc_nonce(&mut self, address: H160);
 	fn set_storage(&mut self, address: H160, key: H256, value: H256);
 	fn reset_storage(&mut self, address: H160);
+	fn transient_storage(&self, address: H160, key: H256) -> H256;
+	fn set_transient_storage(&mut self, address: H160, key: H256, value: H256);
 	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>);
 	fn set_deleted(&mut self, address: H160);
 	fn set_code(&mut self, address: H160, code: Vec<u8>);
@@ -429,6 +435,7 @@ impl PrecompileSet for BTreeMap<H160, PrecompileFn> {
 /// Stack-based executor.
 pub struct StackExecutor<'config, 'precompiles, S, P> {
 	config: &'config Config,
+	hardfork: EvmHardfork,
 	state: S,
 	precompile_set: &'precompiles P,
 }
@@ -444,10 +451,28 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 		self.precompile_set
 	}
 
+	/// Return the enabled hardfork rule sets.
+	pub fn hardfork(&self) -> EvmHardfork {
+		self.hardfork
+	}
+
+	fn context_address(&self) -> Result<H160, ExitError> {
+		self.state
+			.metadata()
+			.context_address
+			.ok_or(ExitError::Other("executing context is unknown".into()))
+	}
+
 	/// Create a new stack-based executor with given precompiles.
-	pub fn new_with_precompiles(state: S, config: &'config Config, precompile_set: &'precompiles P) -> Self {
+	pub fn new_with_precompiles(
+		state: S,
+		config: &'config Config,
+		hardfork: EvmHardfork,
+		precompile_set: &'precompiles P,
+	) -> Self {
 		Self {
 			config,
+			hardfork,
 			state,
 			precompile_set,
 		}
@@ -472,6 +497,18 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 
 	/// Exit a substate. Panic if it results an empty substate stack.
 	pub fn exit_substate(&mut self, kind: StackExitKind) -> Result<(), ExitError> {
+		event!(ExitSubstate {
+			gas_limit: self
+				.state
+				.metadata()
+				.gasometer
+				.gas()
+				.saturating_add(self.state.metadata().gasometer.total_used_gas()),
+			used_gas: self.state.metadata().gasometer.total_used_gas(),
+			used_storage: (self.state.metadata().storage_meter.total_used() as i32)
+				.saturating_sub(self.state.metadata().storage_meter.total_refunded() as i32),
+		});
+
 		match kind {
 			StackExitKind::Succeeded => self.state.exit_commit(),
 			StackExitKind::Reverted => self.state.exit_revert(),
@@ -481,6 +518,8 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 
 	/// Execute the runtime until it returns.
 	pub fn execute(&mut self, runtime: &mut Runtime) -> ExitReason {
+		*self.state.metadata_mut().context_address_mut() = Some(runtime.context().address);
+
 		match runtime.run(self) {
 			Capture::Exit(s) => s,
 			Capture::Trap(_) => unreachable!("Trap is Infallible"),
@@ -499,7 +538,65 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 	) -> Result<(), ExitError> {
 		let transaction_cost = gasometer::create_transaction_cost(init_code, access_list);
 		let gasometer = &mut self.state.metadata_mut().gasometer;
-		gasometer.record_transaction(transaction_cost)
+		gasometer.record_transaction(transaction_cost)?;
+
+		self.record_initcode_cost(U256::from(init_code.len()))
+	}
+
+	/// EIP-3860: check the initcode size limit and record the initcode cost.
+	fn record_initcode_cost(&mut self, len: U256) -> Result<(), ExitError> {
+		if !self.hardfork.has_initcode_limit() {
+			return Ok(());
+		}
+
+		if let Some(limit) = self.config.create_contract_limit {
+			if len > U256::from(limit.saturating_mul(2)) {
+				return Err(ExitError::CreateContractLimit);
+			}
+		}
+
+		// the length is bounded by the limit or the memory cost
+		let words = len.low_u64().saturating_add(31) / 32;
+		self.state
+			.metadata_mut()
+			.gasometer
+			.record_cost(words.saturating_mul(INITCODE_WORD_COST))
+	}
+
+	/// Record the gas cost of the opcodes introduced after London.
+	/// Returns false if it's not one of them or not enabled.
+	fn record_hardfork_opcode_cost(&mut self, opcode: Opcode, stack: &Stack) -> Result<bool, ExitError> {
+		let hardfork = self.hardfork;
+		let is_static = self.state.metadata().is_static;
+		let gas_storage_read_warm = self.config.gas_storage_read_warm;
+		let gasometer = &mut self.state.metadata_mut().gasometer;
+
+		match opcode {
+			opcode::PUSH0 if hardfork.has_push0() => gasometer.record_cost(G_BASE)?,
+			opcode::TLOAD if hardfork.has_transient_storage() => gasometer.record_cost(gas_storage_read_warm)?,
+			opcode::TSTORE if hardfork.has_transient_storage() => {
+				// EIP-1153: TSTORE is a state modifying opcode
+				if is_static {
+					return Err(ExitError::Other("TSTORE in static call".into()));
+				}
+				gasometer.record_cost(gas_storage_read_warm)?
+			}
+			opcode::MCOPY if hardfork.has_mcopy() => {
+				let dst = U256::from_big_endian(&stack.peek(0)?[..]);
+				let src = U256::from_big_endian(&stack.peek(1)?[..]);
+				let len = U256::from_big_endian(&stack.peek(2)?[..]);
+				gasometer.record_dynamic_cost(
+					GasCost::VeryLowCopy { len },
+					Some(MemoryCost {
+						offset: dst.max(src),
+						len,
+					}),
+				)?
+			}
+			_ => return Ok(false),
+		}
+
+		Ok(true)
 	}
 
 	/// Execute a `CREATE` transaction.
@@ -598,7 +695,7 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			value,
 			init_code: &init_code,
 			gas_limit,
-			address,
+			address: Ok(address),
 		});
 
 		if let Err(e) = self.record_create_transaction_cost(&init_code, &access_list) {
@@ -744,6 +841,12 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 	}
 
 	pub fn initialize_with_access_list(&mut self, access_list: Vec<(H160, Vec<H256>)>) {
+		// EIP-3651: the coinbase is warm at the start of the transaction
+		if self.hardfork.has_warm_coinbase() {
+			let coinbase = self.state.block_coinbase();
+			self.state.metadata_mut().access_address(coinbase);
+		}
+
 		let addresses = access_list.iter().map(|a| a.0);
 		self.state.metadata_mut().access_addresses(addresses);
 
@@ -782,7 +885,18 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			gas - gas / 64
 		}
 
-		let address = match self.create_address(scheme) {
+		let address = self.create_address(scheme);
+
+		event!(Create {
+			caller,
+			address: address.as_ref().ok().copied(),
+			scheme,
+			value,
+			init_code: &init_code,
+			target_gas
+		});
+
+		let address = match address {
 			Err(e) => {
 				return Capture::Exit((ExitReason::Error(e), None, Vec::new()));
 			}
@@ -795,15 +909,6 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 		self.state.metadata_mut().access_address(caller);
 		self.state.metadata_mut().access_address(address);
 
-		event!(Create {
-			caller,
-			address,
-			scheme,
-			value,
-			init_code: &init_code,
-			target_gas
-		});
-
 		if let Some(depth) = self.state.metadata().depth {
 			if depth >= self.config.call_stack_limit {
 				return Capture::Exit((ExitError::CallTooDeep.into(), None, Vec::new()));
@@ -1063,13 +1168,7 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 
 		let mut runtime = Runtime::new(Rc::new(code), Rc::new(input), context, self.config);
 
-		#[cfg(not(feature = "tracing"))]
 		let reason = self.execute(&mut runtime);
-		#[cfg(feature = "tracing")]
-		//let reason = module_evm_utility::evm::tracing::using(&mut Tracer, || self.execute(&mut runtime));
-		let reason = module_evm_utility::evm_runtime::tracing::using(&mut Tracer, || self.execute(&mut runtime));
-		//let reason = module_evm_utility::evm_gasometer::tracing::using(&mut Tracer, || self.execute(&mut
-		// runtime));
 
 		log::debug!(target: "evm", "Call execution using address {}: {:?}", code_address, reason);
 
@@ -1308,6 +1407,15 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> Handler
 	fn pre_validate(&mut self, context: &Context, opcode: Opcode, stack: &Stack) -> Result<(), ExitError> {
 		// log::trace!(target: "evm", "Running opcode: {:?}, Pre gas-left: {:?}", opcode, gasometer.gas());
 
+		if self.record_hardfork_opcode_cost(opcode, stack)? {
+			return Ok(());
+		}
+
+		if opcode == Opcode::CREATE || opcode == Opcode::CREATE2 {
+			let len = U256::from_big_endian(&stack.peek(2)?[..]);
+			self.record_initcode_cost(len)?;
+		}
+
 		if let Some(cost) = gasometer::static_opcode_cost(opcode) {
 			self.state.metadata_mut().gasometer.record_cost(cost)?;
 		} else {
@@ -1327,4 +1435,49 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> Handler
 
 		Ok(())
 	}
+
+	fn other(&mut self, opcode: Opcode, machine: &mut Machine) -> Result<(), ExitError> {
+		// the gas cost is recorded in `pre_validate`
+		match opcode {
+			opcode::PUSH0 if self.hardfork.has_push0() => machine.stack_mut().push(H256::zero()),
+			opcode::TLOAD if self.hardfork.has_transient_storage() => {
+				let address = self.context_address()?;
+				let key = machine.stack_mut().pop()?;
+				let value = self.state.transient_storage(address, key);
+				machine.stack_mut().push(value)
+			}
+			opcode::TSTORE if self.hardfork.has_transient_storage() => {
+				let address = self.context_address()?;
+				let key = machine.stack_mut().pop()?;
+				let value = machine.stack_mut().pop()?;
+				self.state.set_transient_storage(address, key, value);
+				Ok(())
+			}
+			opcode::MCOPY if self.hardfork.has_mcopy() => {
+				let dst = U256::from_big_endian(&machine.stack_mut().pop()?[..]);
+				let src = U256::from_big_endian(&machine.stack_mut().pop()?[..]);
+				let len = U256::from_big_endian(&machine.stack_mut().pop()?[..]);
+				if len.is_zero() {
+					return Ok(());
+				}
+
+				// the memory expansion is paid in `pre_validate`
+				machine.memory_mut().resize_offset(dst.max(src), len)?;
+				let (dst, src, len) = (as_usize(dst)?, as_usize(src)?, as_usize(len)?);
+				let data = machine.memory().get(src, len);
+				machine
+					.memory_mut()
+					.set(dst, &data, Some(len))
+					.map_err(|_| ExitError::OutOfGas)
+			}
+			_ => Err(ExitError::InvalidCode(opcode)),
+		}
+	}
+}
+
+fn as_usize(value: U256) -> Result<usize, ExitError> {
+	if value > U256::from(usize::MAX) {
+		return Err(ExitError::OutOfGas);
+	}
+	Ok(value.as_usize())
 }
@@ -0,0 +1,346 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! EVM execution tracer.
+//!
+//! The stack executor emits the call frame events, and the opcode steps and the gas costs are
+//! collected from the listeners of `evm_runtime` and `evm_gasometer`.
+
+use module_evm_utility::{
+	evm::{Context, CreateScheme, ExitError, ExitReason, Transfer},
+	evm_gasometer, evm_runtime,
+};
+pub use primitives::evm::{CallTrace, CallType, OpcodeConfig, Step, TraceOutcome, TracerConfig};
+use sp_core::{H160, H256, U256};
+use sp_std::prelude::*;
+
+environmental::environmental!(tracer: Tracer);
+
+#[derive(Debug)]
+pub enum Event<'a> {
+	TransactCall {
+		caller: H160,
+		address: H160,
+		value: U256,
+		data: &'a [u8],
+		gas_limit: u64,
+	},
+	TransactCreate {
+		caller: H160,
+		value: U256,
+		init_code: &'a [u8],
+		gas_limit: u64,
+		address: Result<H160, ExitError>,
+	},
+	TransactCreate2 {
+		caller: H160,
+		value: U256,
+		init_code: &'a [u8],
+		salt: H256,
+		gas_limit: u64,
+		address: Result<H160, ExitError>,
+	},
+	Call {
+		code_address: H160,
+		transfer: &'a Option<Transfer>,
+		input: &'a [u8],
+		target_gas: Option<u64>,
+		is_static: bool,
+		context: &'a Context,
+	},
+	Create {
+		caller: H160,
+		address: Option<H160>,
+		scheme: CreateScheme,
+		value: U256,
+		init_code: &'a [u8],
+		target_gas: Option<u64>,
+	},
+	Suicide {
+		address: H160,
+		target: H160,
+		balance: U256,
+	},
+	/// The substate of the current call frame is about to exit.
+	ExitSubstate {
+		gas_limit: u64,
+		used_gas: u64,
+		used_storage: i32,
+	},
+	Exit {
+		reason: &'a ExitReason,
+		return_value: &'a [u8],
+	},
+}
+
+/// Send the event to the tracer if the execution is being traced.
+pub fn event(event: Event) {
+	tracer::with(|tracer| tracer.event(event));
+}
+
+struct EvmRuntimeListener;
+
+impl evm_runtime::tracing::EventListener for EvmRuntimeListener {
+	fn event(&mut self, event: evm_runtime::tracing::Event) {
+		tracer::with(|tracer| tracer.evm_runtime_event(event));
+	}
+}
+
+struct EvmGasometerListener;
+
+impl evm_gasometer::tracing::EventListener for EvmGasometerListener {
+	fn event(&mut self, event: evm_gasometer::tracing::Event) {
+		tracer::with(|tracer| tracer.evm_gasometer_event(event));
+	}
+}
+
+pub struct Tracer {
+	config: TracerConfig,
+	/// The finished top level call frames.
+	calls: Vec<CallTrace>,
+	/// The call frames being executed.
+	stack: Vec<CallTrace>,
+	steps: Vec<Step>,
+	step_counter: u64,
+	// the gas cost of the last step is recorded by the next gasometer event
+	pending_step_cost: bool,
+}
+
+impl Tracer {
+	pub fn new(config: TracerConfig) -> Self {
+		Self {
+			config,
+			calls: Vec::new(),
+			stack: Vec::new(),
+			steps: Vec::new(),
+			step_counter: 0,
+			pending_step_cost: false,
+		}
+	}
+
+	/// Run `f` and trace the EVM executions in it.
+	pub fn trace<R, F: FnOnce() -> R>(&mut self, f: F) -> R {
+		let trace_steps = matches!(self.config, TracerConfig::OpcodeTracer(_));
+		tracer::using(self, || {
+			if trace_steps {
+				evm_gasometer::tracing::using(&mut EvmGasometerListener, || {
+					evm_runtime::tracing::using(&mut EvmRuntimeListener, f)
+				})
+			} else {
+				f()
+			}
+		})
+	}
+
+	pub fn finalize(self) -> TraceOutcome {
+		match self.config {
+			TracerConfig::CallTracer => TraceOutcome::Calls(self.calls),
+			TracerConfig::OpcodeTracer(_) => TraceOutcome::Steps(self.steps),
+		}
+	}
+
+	fn enter(&mut self, call_type: CallType, from: H160, to: H160, input: Vec<u8>, value: U256, gas: u64) {
+		let depth = self.stack.len() as u32;
+		self.stack.push(CallTrace {
+			call_type,
+			from,
+			to,
+			input,
+			value,
+			gas,
+			gas_used: 0,
+			used_storage: 0,
+			output: Vec::new(),
+			exit_reason: None,
+			depth,
+			calls: Vec::new(),
+		});
+	}
+
+	fn finish(&mut self, trace: CallTrace) {
+		match self.stack.last_mut() {
+			Some(parent) => parent.calls.push(trace),
+			None => self.calls.push(trace),
+		}
+	}
+
+	fn event(&mut self, event: Event) {
+		match event {
+			Event::Call {
+				code_address,
+				transfer,
+				input,
+				target_gas,
+				is_static,
+				context,
+			} => {
+				let (call_type, from) = if is_static {
+					(CallType::STATICCALL, context.caller)
+				} else if transfer.is_none() {
+					(CallType::DELEGATECALL, context.address)
+				} else if context.address != code_address {
+					(CallType::CALLCODE, context.address)
+				} else {
+					(CallType::CALL, context.caller)
+				};
+				self.enter(
+					call_type,
+					from,
+					code_address,
+					input.to_vec(),
+					context.apparent_value,
+					target_gas.unwrap_or_default(),
+				);
+			}
+			Event::Create {
+				caller,
+				address,
+				scheme,
+				value,
+				init_code,
+				target_gas,
+			} => {
+				let call_type = match scheme {
+					CreateScheme::Create2 { .. } => CallType::CREATE2,
+					_ => CallType::CREATE,
+				};
+				self.enter(
+					call_type,
+					caller,
+					address.unwrap_or_default(),
+					init_code.to_vec(),
+					value,
+					target_gas.unwrap_or_default(),
+				);
+			}
+			Event::Suicide {
+				address,
+				target,
+				balance,
+			} => {
+				let depth = self.stack.len() as u32;
+				self.finish(CallTrace {
+					call_type: CallType::SUICIDE,
+					from: address,
+					to: target,
+					input: Vec::new(),
+					value: balance,
+					gas: 0,
+					gas_used: 0,
+					used_storage: 0,
+					output: Vec::new(),
+					exit_reason: None,
+					depth,
+					calls: Vec::new(),
+				});
+			}
+			Event::ExitSubstate {
+				gas_limit,
+				used_gas,
+				used_storage,
+			} => {
+				if let Some(trace) = self.stack.last_mut() {
+					trace.gas = gas_limit;
+					trace.gas_used = used_gas;
+					trace.used_storage = used_storage;
+				}
+			}
+			Event::Exit { reason, return_value } => {
+				// the transaction failed before entering the call frame
+				if let Some(mut trace) = self.stack.pop() {
+					trace.exit_reason = Some(reason.clone());
+					trace.output = return_value.to_vec();
+					self.finish(trace);
+				}
+			}
+			Event::TransactCall { .. } | Event::TransactCreate { .. } | Event::TransactCreate2 { .. } => {}
+		}
+	}
+
+	fn evm_runtime_event(&mut self, event: evm_runtime::tracing::Event) {
+		let TracerConfig::OpcodeTracer(config) = self.config else {
+			return;
+		};
+
+		if let evm_runtime::tracing::Event::Step {
+			opcode,
+			position,
+			stack,
+			memory,
+			..
+		} = event
+		{
+			let index = self.step_counter;
+			self.step_counter = self.step_counter.saturating_add(1);
+			self.pending_step_cost = false;
+
+			if config.page_size != 0 && index / u64::from(config.page_size) != u64::from(config.page) {
+				return;
+			}
+
+			self.steps.push(Step {
+				op: opcode.as_u8(),
+				pc: position.as_ref().map(|pc| *pc as u64).unwrap_or_default(),
+				depth: self.stack.len().saturating_sub(1) as u32,
+				gas: 0,
+				gas_cost: 0,
+				stack: if config.disable_stack {
+					Vec::new()
+				} else {
+					stack.data().clone()
+				},
+				memory: if config.enable_memory {
+					Some(memory.data().clone())
+				} else {
+					None
+				},
+			});
+			self.pending_step_cost = true;
+		}
+	}
+
+	fn evm_gasometer_event(&mut self, event: evm_gasometer::tracing::Event) {
+		if !self.pending_step_cost {
+			return;
+		}
+
+		// the snapshot is taken before the cost is recorded
+		let (cost, snapshot) = match event {
+			evm_gasometer::tracing::Event::RecordCost { cost, snapshot } => (cost, snapshot),
+			evm_gasometer::tracing::Event::RecordDynamicCost {
+				gas_cost,
+				memory_gas,
+				snapshot,
+				..
+			} => {
+				let memory_cost = memory_gas.saturating_sub(snapshot.map(|s| s.memory_gas).unwrap_or_default());
+				(gas_cost.saturating_add(memory_cost), snapshot)
+			}
+			_ => return,
+		};
+		self.pending_step_cost = false;
+
+		if let (Some(step), Some(snapshot)) = (self.steps.last_mut(), snapshot) {
+			step.gas = snapshot
+				.gas_limit
+				.saturating_sub(snapshot.used_gas)
+				.saturating_sub(snapshot.memory_gas);
+			step.gas_cost = cost;
+		}
+	}
+}
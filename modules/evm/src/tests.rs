@@ -27,6 +27,7 @@ use crate::runner::{
 };
 use frame_support::{assert_noop, assert_ok, dispatch::DispatchErrorWithPostInfo};
 use module_support::{mocks::MockAddressMapping, AddressMapping};
+use primitives::evm::{AccountOverride, RevertReason};
 use sp_core::{
 	bytes::{from_hex, to_hex},
 	H160,
@@ -108,7 +109,7 @@ fn should_calculate_contract_address() {
 		};
 		let metadata = StackSubstateMetadata::new(1000, 1000, &ACALA_CONFIG);
 		let state = SubstrateStackState::<Runtime>::new(&vicinity, metadata);
-		let mut executor = StackExecutor::new_with_precompiles(state, &ACALA_CONFIG, &());
+		let mut executor = StackExecutor::new_with_precompiles(state, &ACALA_CONFIG, EvmHardfork::TransientStorage, &());
 
 		assert_eq!(
 			executor.create_address(evm::CreateScheme::Legacy { caller: addr }),
@@ -1386,6 +1387,277 @@ fn should_set_code() {
 	});
 }
 
+#[test]
+fn should_upgrade_contract() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		assert_eq!(result.used_storage, 284);
+		assert_eq!(reserved_balance(contract_address), 2840);
+		assert_eq!(EVM::contract_storage_sizes(contract_address), 284);
+
+		let old_code_hash = H256::from_str("164981e02df203a0fb32a0af7c2cd1cc7f9df7bb49a4d2b0219307bb68a4b603").unwrap();
+		let storage_key = H256::from_low_u64_be(1);
+		let storage_value = H256::from_low_u64_be(2);
+		AccountStorages::<Runtime>::insert(contract_address, storage_key, storage_value);
+
+		let new_code = [0x60u8; 200].to_vec();
+		let new_code_hash = code_hash(&new_code);
+
+		// the contracts published without opting in can not be upgraded
+		// STOP
+		let plain_contract_address = deploy_runtime_code(&from_hex("0x00").unwrap());
+		assert!(EVM::accounts(plain_contract_address).unwrap().contract_info.unwrap().published);
+		assert_noop!(
+			EVM::propose_contract_upgrade(
+				RuntimeOrigin::signed(alice_account_id.clone()),
+				plain_contract_address,
+				new_code.clone()
+			),
+			Error::<Runtime>::ContractAlreadyPublished
+		);
+
+		assert_ok!(EVM::publish_upgradeable_contract(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			contract_address
+		));
+		assert_eq!(EVM::upgradeable_contracts(contract_address), Some(()));
+
+		assert_noop!(
+			EVM::propose_contract_upgrade(
+				RuntimeOrigin::signed(bob_account_id.clone()),
+				contract_address,
+				new_code.clone()
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EVM::apply_contract_upgrade(RuntimeOrigin::signed(bob_account_id.clone()), contract_address),
+			Error::<Runtime>::ContractUpgradeNotFound
+		);
+
+		// propose and cancel
+		let alice_reserved = reserved_balance(alice());
+		assert_ok!(EVM::propose_contract_upgrade(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			contract_address,
+			new_code.clone()
+		));
+		// the deposit of the proposed code
+		assert_eq!(reserved_balance(alice()), alice_reserved + 2000);
+		assert_eq!(
+			CodeInfos::<Runtime>::get(&new_code_hash),
+			Some(CodeInfo {
+				code_size: 200,
+				ref_count: 1,
+			})
+		);
+		assert_noop!(
+			EVM::cancel_contract_upgrade(RuntimeOrigin::signed(bob_account_id.clone()), contract_address),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EVM::cancel_contract_upgrade(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			contract_address
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::ContractUpgradeCancelled {
+			contract: contract_address,
+			code_hash: new_code_hash,
+		}));
+		assert_eq!(EVM::contract_upgrades(contract_address), None);
+		assert_eq!(CodeInfos::<Runtime>::get(&new_code_hash), None);
+		assert_eq!(Codes::<Runtime>::contains_key(&new_code_hash), false);
+		assert_eq!(reserved_balance(alice()), alice_reserved);
+
+		// propose and apply
+		assert_ok!(EVM::propose_contract_upgrade(
+			RuntimeOrigin::signed(alice_account_id),
+			contract_address,
+			new_code
+		));
+		let effective_block = System::block_number() + 10;
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::ContractUpgradeProposed {
+			contract: contract_address,
+			code_hash: new_code_hash,
+			effective_block,
+		}));
+		assert_eq!(
+			EVM::contract_upgrades(contract_address),
+			Some(ContractUpgrade {
+				code_hash: new_code_hash,
+				effective_block,
+				proposer: alice(),
+				deposit: 2000,
+			})
+		);
+		assert_eq!(reserved_balance(alice()), alice_reserved + 2000);
+
+		System::set_block_number(effective_block - 1);
+		assert_noop!(
+			EVM::apply_contract_upgrade(RuntimeOrigin::signed(bob_account_id.clone()), contract_address),
+			Error::<Runtime>::ContractUpgradeNotReady
+		);
+
+		System::set_block_number(effective_block);
+		assert_ok!(EVM::apply_contract_upgrade(
+			RuntimeOrigin::signed(bob_account_id),
+			contract_address
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::ContractUpgraded {
+			contract: contract_address,
+			code_hash: new_code_hash,
+		}));
+
+		assert_eq!(
+			Accounts::<Runtime>::get(&contract_address),
+			Some(AccountInfo {
+				nonce: 1,
+				contract_info: Some(ContractInfo {
+					code_hash: new_code_hash,
+					maintainer: alice(),
+					published: true
+				})
+			})
+		);
+		assert_eq!(EVM::contract_upgrades(contract_address), None);
+		assert_eq!(CodeInfos::<Runtime>::get(&old_code_hash), None);
+		assert_eq!(Codes::<Runtime>::contains_key(&old_code_hash), false);
+		assert_eq!(
+			CodeInfos::<Runtime>::get(&new_code_hash),
+			Some(CodeInfo {
+				code_size: 200,
+				ref_count: 1,
+			})
+		);
+
+		// storage is kept and the code size change is charged as `set_code`
+		assert_eq!(EVM::account_storages(contract_address, storage_key), storage_value);
+		assert_eq!(EVM::contract_storage_sizes(contract_address), 400);
+		assert_eq!(reserved_balance(contract_address), 4000);
+		// the deposit of the proposed code is released
+		assert_eq!(reserved_balance(alice()), alice_reserved);
+	});
+}
+
+#[test]
+fn storage_deposit_check_and_top_up_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		let contract_account_id = <Runtime as Config>::AddressMapping::get_account_id(&contract_address);
+		assert_eq!(reserved_balance(contract_address), 2840);
+
+		let is_insufficient_event = |record: &frame_system::EventRecord<RuntimeEvent, H256>| {
+			matches!(
+				record.event,
+				RuntimeEvent::EVM(crate::Event::ContractStorageDepositInsufficient { contract, .. })
+					if contract == contract_address
+			)
+		};
+
+		EVM::check_storage_deposits(Weight::MAX);
+		assert!(!System::events().iter().any(is_insufficient_event));
+		assert!(
+			EVM::contract_storage_usages(None, u32::MAX).contains(&ContractStorageUsage {
+				contract: contract_address,
+				storage_size: 284,
+				required_deposit: 2840,
+				reserved_deposit: 2840,
+			})
+		);
+
+		assert_noop!(
+			EVM::top_up_storage_deposit(RuntimeOrigin::signed(bob_account_id.clone()), contract_address),
+			Error::<Runtime>::StorageDepositSufficient
+		);
+		assert_noop!(
+			EVM::top_up_storage_deposit(RuntimeOrigin::signed(bob_account_id.clone()), bob()),
+			Error::<Runtime>::ContractNotFound
+		);
+
+		// the reserved deposit falls below the requirement
+		<Runtime as Config>::Currency::unreserve_named(&RESERVE_ID_STORAGE_DEPOSIT, &contract_account_id, 840);
+
+		let report_weight = EVM::check_storage_deposits(Weight::MAX);
+		System::assert_has_event(RuntimeEvent::EVM(crate::Event::ContractStorageDepositInsufficient {
+			contract: contract_address,
+			required: 2840,
+			reserved: 2000,
+		}));
+		assert!(InsufficientStorageDeposits::<Runtime>::contains_key(contract_address));
+
+		// the contract is reported only once
+		System::reset_events();
+		let check_weight = EVM::check_storage_deposits(Weight::MAX);
+		assert!(!System::events().iter().any(is_insufficient_event));
+		assert_eq!(
+			report_weight,
+			check_weight.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(2))
+		);
+
+		let bob_balance = balance(bob());
+		assert_ok!(EVM::top_up_storage_deposit(
+			RuntimeOrigin::signed(bob_account_id.clone()),
+			contract_address
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::ContractStorageDepositToppedUp {
+			contract: contract_address,
+			who: bob_account_id,
+			amount: 840,
+		}));
+		assert_eq!(balance(bob()), bob_balance - 840);
+		assert_eq!(reserved_balance(contract_address), 2840);
+		assert!(!InsufficientStorageDeposits::<Runtime>::contains_key(contract_address));
+	});
+}
+
 #[test]
 fn should_selfdestruct_without_schedule_task() {
 	// pragma solidity ^0.5.0;
@@ -2631,6 +2903,291 @@ fn strict_call_works() {
 	})
 }
 
+#[test]
+fn sponsored_call_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//     mapping(address => uint256) public values;
+	//
+	//     constructor() public {
+	//         values[msg.sender] = 42;
+	//     }
+	//
+	//     function set(uint val) public {
+	//      values[msg.sender] = val;
+	//     }
+	// }
+
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b50602a6000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610154806100646000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c806354fe9fd71461003b57806360fe47b114610093575b600080fd5b61007d6004803603602081101561005157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291905050506100c1565b6040518082815260200191505060405180910390f35b6100bf600480360360208110156100a957600080fd5b81019080803590602001909291905050506100d9565b005b60006020528060005260406000206000915090505481565b806000803373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055505056fea265627a7a723158207ab6991e97c9c12f57d81df0c7f955435418354adeb26116b581d7f2f035ca8f64736f6c63430005110032"
+	).unwrap();
+	// call method `set(123)`
+	let set = from_hex("0x60fe47b1000000000000000000000000000000000000000000000000000000000000007b").unwrap();
+
+	new_test_ext().execute_with(|| {
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			500000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		assert_ok!(EVM::publish_free(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			contract_address
+		));
+
+		assert_noop!(
+			EVM::sponsored_call(
+				RuntimeOrigin::signed(bob_account_id.clone()),
+				alice(),
+				contract_address,
+				set.clone(),
+				0,
+				100000,
+				1000,
+				vec![],
+			),
+			Error::<Runtime>::NotSponsored
+		);
+
+		let quota = SponsorQuota {
+			gas: 200000,
+			storage: 1000,
+		};
+		assert_noop!(
+			EVM::set_sponsorship(
+				RuntimeOrigin::signed(bob_account_id.clone()),
+				alice(),
+				bob(),
+				Some(quota)
+			),
+			Error::<Runtime>::NoPermission
+		);
+		let alice_reserved = reserved_balance(alice());
+		assert_ok!(EVM::set_sponsorship(
+			RuntimeOrigin::signed(alice_account_id.clone()),
+			alice(),
+			bob(),
+			Some(quota)
+		));
+		System::assert_last_event(RuntimeEvent::EVM(crate::Event::SponsorshipUpdated {
+			paymaster: alice(),
+			user: bob(),
+			quota: Some(quota),
+		}));
+		// the deposit for the sponsorship is reserved
+		assert_eq!(reserved_balance(alice()), alice_reserved + SPONSORSHIP_DEPOSIT);
+
+		assert_noop!(
+			EVM::sponsored_call(
+				RuntimeOrigin::signed(bob_account_id.clone()),
+				alice(),
+				contract_address,
+				set.clone(),
+				0,
+				300000,
+				1000,
+				vec![],
+			),
+			Error::<Runtime>::SponsorQuotaExceeded
+		);
+
+		let alice_balance = balance(alice());
+		let bob_balance = balance(bob());
+		let bob_reserved = reserved_balance(bob());
+
+		assert_eq!(
+			EVM::sponsored_call(
+				RuntimeOrigin::signed(bob_account_id),
+				alice(),
+				contract_address,
+				set,
+				0,
+				100000,
+				1000,
+				vec![],
+			)
+			.unwrap()
+			.pays_fee,
+			Pays::No
+		);
+
+		let used_gas = match System::events().last().map(|record| record.event.clone()) {
+			Some(RuntimeEvent::EVM(crate::Event::CallSponsored {
+				paymaster,
+				user,
+				used_gas,
+				used_storage,
+			})) => {
+				assert_eq!(paymaster, alice());
+				assert_eq!(user, bob());
+				assert_eq!(used_storage, STORAGE_SIZE as i32);
+				used_gas
+			}
+			_ => panic!("expected CallSponsored event"),
+		};
+
+		// the user reserves the storage deposit and the paymaster pays it to the user
+		let storage_deposit = STORAGE_SIZE as u128 * EVM::get_storage_deposit_per_byte();
+		assert_eq!(balance(alice()), alice_balance - storage_deposit);
+		assert_eq!(balance(bob()), bob_balance);
+		assert_eq!(reserved_balance(bob()), bob_reserved + storage_deposit);
+		assert_eq!(
+			EVM::sponsorships(alice(), bob()).map(|sponsorship| sponsorship.quota),
+			Some(SponsorQuota {
+				gas: 200000 - used_gas,
+				storage: 1000 - STORAGE_SIZE,
+			})
+		);
+
+		// the deposit is returned when the sponsorship is removed
+		assert_ok!(EVM::set_sponsorship(
+			RuntimeOrigin::signed(alice_account_id),
+			alice(),
+			bob(),
+			None
+		));
+		assert_eq!(EVM::sponsorships(alice(), bob()), None);
+		assert_eq!(reserved_balance(alice()), alice_reserved);
+	})
+}
+
+#[test]
+fn sponsored_rpc_call_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	// call method `multiply(2, 3)`
+	let multiply = from_hex(
+		"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		assert_noop!(
+			EVM::sponsored_rpc_call(bob(), alice(), contract_address, multiply.clone(), 0, 100000, 0, vec![]),
+			Error::<Runtime>::NotSponsored
+		);
+
+		assert_ok!(EVM::set_sponsorship(
+			RuntimeOrigin::signed(<Runtime as Config>::AddressMapping::get_account_id(&alice())),
+			alice(),
+			bob(),
+			Some(SponsorQuota {
+				gas: 100000,
+				storage: 0
+			})
+		));
+
+		assert_noop!(
+			EVM::sponsored_rpc_call(bob(), alice(), contract_address, multiply.clone(), 0, 200000, 0, vec![]),
+			Error::<Runtime>::SponsorQuotaExceeded
+		);
+
+		let info = EVM::sponsored_rpc_call(bob(), alice(), contract_address, multiply, 0, 100000, 0, vec![]).unwrap();
+		assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(info.value.as_slice()), U256::from(6));
+	})
+}
+
+#[test]
+fn batch_call_works() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_balance = balance(bob());
+
+		let transfer = EvmCall {
+			target: bob(),
+			input: vec![],
+			value: convert_decimals_to_evm(1000),
+			gas_limit: 100000,
+			storage_limit: 0,
+			access_list: vec![],
+		};
+		// call undefined method
+		let failed = EvmCall {
+			target: contract_address,
+			input: from_hex("0x00000000").unwrap(),
+			value: 0,
+			gas_limit: 100000,
+			storage_limit: 0,
+			access_list: vec![],
+		};
+
+		// all the calls are reverted if any of them failed
+		assert_eq!(
+			EVM::batch_call(
+				RuntimeOrigin::signed(alice_account_id.clone()),
+				vec![transfer.clone(), failed],
+				None
+			)
+			.map_err(|e| e.error),
+			Err(Error::<Runtime>::StrictCallFailed.into())
+		);
+		assert_eq!(balance(bob()), bob_balance);
+
+		assert_ok!(EVM::batch_call(
+			RuntimeOrigin::signed(alice_account_id),
+			vec![transfer.clone(), transfer],
+			None
+		));
+		assert_eq!(balance(bob()), bob_balance + 2000);
+	})
+}
+
 #[test]
 // ensure storage reserve/unreserved is done in a single operation
 fn aggregated_storage_logs_works() {
@@ -2967,3 +3524,454 @@ fn should_not_allow_system_contracts_send_tx() {
 		);
 	});
 }
+
+// deploy `code` as the contract code and return the contract address
+fn deploy_runtime_code(code: &[u8]) -> H160 {
+	// PUSH1 len, DUP1, PUSH1 0x0b, PUSH1 0, CODECOPY, PUSH1 0, RETURN
+	let mut init_code = vec![0x60, code.len() as u8];
+	init_code.extend_from_slice(&from_hex("0x80600b6000396000f3").unwrap());
+	init_code.extend_from_slice(code);
+
+	let result = <Runtime as Config>::Runner::create(
+		alice(),
+		init_code,
+		0,
+		1_000_000,
+		1_000,
+		vec![],
+		<Runtime as Config>::config(),
+	)
+	.unwrap();
+	assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+
+	#[cfg(not(feature = "with-ethereum-compatibility"))]
+	publish_free(result.value);
+
+	result.value
+}
+
+fn call_runtime_code(contract: H160) -> CallInfo {
+	<Runtime as Config>::Runner::call(
+		alice(),
+		alice(),
+		contract,
+		vec![],
+		0,
+		1_000_000,
+		1_000,
+		vec![],
+		<Runtime as Config>::config(),
+	)
+	.unwrap()
+}
+
+#[test]
+fn push0_works() {
+	new_test_ext().execute_with(|| {
+		// PUSH1 0x2a, PUSH0, MSTORE, PUSH1 0x20, PUSH0, RETURN
+		let contract = deploy_runtime_code(&from_hex("0x602a5f5260205ff3").unwrap());
+
+		let result = call_runtime_code(contract);
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(result.value.as_slice()), 0x2a.into());
+
+		// not supported before Shanghai
+		let vicinity = Vicinity {
+			gas_price: U256::one(),
+			..Default::default()
+		};
+		let metadata = StackSubstateMetadata::new(1_000_000, 1_000, &ACALA_CONFIG);
+		let state = SubstrateStackState::<Runtime>::new(&vicinity, metadata);
+		let mut executor = StackExecutor::new_with_precompiles(state, &ACALA_CONFIG, EvmHardfork::London, &());
+		let (reason, _) = executor.transact_call(alice(), contract, U256::zero(), vec![], 1_000_000, vec![]);
+		assert!(reason.is_error());
+	});
+}
+
+#[test]
+fn transient_storage_works() {
+	new_test_ext().execute_with(|| {
+		// TLOAD(1) into memory[0..32], TSTORE(1, 0x2a), TLOAD(1) into memory[32..64], RETURN memory[0..64]
+		let contract = deploy_runtime_code(&from_hex("0x60015c5f52602a60015d60015c60205260405ff3").unwrap());
+		let storage_size = ContractStorageSizes::<Runtime>::get(&contract);
+
+		let mut expected = vec![0u8; 64];
+		expected[63] = 0x2a;
+
+		let result = call_runtime_code(contract);
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(result.value, expected);
+		// no storage deposit
+		assert_eq!(result.used_storage, 0);
+		assert_eq!(ContractStorageSizes::<Runtime>::get(&contract), storage_size);
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract).count(), 0);
+
+		// cleared at the end of the transaction
+		let result = call_runtime_code(contract);
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(result.value, expected);
+	});
+}
+
+#[test]
+fn transient_storage_in_static_call_fails() {
+	use std::rc::Rc;
+
+	new_test_ext().execute_with(|| {
+		// TSTORE(1, 0x2a), STOP
+		let code = from_hex("0x602a60015d00").unwrap();
+		let contract = deploy_runtime_code(&code);
+
+		let vicinity = Vicinity {
+			gas_price: U256::one(),
+			..Default::default()
+		};
+		let metadata = StackSubstateMetadata::new(1_000_000, 1_000, &ACALA_CONFIG);
+		let state = SubstrateStackState::<Runtime>::new(&vicinity, metadata);
+		let mut executor = StackExecutor::new_with_precompiles(state, &ACALA_CONFIG, EvmHardfork::TransientStorage, &());
+		executor.enter_substate(1_000_000, true);
+
+		let context = Context {
+			caller: alice(),
+			address: contract,
+			apparent_value: Default::default(),
+		};
+		let mut runtime = evm::Runtime::new(Rc::new(code), Rc::new(Vec::new()), context, &ACALA_CONFIG);
+		assert_eq!(
+			executor.execute(&mut runtime),
+			ExitReason::Error(ExitError::Other("TSTORE in static call".into()))
+		);
+	});
+}
+
+#[test]
+fn hardfork_is_configurable() {
+	new_test_ext().execute_with(|| {
+		// PUSH1 0x2a, PUSH0, MSTORE, PUSH1 0x20, PUSH0, RETURN
+		let contract = deploy_runtime_code(&from_hex("0x602a5f5260205ff3").unwrap());
+
+		Hardfork::set(EvmHardfork::London);
+		assert!(call_runtime_code(contract).exit_reason.is_error());
+
+		Hardfork::set(EvmHardfork::Shanghai);
+		assert_eq!(
+			call_runtime_code(contract).exit_reason,
+			ExitReason::Succeed(ExitSucceed::Returned)
+		);
+	});
+}
+
+#[test]
+fn warm_coinbase_works() {
+	new_test_ext().execute_with(|| {
+		// COINBASE, BALANCE, STOP
+		let contract = deploy_runtime_code(&from_hex("0x413100").unwrap());
+
+		Hardfork::set(EvmHardfork::London);
+		let cold = call_runtime_code(contract);
+		assert_eq!(cold.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+
+		Hardfork::set(EvmHardfork::Shanghai);
+		let warm = call_runtime_code(contract);
+		assert_eq!(warm.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		// the cold account access cost minus the warm access cost
+		assert_eq!(cold.used_gas - warm.used_gas, U256::from(2_500));
+	});
+}
+
+#[test]
+fn mcopy_works() {
+	new_test_ext().execute_with(|| {
+		// PUSH1 0x2a, PUSH0, MSTORE, MCOPY(0x20, 0, 0x20), PUSH1 0x40, PUSH0, RETURN
+		let contract = deploy_runtime_code(&from_hex("0x602a5f5260205f60205e60405ff3").unwrap());
+
+		let mut expected = vec![0u8; 64];
+		expected[31] = 0x2a;
+		expected[63] = 0x2a;
+
+		let result = call_runtime_code(contract);
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(result.value, expected);
+	});
+}
+
+#[test]
+fn initcode_limit_works() {
+	new_test_ext().execute_with(|| {
+		let limit = ACALA_CONFIG.create_contract_limit.unwrap() * 2;
+
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			vec![0u8; limit + 1],
+			0,
+			2_000_000,
+			1_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Error(ExitError::CreateContractLimit));
+
+		// STOP is a valid empty init code
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			vec![0u8; limit],
+			0,
+			2_000_000,
+			1_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+	});
+}
+
+#[test]
+fn estimate_resources_works() {
+	new_test_ext().execute_with(|| {
+		// PUSH1 0x2a, PUSH0, SSTORE, STOP
+		let contract = deploy_runtime_code(&from_hex("0x602a5f5500").unwrap());
+		let call = |gas_limit, storage_limit| {
+			<Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				contract,
+				vec![],
+				0,
+				gas_limit,
+				storage_limit,
+				vec![],
+				<Runtime as Config>::config(),
+			)
+		};
+
+		let estimated = EVM::estimate_resources(alice(), Some(contract), vec![], 0, 1_000_000, 1_000, vec![]).unwrap();
+		assert_eq!(estimated.exit_reason, ExitReason::Succeed(ExitSucceed::Stopped));
+		assert_eq!(estimated.storage, STORAGE_SIZE);
+		assert_eq!(estimated.used_storage, STORAGE_SIZE as i32);
+		assert!(estimated.used_gas <= estimated.gas);
+		assert_eq!(estimated.weight, call_weight::<Runtime>(estimated.gas));
+		assert_eq!(estimated.revert_reason, None);
+		// the changes are discarded
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract).count(), 0);
+
+		// the estimated limits are the lowest
+		assert!(!call(estimated.gas - 1, estimated.storage)
+			.unwrap()
+			.exit_reason
+			.is_succeed());
+		assert_eq!(
+			call(estimated.gas, estimated.storage - 1),
+			Err(Error::<Runtime>::OutOfStorage.into())
+		);
+		assert_eq!(
+			call(estimated.gas, estimated.storage).unwrap().exit_reason,
+			ExitReason::Succeed(ExitSucceed::Stopped)
+		);
+
+		// Panic(0x11)
+		// PUSH4 0x4e487b71, PUSH1 0xe0, SHL, PUSH0, MSTORE, PUSH1 0x11, PUSH1 0x04, MSTORE, PUSH1 0x24, PUSH0, REVERT
+		let contract = deploy_runtime_code(&from_hex("0x634e487b7160e01b5f52601160045260245ffd").unwrap());
+		let estimated = EVM::estimate_resources(alice(), Some(contract), vec![], 0, 1_000_000, 1_000, vec![]).unwrap();
+		assert_eq!(estimated.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+		assert_eq!(estimated.gas, 1_000_000);
+		assert_eq!(estimated.revert_reason, Some(RevertReason::Panic(0x11.into())));
+	});
+}
+
+#[test]
+fn apply_state_override_works() {
+	new_test_ext().execute_with(|| {
+		let contract = H160::repeat_byte(0x11);
+		// PUSH0, SLOAD, PUSH0, MSTORE, PUSH1 0x20, PUSH0, RETURN
+		let code = from_hex("0x5f545f5260205ff3").unwrap();
+		let state_override = StateOverride::from([
+			(
+				contract,
+				AccountOverride {
+					code: Some(code.clone()),
+					state: Some(BTreeMap::from([(H256::zero(), H256::from_low_u64_be(0x2a))])),
+					..Default::default()
+				},
+			),
+			(
+				bob(),
+				AccountOverride {
+					balance: Some(1_000_000_000_000_000_000u128.into()),
+					nonce: Some(10),
+					..Default::default()
+				},
+			),
+		]);
+		let bob_account = EVM::account_basic(&bob());
+
+		let (result, overridden_bob_account) = frame_support::storage::with_transaction(|| {
+			let result = EVM::apply_state_override(state_override).and_then(|_| {
+				<Runtime as Config>::Runner::call(
+					alice(),
+					alice(),
+					contract,
+					vec![],
+					0,
+					1_000_000,
+					1_000,
+					vec![],
+					<Runtime as Config>::config(),
+				)
+				.map(|info| (info, EVM::account_basic(&bob())))
+			});
+			TransactionOutcome::Rollback(result)
+		})
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from(result.value.as_slice()), 0x2a.into());
+		assert_eq!(overridden_bob_account.balance, 1_000_000_000_000_000_000u128.into());
+		assert_eq!(overridden_bob_account.nonce, 10.into());
+
+		// never persisted
+		assert!(EVM::code_at_address(&contract).is_empty());
+		assert_eq!(AccountStorages::<Runtime>::get(contract, H256::zero()), H256::zero());
+		assert_eq!(EVM::account_basic(&bob()), bob_account);
+
+		// invalid decimals
+		let state_override = StateOverride::from([(
+			bob(),
+			AccountOverride {
+				balance: Some(1.into()),
+				..Default::default()
+			},
+		)]);
+		assert_noop!(
+			EVM::apply_state_override(state_override),
+			Error::<Runtime>::InvalidDecimals
+		);
+	});
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracer_works() {
+	use crate::runner::tracing::{CallTrace, CallType, OpcodeConfig, TraceOutcome, Tracer, TracerConfig};
+
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	// multiply(2, 3)
+	let multiply = from_hex(
+		"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let mut tracer = Tracer::new(TracerConfig::CallTracer);
+		let result = tracer
+			.trace(|| {
+				<Runtime as Config>::Runner::create(
+					alice(),
+					contract,
+					0,
+					1000000,
+					1000000,
+					vec![],
+					<Runtime as Config>::config(),
+				)
+			})
+			.unwrap();
+		let contract_address = result.value;
+
+		let TraceOutcome::Calls(calls) = tracer.finalize() else {
+			panic!("unexpected trace outcome");
+		};
+		assert_eq!(calls.len(), 1);
+		assert_eq!(calls[0].call_type, CallType::CREATE);
+		assert_eq!(calls[0].from, alice());
+		assert_eq!(calls[0].to, contract_address);
+		assert_eq!(calls[0].exit_reason, Some(ExitReason::Succeed(ExitSucceed::Returned)));
+		assert!(calls[0].gas_used > 0);
+		assert!(calls[0].used_storage > 0);
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		publish_free(contract_address);
+
+		let mut tracer = Tracer::new(TracerConfig::CallTracer);
+		let result = tracer
+			.trace(|| {
+				<Runtime as Config>::Runner::call(
+					alice(),
+					alice(),
+					contract_address,
+					multiply.clone(),
+					0,
+					1000000,
+					1000000,
+					vec![],
+					<Runtime as Config>::config(),
+				)
+			})
+			.unwrap();
+
+		let TraceOutcome::Calls(calls) = tracer.finalize() else {
+			panic!("unexpected trace outcome");
+		};
+		assert_eq!(
+			calls,
+			vec![CallTrace {
+				call_type: CallType::CALL,
+				from: alice(),
+				to: contract_address,
+				input: multiply.clone(),
+				value: U256::zero(),
+				gas: calls[0].gas,
+				gas_used: calls[0].gas_used,
+				used_storage: 0,
+				output: result.value,
+				exit_reason: Some(ExitReason::Succeed(ExitSucceed::Returned)),
+				depth: 0,
+				calls: vec![],
+			}]
+		);
+		assert!(calls[0].gas_used > 0 && calls[0].gas_used < calls[0].gas);
+
+		let mut tracer = Tracer::new(TracerConfig::OpcodeTracer(OpcodeConfig {
+			page: 0,
+			page_size: 3,
+			disable_stack: false,
+			enable_memory: true,
+		}));
+		tracer
+			.trace(|| {
+				<Runtime as Config>::Runner::call(
+					alice(),
+					alice(),
+					contract_address,
+					multiply,
+					0,
+					1000000,
+					1000000,
+					vec![],
+					<Runtime as Config>::config(),
+				)
+			})
+			.unwrap();
+
+		let TraceOutcome::Steps(steps) = tracer.finalize() else {
+			panic!("unexpected trace outcome");
+		};
+		// PUSH1 0x80, PUSH1 0x40, MSTORE
+		assert_eq!(steps.len(), 3);
+		assert_eq!(
+			steps.iter().map(|step| (step.op, step.pc, step.gas_cost)).collect::<Vec<_>>(),
+			vec![(0x60, 0, 3), (0x60, 2, 3), (0x52, 4, 12)]
+		);
+		assert_eq!(steps[1].gas, steps[0].gas - 3);
+		assert_eq!(steps[2].stack, vec![H256::from_low_u64_be(0x80), H256::from_low_u64_be(0x40)]);
+		assert_eq!(steps[2].memory, Some(vec![]));
+	});
+}
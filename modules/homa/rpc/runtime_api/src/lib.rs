@@ -0,0 +1,40 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use primitives::{homa::ExchangeRateRecord, EraIndex};
+use sp_runtime::FixedU128;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait HomaRuntimeRPCApi {
+		/// The exchange rate records of latest eras, ordered by era index ascending.
+		fn exchange_rate_history() -> Vec<(EraIndex, ExchangeRateRecord)>;
+
+		/// The annualized reward rate realised over the latest `eras` eras.
+		fn realised_apy(eras: EraIndex) -> Option<FixedU128>;
+
+		/// The current exchange rate (staking : liquid).
+		fn current_exchange_rate() -> FixedU128;
+
+		/// The estimated exchange rate after the next era is bumped.
+		fn projected_exchange_rate() -> FixedU128;
+	}
+}
@@ -23,9 +23,12 @@
 
 use frame_support::{pallet_prelude::*, transactional, PalletId};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{ExchangeRate, ExchangeRateProvider, FractionalRate, HomaManager, HomaSubAccountXcm, Rate, Ratio};
+use module_support::{
+	ExchangeRate, ExchangeRateProvider, FractionalRate, HomaManager, HomaSubAccountXcm, LiquidStakingPools, Rate,
+	Ratio, Swap, SwapLimit,
+};
 use orml_traits::MultiCurrency;
-use primitives::{Balance, CurrencyId, EraIndex};
+use primitives::{homa::ExchangeRateRecord, Balance, CurrencyId, EraIndex};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
@@ -98,8 +101,8 @@ pub mod module {
 	}
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Multi-currency support for asset management
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
@@ -144,18 +147,29 @@ pub mod module {
 		#[pallet::constant]
 		type RedeemThreshold: Get<Balance>;
 
+		/// The maximum number of exchange rate records to keep in history.
+		#[pallet::constant]
+		type MaxExchangeRateHistory: Get<u32>;
+
+		/// The number of relaychain eras per year, used to annualize the realised reward rate.
+		#[pallet::constant]
+		type ErasPerYear: Get<EraIndex>;
+
 		/// Block number provider for the relaychain.
 		type RelayChainBlockNumber: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
 		/// The XcmInterface to manage the staking of sub-account on relaychain.
 		type XcmInterface: HomaSubAccountXcm<Self::AccountId, Balance>;
 
+		/// Swap to redeem the liquid currency which cannot be fast matched by DEX.
+		type Swap: Swap<Self::AccountId, Balance, CurrencyId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		///	The mint amount is below the threshold.
 		BelowMintThreshold,
 		///	The redeem amount to request is below the threshold.
@@ -174,11 +188,13 @@ pub mod module {
 		InvalidRate,
 		/// Invalid last era bumped block config
 		InvalidLastEraBumpedBlock,
+		/// The redemption of instant redeem is below the minimum.
+		BelowMinimumRedemption,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// The minter use staking currency to mint liquid currency.
 		Minted {
 			minter: T::AccountId,
@@ -204,6 +220,12 @@ pub mod module {
 			fee_in_liquid: Balance,
 			redeemed_staking_amount: Balance,
 		},
+		/// Liquid currency is redeemed by swap on DEX.
+		RedeemedByDexSwap {
+			redeemer: T::AccountId,
+			liquid_amount: Balance,
+			redeemed_staking_amount: Balance,
+		},
 		/// Redeem request is redeemed by unbond on relaychain.
 		RedeemedByUnbond {
 			redeemer: T::AccountId,
@@ -249,7 +271,7 @@ pub mod module {
 	/// RelayChainCurrentEra : EraIndex
 	#[pallet::storage]
 	#[pallet::getter(fn relay_chain_current_era)]
-	pub type RelayChainCurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+	pub type RelayChainCurrentEra<T: Config<I>, I: 'static = ()> = StorageValue<_, EraIndex, ValueQuery>;
 
 	// /// The latest processed era of Homa, it should be always <= RelayChainCurrentEra
 	// ///
@@ -263,21 +285,22 @@ pub mod module {
 	/// StakingLedgers map: u16 => Option<StakingLedger>
 	#[pallet::storage]
 	#[pallet::getter(fn staking_ledgers)]
-	pub type StakingLedgers<T: Config> = StorageMap<_, Twox64Concat, u16, StakingLedger, OptionQuery>;
+	pub type StakingLedgers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u16, StakingLedger, OptionQuery>;
 
 	/// The total amount of staking currency bonded in the homa protocol
 	///
 	/// TotalStakingBonded value: Balance
 	#[pallet::storage]
 	#[pallet::getter(fn get_total_bonded)]
-	pub type TotalStakingBonded<T: Config> = StorageValue<_, Balance, ValueQuery>;
+	pub type TotalStakingBonded<T: Config<I>, I: 'static = ()> = StorageValue<_, Balance, ValueQuery>;
 
 	/// The total staking currency to bond on relaychain when new era,
 	/// and that is available to be match fast redeem request.
 	/// ToBondPool value: StakingCurrencyAmount
 	#[pallet::storage]
 	#[pallet::getter(fn to_bond_pool)]
-	pub type ToBondPool<T: Config> = StorageValue<_, Balance, ValueQuery>;
+	pub type ToBondPool<T: Config<I>, I: 'static = ()> = StorageValue<_, Balance, ValueQuery>;
 
 	/// The total amount of void liquid currency. It's will not be issued,
 	/// used to avoid newly issued LDOT to obtain the incoming staking income from relaychain.
@@ -288,35 +311,36 @@ pub mod module {
 	/// TotalVoidLiquid value: LiquidCurrencyAmount
 	#[pallet::storage]
 	#[pallet::getter(fn total_void_liquid)]
-	pub type TotalVoidLiquid<T: Config> = StorageValue<_, Balance, ValueQuery>;
+	pub type TotalVoidLiquid<T: Config<I>, I: 'static = ()> = StorageValue<_, Balance, ValueQuery>;
 
 	/// The total unclaimed redemption.
 	///
 	/// UnclaimedRedemption value: StakingCurrencyAmount
 	#[pallet::storage]
 	#[pallet::getter(fn unclaimed_redemption)]
-	pub type UnclaimedRedemption<T: Config> = StorageValue<_, Balance, ValueQuery>;
+	pub type UnclaimedRedemption<T: Config<I>, I: 'static = ()> = StorageValue<_, Balance, ValueQuery>;
 
 	/// Requests to redeem staked currencies.
 	///
 	/// RedeemRequests: Map: AccountId => Option<(liquid_amount: Balance, allow_fast_match: bool)>
 	#[pallet::storage]
 	#[pallet::getter(fn redeem_requests)]
-	pub type RedeemRequests<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (Balance, bool), OptionQuery>;
+	pub type RedeemRequests<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, (Balance, bool), OptionQuery>;
 
 	/// The records of unbonding by AccountId.
 	///
 	/// Unbondings: double_map AccountId, ExpireEraIndex => UnbondingStakingCurrencyAmount
 	#[pallet::storage]
 	#[pallet::getter(fn unbondings)]
-	pub type Unbondings<T: Config> =
+	pub type Unbondings<T: Config<I>, I: 'static = ()> =
 		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, EraIndex, Balance, ValueQuery>;
 
 	/// The estimated staking reward rate per era on relaychain.
 	///
 	/// EstimatedRewardRatePerEra: value: Rate
 	#[pallet::storage]
-	pub type EstimatedRewardRatePerEra<T: Config> = StorageValue<_, FractionalRate, ValueQuery>;
+	pub type EstimatedRewardRatePerEra<T: Config<I>, I: 'static = ()> = StorageValue<_, FractionalRate, ValueQuery>;
 
 	/// The maximum amount of bonded staking currency for a single sub on relaychain to obtain the
 	/// best staking rewards.
@@ -324,66 +348,75 @@ pub mod module {
 	/// SoftBondedCapPerSubAccount: value: Balance
 	#[pallet::storage]
 	#[pallet::getter(fn soft_bonded_cap_per_sub_account)]
-	pub type SoftBondedCapPerSubAccount<T: Config> = StorageValue<_, Balance, ValueQuery>;
+	pub type SoftBondedCapPerSubAccount<T: Config<I>, I: 'static = ()> = StorageValue<_, Balance, ValueQuery>;
 
 	/// The rate of Homa drawn from the staking reward as commission.
 	/// The draw will be transfer to TreasuryAccount of Homa in liquid currency.
 	///
 	/// CommissionRate: value: Rate
 	#[pallet::storage]
-	pub type CommissionRate<T: Config> = StorageValue<_, FractionalRate, ValueQuery>;
+	pub type CommissionRate<T: Config<I>, I: 'static = ()> = StorageValue<_, FractionalRate, ValueQuery>;
 
 	/// The fixed fee rate for redeem request is fast matched.
 	///
 	/// FastMatchFeeRate: value: Rate
 	#[pallet::storage]
-	pub type FastMatchFeeRate<T: Config> = StorageValue<_, FractionalRate, ValueQuery>;
+	pub type FastMatchFeeRate<T: Config<I>, I: 'static = ()> = StorageValue<_, FractionalRate, ValueQuery>;
 
 	/// The relaychain block number of last era bumped.
 	///
 	/// LastEraBumpedBlock: value: BlockNumberFor<T>
 	#[pallet::storage]
 	#[pallet::getter(fn last_era_bumped_block)]
-	pub type LastEraBumpedBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+	pub type LastEraBumpedBlock<T: Config<I>, I: 'static = ()> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
 	/// The internal of relaychain block number of relaychain to bump local current era.
 	///
 	/// LastEraBumpedRelayChainBlock: value: BlockNumberFor<T>
 	#[pallet::storage]
 	#[pallet::getter(fn bump_era_frequency)]
-	pub type BumpEraFrequency<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+	pub type BumpEraFrequency<T: Config<I>, I: 'static = ()> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The exchange rate records of latest eras, recorded after the rebalance of bumping era.
+	/// Ordered by era index ascending, keep `MaxExchangeRateHistory` records at most.
+	///
+	/// ExchangeRateHistory: value: BoundedVec<(EraIndex, ExchangeRateRecord), MaxExchangeRateHistory>
+	#[pallet::storage]
+	#[pallet::getter(fn exchange_rate_history)]
+	pub type ExchangeRateHistory<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(EraIndex, ExchangeRateRecord), T::MaxExchangeRateHistory>, ValueQuery>;
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
 			let bump_era_number = Self::era_amount_should_to_bump(T::RelayChainBlockNumber::current_block_number());
 			if !bump_era_number.is_zero() {
 				let _ = Self::bump_current_era(bump_era_number);
 				debug_assert_eq!(
-					TotalStakingBonded::<T>::get(),
-					StakingLedgers::<T>::iter().fold(Zero::zero(), |total_bonded: Balance, (_, ledger)| {
+					TotalStakingBonded::<T, I>::get(),
+					StakingLedgers::<T, I>::iter().fold(Zero::zero(), |total_bonded: Balance, (_, ledger)| {
 						total_bonded.saturating_add(ledger.bonded)
 					})
 				);
-				<T as Config>::WeightInfo::on_initialize_with_bump_era()
+				Self::bump_era_weight()
 			} else {
-				<T as Config>::WeightInfo::on_initialize()
+				<T as Config<I>>::WeightInfo::on_initialize()
 			}
 		}
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Mint liquid currency by put locking up amount of staking currency.
 		///
 		/// Parameters:
 		/// - `amount`: The amount of staking currency used to mint liquid currency.
 		#[pallet::call_index(0)]
-		#[pallet::weight(< T as Config >::WeightInfo::mint())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::mint())]
 		pub fn mint(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
 			let minter = ensure_signed(origin)?;
 			Self::do_mint(minter, amount)
@@ -404,7 +437,7 @@ pub mod module {
 		/// - `allow_fast_match`: allow the request to be fast matched, fast match will take a fixed
 		///   rate as fee.
 		#[pallet::call_index(1)]
-		#[pallet::weight(< T as Config >::WeightInfo::request_redeem())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::request_redeem())]
 		pub fn request_redeem(
 			origin: OriginFor<T>,
 			#[pallet::compact] amount: Balance,
@@ -419,7 +452,7 @@ pub mod module {
 		/// Parameters:
 		/// - `redeemer_list`: The list of redeem requests to execute fast redeem.
 		#[pallet::call_index(2)]
-		#[pallet::weight(< T as Config >::WeightInfo::fast_match_redeems(redeemer_list.len() as u32))]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::fast_match_redeems(redeemer_list.len() as u32))]
 		pub fn fast_match_redeems(origin: OriginFor<T>, redeemer_list: Vec<T::AccountId>) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
@@ -435,24 +468,24 @@ pub mod module {
 		/// Parameters:
 		/// - `redeemer`: redeemer.
 		#[pallet::call_index(3)]
-		#[pallet::weight(< T as Config >::WeightInfo::claim_redemption())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::claim_redemption())]
 		pub fn claim_redemption(origin: OriginFor<T>, redeemer: T::AccountId) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
 			let mut available_staking: Balance = Zero::zero();
 			let current_era = Self::relay_chain_current_era();
-			for (expired_era_index, unbonded) in Unbondings::<T>::iter_prefix(&redeemer) {
+			for (expired_era_index, unbonded) in Unbondings::<T, I>::iter_prefix(&redeemer) {
 				if expired_era_index <= current_era {
 					available_staking = available_staking.saturating_add(unbonded);
-					Unbondings::<T>::remove(&redeemer, expired_era_index);
+					Unbondings::<T, I>::remove(&redeemer, expired_era_index);
 				}
 			}
 
 			if !available_staking.is_zero() {
-				UnclaimedRedemption::<T>::try_mutate(|total| -> DispatchResult {
+				UnclaimedRedemption::<T, I>::try_mutate(|total| -> DispatchResult {
 					*total = total
 						.checked_sub(available_staking)
-						.ok_or(Error::<T>::InsufficientUnclaimedRedemption)?;
+						.ok_or(Error::<T, I>::InsufficientUnclaimedRedemption)?;
 					Ok(())
 				})?;
 				T::Currency::transfer(
@@ -462,7 +495,7 @@ pub mod module {
 					available_staking,
 				)?;
 
-				Self::deposit_event(Event::<T>::WithdrawRedemption {
+				Self::deposit_event(Event::<T, I>::WithdrawRedemption {
 					redeemer,
 					redemption_amount: available_staking,
 				});
@@ -483,7 +516,7 @@ pub mod module {
 		///   HomaTreasury
 		/// - `fast_match_fee_rate`: the fixed fee rate when redeem request is been fast matched.
 		#[pallet::call_index(4)]
-		#[pallet::weight(< T as Config >::WeightInfo::update_homa_params())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::update_homa_params())]
 		pub fn update_homa_params(
 			origin: OriginFor<T>,
 			soft_bonded_cap_per_sub_account: Option<Balance>,
@@ -494,28 +527,28 @@ pub mod module {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 
 			if let Some(cap_amount) = soft_bonded_cap_per_sub_account {
-				SoftBondedCapPerSubAccount::<T>::put(cap_amount);
-				Self::deposit_event(Event::<T>::SoftBondedCapPerSubAccountUpdated { cap_amount });
+				SoftBondedCapPerSubAccount::<T, I>::put(cap_amount);
+				Self::deposit_event(Event::<T, I>::SoftBondedCapPerSubAccountUpdated { cap_amount });
 			}
 			if let Some(reward_rate) = estimated_reward_rate_per_era {
-				EstimatedRewardRatePerEra::<T>::mutate(|rate| -> DispatchResult {
-					rate.try_set(reward_rate).map_err(|_| Error::<T>::InvalidRate.into())
+				EstimatedRewardRatePerEra::<T, I>::mutate(|rate| -> DispatchResult {
+					rate.try_set(reward_rate).map_err(|_| Error::<T, I>::InvalidRate.into())
 				})?;
-				Self::deposit_event(Event::<T>::EstimatedRewardRatePerEraUpdated { reward_rate });
+				Self::deposit_event(Event::<T, I>::EstimatedRewardRatePerEraUpdated { reward_rate });
 			}
 			if let Some(commission_rate) = commission_rate {
-				CommissionRate::<T>::mutate(|rate| -> DispatchResult {
+				CommissionRate::<T, I>::mutate(|rate| -> DispatchResult {
 					rate.try_set(commission_rate)
-						.map_err(|_| Error::<T>::InvalidRate.into())
+						.map_err(|_| Error::<T, I>::InvalidRate.into())
 				})?;
-				Self::deposit_event(Event::<T>::CommissionRateUpdated { commission_rate });
+				Self::deposit_event(Event::<T, I>::CommissionRateUpdated { commission_rate });
 			}
 			if let Some(fast_match_fee_rate) = fast_match_fee_rate {
-				FastMatchFeeRate::<T>::mutate(|rate| -> DispatchResult {
+				FastMatchFeeRate::<T, I>::mutate(|rate| -> DispatchResult {
 					rate.try_set(fast_match_fee_rate)
-						.map_err(|_| Error::<T>::InvalidRate.into())
+						.map_err(|_| Error::<T, I>::InvalidRate.into())
 				})?;
-				Self::deposit_event(Event::<T>::FastMatchFeeRateUpdated { fast_match_fee_rate });
+				Self::deposit_event(Event::<T, I>::FastMatchFeeRateUpdated { fast_match_fee_rate });
 			}
 
 			Ok(())
@@ -528,7 +561,7 @@ pub mod module {
 		/// - `fix_last_era_bumped_block`: fix the relaychain block number of last era bumped.
 		/// - `frequency`: the frequency of block number on parachain.
 		#[pallet::call_index(5)]
-		#[pallet::weight(< T as Config >::WeightInfo::update_bump_era_params())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::update_bump_era_params())]
 		pub fn update_bump_era_params(
 			origin: OriginFor<T>,
 			last_era_bumped_block: Option<BlockNumberFor<T>>,
@@ -537,8 +570,8 @@ pub mod module {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 
 			if let Some(change) = frequency {
-				BumpEraFrequency::<T>::put(change);
-				Self::deposit_event(Event::<T>::BumpEraFrequencyUpdated { frequency: change });
+				BumpEraFrequency::<T, I>::put(change);
+				Self::deposit_event(Event::<T, I>::BumpEraFrequencyUpdated { frequency: change });
 			}
 
 			if let Some(change) = last_era_bumped_block {
@@ -554,11 +587,11 @@ pub mod module {
 					ensure!(
 						change > current_relay_chain_block.saturating_sub(bump_era_frequency)
 							&& change <= current_relay_chain_block,
-						Error::<T>::InvalidLastEraBumpedBlock
+						Error::<T, I>::InvalidLastEraBumpedBlock
 					);
 
-					LastEraBumpedBlock::<T>::put(change);
-					Self::deposit_event(Event::<T>::LastEraBumpedBlockUpdated {
+					LastEraBumpedBlock::<T, I>::put(change);
+					Self::deposit_event(Event::<T, I>::LastEraBumpedBlockUpdated {
 						last_era_bumped_block: change,
 					});
 				}
@@ -573,7 +606,7 @@ pub mod module {
 		/// Parameters:
 		/// - `updates`: update list of subaccount.
 		#[pallet::call_index(6)]
-		#[pallet::weight(< T as Config >::WeightInfo::reset_ledgers(updates.len() as u32))]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::reset_ledgers(updates.len() as u32))]
 		pub fn reset_ledgers(
 			origin: OriginFor<T>,
 			updates: Vec<(u16, Option<Balance>, Option<Vec<UnlockChunk>>)>,
@@ -585,7 +618,7 @@ pub mod module {
 					if let Some(change) = bonded_change {
 						if ledger.bonded != change {
 							ledger.bonded = change;
-							Self::deposit_event(Event::<T>::LedgerBondedReset {
+							Self::deposit_event(Event::<T, I>::LedgerBondedReset {
 								sub_account_index,
 								new_bonded_amount: change,
 							});
@@ -594,7 +627,7 @@ pub mod module {
 					if let Some(change) = unlocking_change {
 						if ledger.unlocking != change {
 							ledger.unlocking = change.clone();
-							Self::deposit_event(Event::<T>::LedgerUnlockingReset {
+							Self::deposit_event(Event::<T, I>::LedgerUnlockingReset {
 								sub_account_index,
 								new_unlocking: change,
 							});
@@ -616,14 +649,14 @@ pub mod module {
 		/// Parameters:
 		/// - `era_index`: the latest era index of relaychain.
 		#[pallet::call_index(7)]
-		#[pallet::weight(< T as Config >::WeightInfo::reset_current_era())]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::reset_current_era())]
 		pub fn reset_current_era(origin: OriginFor<T>, era_index: EraIndex) -> DispatchResult {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 
-			RelayChainCurrentEra::<T>::mutate(|current_era| {
+			RelayChainCurrentEra::<T, I>::mutate(|current_era| {
 				if *current_era != era_index {
 					*current_era = era_index;
-					Self::deposit_event(Event::<T>::CurrentEraReset {
+					Self::deposit_event(Event::<T, I>::CurrentEraReset {
 						new_era_index: era_index,
 					});
 				}
@@ -633,7 +666,7 @@ pub mod module {
 		}
 
 		#[pallet::call_index(8)]
-		#[pallet::weight(< T as Config >::WeightInfo::on_initialize_with_bump_era())]
+		#[pallet::weight(Pallet::<T, I>::bump_era_weight())]
 		pub fn force_bump_current_era(origin: OriginFor<T>, bump_amount: EraIndex) -> DispatchResult {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 			Self::bump_current_era(bump_amount)
@@ -644,7 +677,7 @@ pub mod module {
 		/// Parameters:
 		/// - `redeemer_list`: The list of redeem requests to execute fast redeem.
 		#[pallet::call_index(9)]
-		#[pallet::weight(< T as Config >::WeightInfo::fast_match_redeems(redeemer_list.len() as u32))]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::fast_match_redeems(redeemer_list.len() as u32))]
 		pub fn fast_match_redeems_completely(origin: OriginFor<T>, redeemer_list: Vec<T::AccountId>) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
@@ -654,42 +687,61 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Redeem liquid currency for staking currency instantly. The amount will be fast matched
+		/// with ToBondPool as much as possible, and the remainder that cannot be matched will be
+		/// swapped to staking currency on DEX.
+		///
+		/// Parameters:
+		/// - `amount`: The amount of liquid currency to be redeemed.
+		/// - `min_redemption`: The minimum amount of staking currency to receive.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::instant_redeem())]
+		pub fn instant_redeem(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: Balance,
+			#[pallet::compact] min_redemption: Balance,
+		) -> DispatchResult {
+			let redeemer = ensure_signed(origin)?;
+			Self::do_instant_redeem(redeemer, amount, min_redemption)?;
+			Ok(())
+		}
 	}
 
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Module account id
 		pub fn account_id() -> T::AccountId {
 			T::PalletId::get().into_account_truncating()
 		}
 
 		pub(crate) fn estimated_reward_rate_per_era() -> Rate {
-			EstimatedRewardRatePerEra::<T>::get().into_inner()
+			EstimatedRewardRatePerEra::<T, I>::get().into_inner()
 		}
 
 		pub(crate) fn commission_rate() -> Rate {
-			CommissionRate::<T>::get().into_inner()
+			CommissionRate::<T, I>::get().into_inner()
 		}
 
 		pub(crate) fn fast_match_fee_rate() -> Rate {
-			FastMatchFeeRate::<T>::get().into_inner()
+			FastMatchFeeRate::<T, I>::get().into_inner()
 		}
 
 		pub fn do_update_ledger<R, E>(
 			sub_account_index: u16,
 			f: impl FnOnce(&mut StakingLedger) -> sp_std::result::Result<R, E>,
 		) -> sp_std::result::Result<R, E> {
-			StakingLedgers::<T>::try_mutate_exists(sub_account_index, |maybe_ledger| {
+			StakingLedgers::<T, I>::try_mutate_exists(sub_account_index, |maybe_ledger| {
 				let mut ledger = maybe_ledger.take().unwrap_or_default();
 				let old_bonded_amount = ledger.bonded;
 
 				f(&mut ledger).map(move |result| {
 					*maybe_ledger = if ledger == Default::default() {
-						TotalStakingBonded::<T>::mutate(|staking_balance| {
+						TotalStakingBonded::<T, I>::mutate(|staking_balance| {
 							*staking_balance = staking_balance.saturating_sub(old_bonded_amount)
 						});
 						None
 					} else {
-						TotalStakingBonded::<T>::mutate(|staking_balance| {
+						TotalStakingBonded::<T, I>::mutate(|staking_balance| {
 							*staking_balance = staking_balance
 								.saturating_add(ledger.bonded)
 								.saturating_sub(old_bonded_amount)
@@ -703,12 +755,12 @@ pub mod module {
 
 		pub(super) fn do_mint(minter: T::AccountId, amount: Balance) -> DispatchResult {
 			// Ensure the amount is above the MintThreshold.
-			ensure!(amount >= T::MintThreshold::get(), Error::<T>::BelowMintThreshold);
+			ensure!(amount >= T::MintThreshold::get(), Error::<T, I>::BelowMintThreshold);
 
 			// Ensure the total staking currency will not exceed soft cap.
 			ensure!(
 				Self::get_total_staking_currency().saturating_add(amount) <= Self::get_staking_currency_soft_cap(),
-				Error::<T>::ExceededStakingCurrencySoftCap
+				Error::<T, I>::ExceededStakingCurrencySoftCap
 			);
 
 			T::Currency::transfer(T::StakingCurrencyId::get(), &minter, &Self::account_id(), amount)?;
@@ -724,10 +776,10 @@ pub mod module {
 
 			Self::issue_liquid_currency(&minter, liquid_issue_to_minter)?;
 
-			ToBondPool::<T>::mutate(|pool| *pool = pool.saturating_add(amount));
-			TotalVoidLiquid::<T>::mutate(|total| *total = total.saturating_add(liquid_add_to_void));
+			ToBondPool::<T, I>::mutate(|pool| *pool = pool.saturating_add(amount));
+			TotalVoidLiquid::<T, I>::mutate(|total| *total = total.saturating_add(liquid_add_to_void));
 
-			Self::deposit_event(Event::<T>::Minted {
+			Self::deposit_event(Event::<T, I>::Minted {
 				minter,
 				staking_currency_amount: amount,
 				liquid_amount_received: liquid_issue_to_minter,
@@ -741,13 +793,13 @@ pub mod module {
 			amount: Balance,
 			allow_fast_match: bool,
 		) -> DispatchResult {
-			RedeemRequests::<T>::try_mutate_exists(&redeemer, |maybe_request| -> DispatchResult {
+			RedeemRequests::<T, I>::try_mutate_exists(&redeemer, |maybe_request| -> DispatchResult {
 				let (previous_request_amount, _) = maybe_request.take().unwrap_or_default();
 				let liquid_currency_id = T::LiquidCurrencyId::get();
 
 				ensure!(
 					(!previous_request_amount.is_zero() && amount.is_zero()) || amount >= T::RedeemThreshold::get(),
-					Error::<T>::BelowRedeemThreshold
+					Error::<T, I>::BelowRedeemThreshold
 				);
 
 				match amount.cmp(&previous_request_amount) {
@@ -774,13 +826,13 @@ pub mod module {
 
 				if !amount.is_zero() {
 					*maybe_request = Some((amount, allow_fast_match));
-					Self::deposit_event(Event::<T>::RequestedRedeem {
+					Self::deposit_event(Event::<T, I>::RequestedRedeem {
 						redeemer: redeemer.clone(),
 						liquid_amount: amount,
 						allow_fast_match,
 					});
 				} else if !previous_request_amount.is_zero() {
-					Self::deposit_event(Event::<T>::RedeemRequestCancelled {
+					Self::deposit_event(Event::<T, I>::RedeemRequestCancelled {
 						redeemer: redeemer.clone(),
 						cancelled_liquid_amount: previous_request_amount,
 					});
@@ -798,7 +850,7 @@ pub mod module {
 
 		/// Calculate the total amount of staking currency belong to Homa.
 		pub fn get_total_staking_currency() -> Balance {
-			TotalStakingBonded::<T>::get().saturating_add(Self::to_bond_pool())
+			TotalStakingBonded::<T, I>::get().saturating_add(Self::to_bond_pool())
 		}
 
 		/// Calculate the total amount of liquid currency.
@@ -841,20 +893,11 @@ pub mod module {
 
 		#[transactional]
 		pub fn do_fast_match_redeem(redeemer: &T::AccountId, allow_partially: bool) -> DispatchResult {
-			RedeemRequests::<T>::try_mutate_exists(redeemer, |maybe_request| -> DispatchResult {
+			RedeemRequests::<T, I>::try_mutate_exists(redeemer, |maybe_request| -> DispatchResult {
 				if let Some((request_amount, allow_fast_match)) = maybe_request.take() {
-					ensure!(allow_fast_match, Error::<T>::FastMatchIsNotAllowed);
-
-					// calculate the liquid currency limit can be used to redeem based on ToBondPool at fee_rate.
-					let available_staking_currency = Self::to_bond_pool();
-					let liquid_currency_limit = Self::convert_staking_to_liquid(available_staking_currency)?;
-					let fast_match_fee_rate = Self::fast_match_fee_rate();
-					let liquid_limit_at_fee_rate = Rate::one()
-						.saturating_sub(fast_match_fee_rate)
-						.reciprocal()
-						.unwrap_or_else(Bounded::max_value)
-						.saturating_mul_int(liquid_currency_limit);
-					let module_account = Self::account_id();
+					ensure!(allow_fast_match, Error::<T, I>::FastMatchIsNotAllowed);
+
+					let liquid_limit_at_fee_rate = Self::fast_match_liquid_limit()?;
 
 					// calculate the actual liquid currency to be used to redeem
 					let actual_liquid_to_redeem = if liquid_limit_at_fee_rate >= request_amount {
@@ -865,37 +908,13 @@ pub mod module {
 					};
 
 					if !actual_liquid_to_redeem.is_zero() {
-						let liquid_to_burn = Rate::one()
-							.saturating_sub(fast_match_fee_rate)
-							.saturating_mul_int(actual_liquid_to_redeem);
-						let redeemed_staking = Self::convert_liquid_to_staking(liquid_to_burn)?;
-						let fee_in_liquid = actual_liquid_to_redeem.saturating_sub(liquid_to_burn);
-
-						// burn liquid_to_burn for redeemed_staking and burn fee_in_liquid to reward all holders of
-						// liquid currency.
-						Self::burn_liquid_currency(&module_account, actual_liquid_to_redeem)?;
-
-						// transfer redeemed_staking to redeemer.
-						T::Currency::transfer(
-							T::StakingCurrencyId::get(),
-							&module_account,
-							redeemer,
-							redeemed_staking,
-						)?;
-						ToBondPool::<T>::mutate(|pool| *pool = pool.saturating_sub(redeemed_staking));
-
-						Self::deposit_event(Event::<T>::RedeemedByFastMatch {
-							redeemer: redeemer.clone(),
-							matched_liquid_amount: actual_liquid_to_redeem,
-							fee_in_liquid,
-							redeemed_staking_amount: redeemed_staking,
-						});
+						Self::redeem_by_fast_match(&Self::account_id(), redeemer, actual_liquid_to_redeem)?;
 					}
 
 					// update request amount
 					let remainder_request_amount = request_amount.saturating_sub(actual_liquid_to_redeem);
 					if !remainder_request_amount.is_zero() {
-						ensure!(allow_partially, Error::<T>::CannotCompletelyFastMatch);
+						ensure!(allow_partially, Error::<T, I>::CannotCompletelyFastMatch);
 						*maybe_request = Some((remainder_request_amount, allow_fast_match));
 					}
 				}
@@ -904,25 +923,112 @@ pub mod module {
 			})
 		}
 
+		/// Calculate the liquid currency limit can be used to redeem based on ToBondPool at fast
+		/// match fee rate.
+		fn fast_match_liquid_limit() -> Result<Balance, DispatchError> {
+			let liquid_currency_limit = Self::convert_staking_to_liquid(Self::to_bond_pool())?;
+			Ok(Rate::one()
+				.saturating_sub(Self::fast_match_fee_rate())
+				.reciprocal()
+				.unwrap_or_else(Bounded::max_value)
+				.saturating_mul_int(liquid_currency_limit))
+		}
+
+		/// Redeem `liquid_amount` of liquid currency held by `liquid_holder` with the staking
+		/// currency in ToBondPool at fast match fee rate, the redeemed staking currency will be
+		/// transferred to `redeemer`. Return the redeemed staking currency amount.
+		fn redeem_by_fast_match(
+			liquid_holder: &T::AccountId,
+			redeemer: &T::AccountId,
+			liquid_amount: Balance,
+		) -> Result<Balance, DispatchError> {
+			let liquid_to_burn = Rate::one()
+				.saturating_sub(Self::fast_match_fee_rate())
+				.saturating_mul_int(liquid_amount);
+			let redeemed_staking = Self::convert_liquid_to_staking(liquid_to_burn)?;
+			let fee_in_liquid = liquid_amount.saturating_sub(liquid_to_burn);
+
+			// burn liquid_to_burn for redeemed_staking and burn fee_in_liquid to reward all holders of
+			// liquid currency.
+			Self::burn_liquid_currency(liquid_holder, liquid_amount)?;
+
+			// transfer redeemed_staking to redeemer.
+			T::Currency::transfer(
+				T::StakingCurrencyId::get(),
+				&Self::account_id(),
+				redeemer,
+				redeemed_staking,
+			)?;
+			ToBondPool::<T, I>::mutate(|pool| *pool = pool.saturating_sub(redeemed_staking));
+
+			Self::deposit_event(Event::<T, I>::RedeemedByFastMatch {
+				redeemer: redeemer.clone(),
+				matched_liquid_amount: liquid_amount,
+				fee_in_liquid,
+				redeemed_staking_amount: redeemed_staking,
+			});
+
+			Ok(redeemed_staking)
+		}
+
+		/// Redeem liquid currency instantly, fast match with ToBondPool first and swap the
+		/// remainder on DEX. Return the total redeemed staking currency amount.
+		#[transactional]
+		pub fn do_instant_redeem(
+			redeemer: T::AccountId,
+			amount: Balance,
+			min_redemption: Balance,
+		) -> Result<Balance, DispatchError> {
+			// Ensure the amount is above the RedeemThreshold.
+			ensure!(amount >= T::RedeemThreshold::get(), Error::<T, I>::BelowRedeemThreshold);
+
+			let matched_liquid = Self::fast_match_liquid_limit()?.min(amount);
+			let mut redemption: Balance = Zero::zero();
+
+			if !matched_liquid.is_zero() {
+				redemption = Self::redeem_by_fast_match(&redeemer, &redeemer, matched_liquid)?;
+			}
+
+			let remainder_liquid = amount.saturating_sub(matched_liquid);
+			if !remainder_liquid.is_zero() {
+				let (_, swapped_staking) = T::Swap::swap(
+					&redeemer,
+					T::LiquidCurrencyId::get(),
+					T::StakingCurrencyId::get(),
+					SwapLimit::ExactSupply(remainder_liquid, min_redemption.saturating_sub(redemption)),
+				)?;
+				redemption = redemption.saturating_add(swapped_staking);
+
+				Self::deposit_event(Event::<T, I>::RedeemedByDexSwap {
+					redeemer: redeemer.clone(),
+					liquid_amount: remainder_liquid,
+					redeemed_staking_amount: swapped_staking,
+				});
+			}
+
+			ensure!(redemption >= min_redemption, Error::<T, I>::BelowMinimumRedemption);
+			Ok(redemption)
+		}
+
 		/// Accumulate staking rewards according to EstimatedRewardRatePerEra and era internally.
 		/// And draw commission from estimated staking rewards by issuing liquid currency to
 		/// TreasuryAccount. Note: This will cause some losses to the minters in previous_era,
 		/// because they have been already deducted some liquid currency amount when mint in
 		/// previous_era. Until there is a better way to calculate, this part of the loss can only
 		/// be regarded as an implicit mint fee!
+		/// Return the total staking rewards accumulated.
 		#[transactional]
-		pub fn process_staking_rewards(new_era: EraIndex, previous_era: EraIndex) -> DispatchResult {
+		pub fn process_staking_rewards(new_era: EraIndex, previous_era: EraIndex) -> Result<Balance, DispatchError> {
 			let era_interval = new_era.saturating_sub(previous_era);
 			let reward_rate = Self::estimated_reward_rate_per_era()
 				.saturating_add(Rate::one())
 				.saturating_pow(era_interval.unique_saturated_into())
 				.saturating_sub(Rate::one());
+			let mut total_reward_staking: Balance = Zero::zero();
 
 			if !reward_rate.is_zero() {
-				let mut total_reward_staking: Balance = Zero::zero();
-
 				// iterate all subaccounts
-				for (sub_account_index, ledger) in StakingLedgers::<T>::iter() {
+				for (sub_account_index, ledger) in StakingLedgers::<T, I>::iter() {
 					let reward_staking = reward_rate.saturating_mul_int(ledger.bonded);
 
 					if !reward_staking.is_zero() {
@@ -939,7 +1045,7 @@ pub mod module {
 				if !total_reward_staking.is_zero() && !commission_rate.is_zero() {
 					let commission_staking_amount = commission_rate.saturating_mul_int(total_reward_staking);
 					let commission_ratio =
-						Ratio::checked_from_rational(commission_staking_amount, TotalStakingBonded::<T>::get())
+						Ratio::checked_from_rational(commission_staking_amount, TotalStakingBonded::<T, I>::get())
 							.unwrap_or_else(Ratio::min_value);
 					let inflate_rate = commission_ratio
 						.checked_div(&Ratio::one().saturating_sub(commission_ratio))
@@ -950,7 +1056,7 @@ pub mod module {
 				}
 			}
 
-			Ok(())
+			Ok(total_reward_staking)
 		}
 
 		/// Get back unbonded of all subaccounts on relaychain by XCM.
@@ -960,7 +1066,7 @@ pub mod module {
 			let mut total_withdrawn_staking: Balance = Zero::zero();
 
 			// iterate all subaccounts
-			for (sub_account_index, ledger) in StakingLedgers::<T>::iter() {
+			for (sub_account_index, ledger) in StakingLedgers::<T, I>::iter() {
 				let (new_ledger, expired_unlocking) = ledger.consolidate_unlocked(new_era);
 
 				if !expired_unlocking.is_zero() {
@@ -977,7 +1083,7 @@ pub mod module {
 
 			// issue withdrawn unbonded to module account for redeemer to claim
 			Self::issue_staking_currency(&Self::account_id(), total_withdrawn_staking)?;
-			UnclaimedRedemption::<T>::mutate(|total| *total = total.saturating_add(total_withdrawn_staking));
+			UnclaimedRedemption::<T, I>::mutate(|total| *total = total.saturating_add(total_withdrawn_staking));
 
 			Ok(())
 		}
@@ -1023,7 +1129,7 @@ pub mod module {
 				}
 
 				// update pool
-				ToBondPool::<T>::mutate(|pool| *pool = remainder);
+				ToBondPool::<T, I>::mutate(|pool| *pool = remainder);
 			}
 
 			Ok(())
@@ -1033,22 +1139,22 @@ pub mod module {
 		#[transactional]
 		pub fn process_redeem_requests(new_era: EraIndex) -> DispatchResult {
 			let era_index_to_expire = new_era + T::BondingDuration::get();
-			let total_bonded = TotalStakingBonded::<T>::get();
+			let total_bonded = TotalStakingBonded::<T, I>::get();
 			let mut total_redeem_amount: Balance = Zero::zero();
 			let mut remain_total_bonded = total_bonded;
 
 			// iter RedeemRequests and insert to Unbondings if remain_total_bonded is enough.
-			for (redeemer, (redeem_amount, _)) in RedeemRequests::<T>::iter() {
+			for (redeemer, (redeem_amount, _)) in RedeemRequests::<T, I>::iter() {
 				let redemption_amount = Self::convert_liquid_to_staking(redeem_amount)?;
 
 				if remain_total_bonded >= redemption_amount {
 					total_redeem_amount = total_redeem_amount.saturating_add(redeem_amount);
 					remain_total_bonded = remain_total_bonded.saturating_sub(redemption_amount);
-					RedeemRequests::<T>::remove(&redeemer);
-					Unbondings::<T>::mutate(&redeemer, era_index_to_expire, |n| {
+					RedeemRequests::<T, I>::remove(&redeemer);
+					Unbondings::<T, I>::mutate(&redeemer, era_index_to_expire, |n| {
 						*n = n.saturating_add(redemption_amount)
 					});
-					Self::deposit_event(Event::<T>::RedeemedByUnbond {
+					Self::deposit_event(Event::<T, I>::RedeemedByUnbond {
 						redeemer,
 						era_index_when_unbond: new_era,
 						liquid_amount: redeem_amount,
@@ -1103,17 +1209,18 @@ pub mod module {
 		pub fn bump_current_era(amount: EraIndex) -> DispatchResult {
 			let previous_era = Self::relay_chain_current_era();
 			let new_era = previous_era.saturating_add(amount);
-			RelayChainCurrentEra::<T>::put(new_era);
-			LastEraBumpedBlock::<T>::put(T::RelayChainBlockNumber::current_block_number());
-			Self::deposit_event(Event::<T>::CurrentEraBumped { new_era_index: new_era });
+			RelayChainCurrentEra::<T, I>::put(new_era);
+			LastEraBumpedBlock::<T, I>::put(T::RelayChainBlockNumber::current_block_number());
+			Self::deposit_event(Event::<T, I>::CurrentEraBumped { new_era_index: new_era });
 
 			// Rebalance:
 			let res = || -> DispatchResult {
-				TotalVoidLiquid::<T>::put(0);
-				Self::process_staking_rewards(new_era, previous_era)?;
+				TotalVoidLiquid::<T, I>::put(0);
+				let rewards = Self::process_staking_rewards(new_era, previous_era)?;
 				Self::process_scheduled_unbond(new_era)?;
 				Self::process_to_bond_pool()?;
 				Self::process_redeem_requests(new_era)?;
+				Self::record_exchange_rate(new_era, rewards);
 				Ok(())
 			}();
 
@@ -1126,6 +1233,80 @@ pub mod module {
 			res
 		}
 
+		/// The weight of bumping era, including the rewrite of ExchangeRateHistory.
+		pub fn bump_era_weight() -> Weight {
+			<T as Config<I>>::WeightInfo::on_initialize_with_bump_era()
+				// ExchangeRateHistory (r: 1, w: 1)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		/// Record the exchange rate of Homa at the era into ExchangeRateHistory,
+		/// the earliest record will be dropped if the history is full.
+		pub(crate) fn record_exchange_rate(era: EraIndex, rewards: Balance) {
+			let record = ExchangeRateRecord {
+				total_staking: Self::get_total_staking_currency(),
+				total_liquid: Self::get_total_liquid_currency(),
+				exchange_rate: Self::current_exchange_rate(),
+				rewards,
+			};
+
+			ExchangeRateHistory::<T, I>::mutate(|history| {
+				// the era may have been reset backwards by governance, drop the records which are outdated.
+				history.retain(|(era_index, _)| *era_index < era);
+				if !history.is_empty() && history.is_full() {
+					history.remove(0);
+				}
+				let _ = history.try_push((era, record));
+			});
+		}
+
+		/// Get the exchange rate recorded at the specific era.
+		pub fn exchange_rate_at(era: EraIndex) -> Option<ExchangeRate> {
+			Self::exchange_rate_history()
+				.into_iter()
+				.find(|(era_index, _)| *era_index == era)
+				.map(|(_, record)| record.exchange_rate)
+		}
+
+		/// Calculate the annualized reward rate realised by the growth of exchange rate over the
+		/// latest `eras` eras in ExchangeRateHistory.
+		/// Note: realised_apy = (1 + growth_rate / era_interval) ^ ErasPerYear - 1
+		/// Return None if there are not enough records.
+		pub fn realised_apy(eras: EraIndex) -> Option<Rate> {
+			let history = Self::exchange_rate_history();
+			let (latest_era, latest_record) = history.last()?;
+			let start_era = latest_era.saturating_sub(eras);
+			let (earliest_era, earliest_record) = history.iter().find(|(era_index, _)| *era_index >= start_era)?;
+
+			let era_interval = latest_era.saturating_sub(*earliest_era);
+			if era_interval.is_zero() {
+				return None;
+			}
+
+			let reward_rate_per_era = latest_record
+				.exchange_rate
+				.checked_div(&earliest_record.exchange_rate)?
+				.saturating_sub(Rate::one())
+				.checked_div(&Rate::saturating_from_integer(era_interval))?;
+
+			Some(
+				reward_rate_per_era
+					.saturating_add(Rate::one())
+					.saturating_pow(T::ErasPerYear::get().unique_saturated_into())
+					.saturating_sub(Rate::one()),
+			)
+		}
+
+		/// Estimate the exchange rate after the next era is bumped, according to
+		/// EstimatedRewardRatePerEra and CommissionRate.
+		pub fn projected_exchange_rate() -> ExchangeRate {
+			let net_reward_rate = Rate::one()
+				.saturating_sub(Self::commission_rate())
+				.saturating_mul(Self::estimated_reward_rate_per_era());
+
+			Self::current_exchange_rate().saturating_mul(Rate::one().saturating_add(net_reward_rate))
+		}
+
 		/// This should be the only function in the system that issues liquid currency
 		fn issue_liquid_currency(who: &T::AccountId, amount: Balance) -> DispatchResult {
 			T::Currency::deposit(T::LiquidCurrencyId::get(), who, amount)
@@ -1143,14 +1324,14 @@ pub mod module {
 		}
 	}
 
-	impl<T: Config> ExchangeRateProvider for Pallet<T> {
+	impl<T: Config<I>, I: 'static> ExchangeRateProvider for Pallet<T, I> {
 		fn get_exchange_rate() -> ExchangeRate {
 			Self::current_exchange_rate()
 		}
 	}
 }
 
-impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
+impl<T: Config<I>, I: 'static> HomaManager<T::AccountId, Balance> for Pallet<T, I> {
 	fn mint(who: T::AccountId, amount: Balance) -> DispatchResult {
 		Self::do_mint(who, amount)
 	}
@@ -1159,20 +1340,46 @@ impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
 		Self::do_request_redeem(who, amount, fast_match)
 	}
 
+	fn instant_redeem(who: T::AccountId, amount: Balance, min_redemption: Balance) -> Result<Balance, DispatchError> {
+		Self::do_instant_redeem(who, amount, min_redemption)
+	}
+
 	fn get_exchange_rate() -> ExchangeRate {
 		Self::current_exchange_rate()
 	}
 
 	fn get_estimated_reward_rate() -> Rate {
-		EstimatedRewardRatePerEra::<T>::get().into_inner()
+		EstimatedRewardRatePerEra::<T, I>::get().into_inner()
 	}
 
 	fn get_commission_rate() -> Rate {
-		CommissionRate::<T>::get().into_inner()
+		CommissionRate::<T, I>::get().into_inner()
 	}
 
 	fn get_fast_match_fee() -> Rate {
-		FastMatchFeeRate::<T>::get().into_inner()
+		FastMatchFeeRate::<T, I>::get().into_inner()
+	}
+
+	fn get_exchange_rate_at(era: EraIndex) -> Option<ExchangeRate> {
+		Self::exchange_rate_at(era)
+	}
+
+	fn get_realised_apy(eras: EraIndex) -> Option<Rate> {
+		Self::realised_apy(eras)
+	}
+
+	fn get_projected_exchange_rate() -> ExchangeRate {
+		Self::projected_exchange_rate()
+	}
+}
+
+impl<T: Config<I>, I: 'static> LiquidStakingPools for Pallet<T, I> {
+	fn get_staking_currency_and_exchange_rate(liquid_currency_id: CurrencyId) -> Option<(CurrencyId, ExchangeRate)> {
+		if liquid_currency_id == T::LiquidCurrencyId::get() {
+			Some((T::StakingCurrencyId::get(), Self::current_exchange_rate()))
+		} else {
+			None
+		}
 	}
 }
 
@@ -22,11 +22,12 @@
 
 use super::*;
 use frame_support::{
+	instances::Instance1,
 	ord_parameter_types, parameter_types,
 	traits::{ConstU128, ConstU32, ConstU64, Everything, Nothing},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
-use module_support::mocks::MockAddressMapping;
+use module_support::{mocks::MockAddressMapping, AggregatedSwapPath, SwapError};
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, TokenSymbol};
 use sp_core::{H160, H256};
@@ -44,16 +45,21 @@ pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId = AccountId32::new([2u8; 32]);
 pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
 pub const DAVE: AccountId = AccountId32::new([4u8; 32]);
+pub const DEX_POOL: AccountId = AccountId32::new([5u8; 32]);
 pub const HOMA_TREASURY: AccountId = AccountId32::new([255u8; 32]);
 pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const STAKING_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
 pub const LIQUID_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+pub const KSM_STAKING_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+pub const KSM_LIQUID_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::LKSM);
 
 /// mock XCM transfer.
-pub struct MockHomaSubAccountXcm;
-impl HomaSubAccountXcm<AccountId, Balance> for MockHomaSubAccountXcm {
+pub struct MockHomaSubAccountXcm<GetStakingCurrencyId = StakingCurrencyId>(PhantomData<GetStakingCurrencyId>);
+impl<GetStakingCurrencyId: Get<CurrencyId>> HomaSubAccountXcm<AccountId, Balance>
+	for MockHomaSubAccountXcm<GetStakingCurrencyId>
+{
 	fn transfer_staking_to_sub_account(sender: &AccountId, _: u16, amount: Balance) -> DispatchResult {
-		Currencies::withdraw(StakingCurrencyId::get(), sender, amount)
+		Currencies::withdraw(GetStakingCurrencyId::get(), sender, amount)
 	}
 
 	fn withdraw_unbonded_from_sub_account(_: u16, _: Balance) -> DispatchResult {
@@ -77,6 +83,49 @@ impl HomaSubAccountXcm<AccountId, Balance> for MockHomaSubAccountXcm {
 	}
 }
 
+parameter_types! {
+	pub static MockSwapRate: ExchangeRate = ExchangeRate::saturating_from_rational(9, 100);
+}
+
+/// mock DEX swap liquid currency to staking currency with DEX_POOL at MockSwapRate.
+pub struct MockSwap;
+impl Swap<AccountId, Balance, CurrencyId> for MockSwap {
+	fn get_swap_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		match (supply_currency_id, target_currency_id, limit) {
+			(LIQUID_CURRENCY_ID, STAKING_CURRENCY_ID, SwapLimit::ExactSupply(supply_amount, min_target_amount)) => {
+				let target_amount = MockSwapRate::get().saturating_mul_int(supply_amount);
+				(target_amount >= min_target_amount).then_some((supply_amount, target_amount))
+			}
+			_ => None,
+		}
+	}
+
+	fn swap(
+		who: &AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (supply_amount, target_amount) = Self::get_swap_amount(supply_currency_id, target_currency_id, limit)
+			.ok_or_else(|| Into::<DispatchError>::into(SwapError::CannotSwap))?;
+		Currencies::transfer(supply_currency_id, who, &DEX_POOL, supply_amount)?;
+		Currencies::transfer(target_currency_id, &DEX_POOL, who, target_amount)?;
+		Ok((supply_amount, target_amount))
+	}
+
+	fn swap_by_aggregated_path(
+		_: &AccountId,
+		_: &[AggregatedSwapPath<CurrencyId>],
+		_: SwapLimit<Balance>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		Err(SwapError::CannotSwap.into())
+	}
+}
+
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = Everything;
 	type BlockWeights = ();
@@ -159,6 +208,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type AssetStatusProvider = ();
 }
 
 impl BlockNumberProvider for MockRelayBlockNumberProvider {
@@ -183,6 +233,8 @@ parameter_types! {
 	pub const BondingDuration: EraIndex = 28;
 	pub static MintThreshold: Balance = 0;
 	pub static RedeemThreshold: Balance = 0;
+	pub const MaxExchangeRateHistory: u32 = 3;
+	pub const ErasPerYear: EraIndex = 365;
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
 }
 
@@ -199,8 +251,41 @@ impl Config for Runtime {
 	type BondingDuration = BondingDuration;
 	type MintThreshold = MintThreshold;
 	type RedeemThreshold = RedeemThreshold;
+	type MaxExchangeRateHistory = MaxExchangeRateHistory;
+	type ErasPerYear = ErasPerYear;
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type XcmInterface = MockHomaSubAccountXcm;
+	type Swap = MockSwap;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const KsmStakingCurrencyId: CurrencyId = KSM_STAKING_CURRENCY_ID;
+	pub const KsmLiquidCurrencyId: CurrencyId = KSM_LIQUID_CURRENCY_ID;
+	pub const KsmHomaPalletId: PalletId = PalletId(*b"aca/hmks");
+	pub KsmDefaultExchangeRate: ExchangeRate = ExchangeRate::saturating_from_rational(1, 5);
+	pub KsmActiveSubAccountsIndexList: Vec<u16> = vec![0];
+	pub const KsmBondingDuration: EraIndex = 7;
+}
+
+impl Config<Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GovernanceOrigin = EnsureSignedBy<HomaAdmin, AccountId>;
+	type StakingCurrencyId = KsmStakingCurrencyId;
+	type LiquidCurrencyId = KsmLiquidCurrencyId;
+	type PalletId = KsmHomaPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type DefaultExchangeRate = KsmDefaultExchangeRate;
+	type ActiveSubAccountsIndexList = KsmActiveSubAccountsIndexList;
+	type BondingDuration = KsmBondingDuration;
+	type MintThreshold = MintThreshold;
+	type RedeemThreshold = RedeemThreshold;
+	type MaxExchangeRateHistory = MaxExchangeRateHistory;
+	type ErasPerYear = ErasPerYear;
+	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
+	type XcmInterface = MockHomaSubAccountXcm<KsmStakingCurrencyId>;
+	type Swap = MockSwap;
 	type WeightInfo = ();
 }
 
@@ -210,6 +295,7 @@ frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
 		Homa: homa,
+		KsmHoma: homa::<Instance1>,
 		Balances: pallet_balances,
 		Tokens: orml_tokens,
 		Currencies: module_currencies,
@@ -21,8 +21,9 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, instances::Instance1};
 use mock::{RuntimeEvent, *};
+use module_support::SwapError;
 use orml_traits::MultiCurrency;
 use sp_runtime::{traits::BadOrigin, FixedPointNumber};
 
@@ -1365,6 +1366,214 @@ fn bump_current_era_works() {
 		});
 }
 
+#[test]
+fn instant_redeem_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, STAKING_CURRENCY_ID, 1_000_000),
+			(DEX_POOL, STAKING_CURRENCY_ID, 1_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000),
+				None,
+				None,
+				Some(Rate::saturating_from_rational(1, 10)),
+			));
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(ALICE), 100_000));
+			assert_ok!(Homa::reset_ledgers(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				vec![(0, Some(400_000), None)]
+			));
+			assert_eq!(Homa::to_bond_pool(), 100_000);
+			assert_eq!(Homa::current_exchange_rate(), ExchangeRate::saturating_from_rational(1, 2));
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &ALICE), 900_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 1_000_000);
+
+			RedeemThreshold::set(500_001);
+			assert_noop!(
+				Homa::instant_redeem(RuntimeOrigin::signed(ALICE), 500_000, 0),
+				Error::<Runtime>::BelowRedeemThreshold
+			);
+			RedeemThreshold::set(0);
+
+			// fast match 222_222 by ToBondPool and swap the remainder 277_778 on DEX,
+			// the swap cannot receive enough redemption.
+			assert_noop!(
+				Homa::instant_redeem(RuntimeOrigin::signed(ALICE), 500_000, 125_000),
+				SwapError::CannotSwap
+			);
+
+			assert_ok!(Homa::instant_redeem(RuntimeOrigin::signed(ALICE), 500_000, 124_999));
+			System::assert_has_event(RuntimeEvent::Homa(crate::Event::RedeemedByFastMatch {
+				redeemer: ALICE,
+				matched_liquid_amount: 222_222,
+				fee_in_liquid: 22_223,
+				redeemed_staking_amount: 99_999,
+			}));
+			System::assert_last_event(RuntimeEvent::Homa(crate::Event::RedeemedByDexSwap {
+				redeemer: ALICE,
+				liquid_amount: 277_778,
+				redeemed_staking_amount: 25_000,
+			}));
+			assert_eq!(Homa::to_bond_pool(), 1);
+			assert_eq!(Homa::redeem_requests(&ALICE), None);
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &ALICE), 1_024_999);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 500_000);
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &Homa::account_id()), 1);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &DEX_POOL), 277_778);
+		});
+}
+
+#[test]
+fn exchange_rate_history_works() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, STAKING_CURRENCY_ID, 100_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(20_000_000),
+				Some(Rate::saturating_from_rational(1, 100)),
+				None,
+				None,
+			));
+			assert_eq!(Homa::exchange_rate_history().into_inner(), vec![]);
+			assert_eq!(Homa::exchange_rate_at(1), None);
+			assert_eq!(Homa::realised_apy(10), None);
+
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(ALICE), 30_000_000));
+
+			// bump era to #1, record without rewards.
+			assert_ok!(Homa::bump_current_era(1));
+			let record_1 = ExchangeRateRecord {
+				total_staking: 28_000_000,
+				total_liquid: 297_029_702,
+				exchange_rate: ExchangeRate::saturating_from_rational(28_000_000, 297_029_702),
+				rewards: 0,
+			};
+			assert_eq!(Homa::exchange_rate_history().into_inner(), vec![(1, record_1)]);
+			assert_eq!(Homa::exchange_rate_at(1), Some(record_1.exchange_rate));
+			assert_eq!(Homa::realised_apy(10), None);
+
+			// bump era to #2, record with staking rewards.
+			assert_ok!(Homa::bump_current_era(1));
+			let record_2 = ExchangeRateRecord {
+				total_staking: 28_280_000,
+				total_liquid: 297_029_702,
+				exchange_rate: ExchangeRate::saturating_from_rational(28_280_000, 297_029_702),
+				rewards: 280_000,
+			};
+			assert_eq!(
+				Homa::exchange_rate_history().into_inner(),
+				vec![(1, record_1), (2, record_2)]
+			);
+			assert_eq!(Homa::exchange_rate_at(2), Some(record_2.exchange_rate));
+
+			// realised reward rate per era is 1%, (1 + 1%) ^ 365 - 1 = 36.7834...
+			assert_eq!(
+				Homa::realised_apy(10).map(|apy| apy.saturating_mul_int(100u128)),
+				Some(3678)
+			);
+			assert_eq!(Homa::realised_apy(1), Homa::realised_apy(10));
+			assert_eq!(Homa::realised_apy(0), None);
+			assert_eq!(
+				Homa::projected_exchange_rate(),
+				Homa::current_exchange_rate().saturating_mul(Rate::saturating_from_rational(101, 100))
+			);
+
+			// the earliest record will be dropped if history is full.
+			assert_ok!(Homa::bump_current_era(1));
+			assert_ok!(Homa::bump_current_era(1));
+			assert_eq!(
+				Homa::exchange_rate_history()
+					.into_iter()
+					.map(|(era, _)| era)
+					.collect::<Vec<_>>(),
+				vec![2, 3, 4]
+			);
+			assert_eq!(Homa::exchange_rate_at(1), None);
+
+			// the records outdated by resetting era will be overwritten.
+			assert_ok!(Homa::reset_current_era(RuntimeOrigin::signed(HomaAdmin::get()), 2));
+			assert_ok!(Homa::bump_current_era(1));
+			assert_eq!(
+				Homa::exchange_rate_history()
+					.into_iter()
+					.map(|(era, _)| era)
+					.collect::<Vec<_>>(),
+				vec![2, 3]
+			);
+		});
+}
+
+#[test]
+fn multiple_liquid_staking_pools_works() {
+	ExtBuilder::default()
+		.balances(vec![
+			(ALICE, STAKING_CURRENCY_ID, 1_000_000),
+			(ALICE, KSM_STAKING_CURRENCY_ID, 1_000_000),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000),
+				None,
+				None,
+				None,
+			));
+			assert_ok!(KsmHoma::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(500_000),
+				None,
+				None,
+				None,
+			));
+			assert_eq!(Homa::soft_bonded_cap_per_sub_account(), 1_000_000);
+			assert_eq!(KsmHoma::soft_bonded_cap_per_sub_account(), 500_000);
+
+			assert_noop!(
+				KsmHoma::mint(RuntimeOrigin::signed(ALICE), 500_001),
+				Error::<Runtime, Instance1>::ExceededStakingCurrencySoftCap
+			);
+
+			assert_ok!(KsmHoma::mint(RuntimeOrigin::signed(ALICE), 100_000));
+			System::assert_last_event(RuntimeEvent::KsmHoma(crate::Event::Minted {
+				minter: ALICE,
+				staking_currency_amount: 100_000,
+				liquid_amount_received: 500_000,
+				liquid_amount_added_to_void: 0,
+			}));
+			assert_eq!(KsmHoma::to_bond_pool(), 100_000);
+			assert_eq!(Homa::to_bond_pool(), 0);
+			assert_eq!(Currencies::free_balance(KSM_STAKING_CURRENCY_ID, &ALICE), 900_000);
+			assert_eq!(Currencies::free_balance(KSM_LIQUID_CURRENCY_ID, &ALICE), 500_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 0);
+
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(ALICE), 100_000));
+			assert_eq!(Homa::to_bond_pool(), 100_000);
+			assert_eq!(KsmHoma::to_bond_pool(), 100_000);
+			assert_eq!(Currencies::free_balance(STAKING_CURRENCY_ID, &ALICE), 900_000);
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY_ID, &ALICE), 1_000_000);
+
+			assert_eq!(
+				<(Homa, KsmHoma) as LiquidStakingPools>::get_staking_currency_and_exchange_rate(LIQUID_CURRENCY_ID),
+				Some((STAKING_CURRENCY_ID, ExchangeRate::saturating_from_rational(1, 10)))
+			);
+			assert_eq!(
+				<(Homa, KsmHoma) as LiquidStakingPools>::get_staking_currency_and_exchange_rate(KSM_LIQUID_CURRENCY_ID),
+				Some((KSM_STAKING_CURRENCY_ID, ExchangeRate::saturating_from_rational(1, 5)))
+			);
+			assert_eq!(
+				<(Homa, KsmHoma) as LiquidStakingPools>::get_staking_currency_and_exchange_rate(STAKING_CURRENCY_ID),
+				None
+			);
+		});
+}
+
 #[test]
 fn last_era_bumped_block_config_check_works() {
 	ExtBuilder::default().build().execute_with(|| {
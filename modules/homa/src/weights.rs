@@ -57,6 +57,7 @@ pub trait WeightInfo {
 	fn update_bump_era_params() -> Weight;
 	fn reset_ledgers(n: u32, ) -> Weight;
 	fn reset_current_era() -> Weight;
+	fn instant_redeem() -> Weight;
 }
 
 /// Weights for module_homa using the Acala node and recommended hardware.
@@ -187,6 +188,21 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Homa ToBondPool (r:1 w:1)
+	// Storage: Homa TotalStakingBonded (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Homa TotalVoidLiquid (r:1 w:0)
+	// Storage: Homa FastMatchFeeRate (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	fn instant_redeem() -> Weight {
+		Weight::from_parts(136_573_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -248,4 +264,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	fn instant_redeem() -> Weight {
+		Weight::from_parts(136_573_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
 }
@@ -138,10 +138,12 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type AssetStatusProvider = ();
 }
 
 parameter_types! {
 	pub NetworkContractSource: EvmAddress = EvmAddress::default();
+	pub const Hardfork: module_evm::EvmHardfork = module_evm::EvmHardfork::TransientStorage;
 }
 
 ord_parameter_types! {
@@ -162,12 +164,16 @@ impl module_evm::Config for Runtime {
 	type GasToWeight = ();
 	type ChargeTransactionPayment = module_support::mocks::MockReservedTransactionPayment<Balances>;
 	type NetworkContractOrigin = EnsureRoot<AccountId>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 
 	type DeveloperDeposit = ConstU128<1000>;
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureRoot<AccountId>;
+	type ContractUpgradeDelay = ConstU64<10>;
+	type SponsorshipDeposit = ConstU128<100>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
@@ -186,6 +192,9 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type ContractSignatureVerifier = ();
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = ();
 	type WeightInfo = ();
 }
 
@@ -241,6 +241,9 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type ContractSignatureVerifier = ();
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = ();
 	type WeightInfo = ();
 }
 
@@ -284,6 +287,7 @@ impl module_cdp_engine::Config for Runtime {
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = SpecificJointsSwap<(), AlternativeSwapPathJointList>;
 	type WeightInfo = ();
+	type AssetStatusProvider = ();
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
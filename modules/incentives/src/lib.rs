@@ -41,7 +41,9 @@
 
 use frame_support::{pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use module_support::{DEXIncentives, EmergencyShutdown, FractionalRate, IncentivesManager, PoolId, Rate};
+use module_support::{
+	DEXIncentives, EmergencyShutdown, EmissionVotes, FractionalRate, IncentivesManager, PoolId, Rate, Ratio,
+};
 use orml_traits::{Happened, MultiCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
@@ -89,6 +91,9 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The votes to direct the incentive rewards among `PoolId::Dex`.
+		type EmissionVotes: EmissionVotes;
+
 		/// The module id, keep DexShare LP.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -288,23 +293,7 @@ pub mod module {
 				}
 
 				for (currency_id, amount) in update_list {
-					IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
-						let mut v = maybe_amount.unwrap_or_default();
-						if amount != v {
-							v = amount;
-							Self::deposit_event(Event::IncentiveRewardAmountUpdated {
-								pool: pool_id,
-								reward_currency_id: currency_id,
-								reward_amount_per_period: amount,
-							});
-						}
-
-						if v.is_zero() {
-							*maybe_amount = None;
-						} else {
-							*maybe_amount = Some(v);
-						}
-					});
+					Self::do_update_incentive_reward(pool_id, currency_id, amount);
 				}
 			}
 			Ok(())
@@ -365,6 +354,40 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Split the incentive reward amount of `reward_currency_id` per period among `PoolId::Dex`
+		/// pro rata to their votes of `EmissionVotes`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `pools`: the pools to update, the pool which has no votes will get no rewards.
+		/// - `reward_currency_id`: the reward currency.
+		/// - `total_reward_amount`: the total reward amount per period for all voted pools.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_incentive_rewards(pools.len() as u32))]
+		pub fn update_dex_incentive_rewards_by_votes(
+			origin: OriginFor<T>,
+			pools: Vec<PoolId>,
+			reward_currency_id: CurrencyId,
+			#[pallet::compact] total_reward_amount: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let total_vote_weight = T::EmissionVotes::get_total_vote_weight();
+
+			for pool_id in pools {
+				match pool_id {
+					PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id() => {}
+					_ => return Err(Error::<T>::InvalidPoolId.into()),
+				}
+
+				let amount =
+					Ratio::checked_from_rational(T::EmissionVotes::get_pool_vote_weight(&pool_id), total_vote_weight)
+						.map(|ratio| ratio.saturating_mul_int(total_reward_amount))
+						.unwrap_or_default();
+				Self::do_update_incentive_reward(pool_id, reward_currency_id, amount);
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -377,6 +400,26 @@ impl<T: Config> Pallet<T> {
 		ClaimRewardDeductionRates::<T>::get(pool_id).into_inner()
 	}
 
+	fn do_update_incentive_reward(pool_id: PoolId, currency_id: CurrencyId, amount: Balance) {
+		IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
+			let mut v = maybe_amount.unwrap_or_default();
+			if amount != v {
+				v = amount;
+				Self::deposit_event(Event::IncentiveRewardAmountUpdated {
+					pool: pool_id,
+					reward_currency_id: currency_id,
+					reward_amount_per_period: amount,
+				});
+			}
+
+			if v.is_zero() {
+				*maybe_amount = None;
+			} else {
+				*maybe_amount = Some(v);
+			}
+		});
+	}
+
 	// accumulate incentive rewards of multi currencies
 	fn accumulate_incentives(pool_id: PoolId) {
 		for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
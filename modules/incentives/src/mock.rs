@@ -123,6 +123,20 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub struct MockEmissionVotes;
+impl EmissionVotes for MockEmissionVotes {
+	fn get_pool_vote_weight(pool_id: &PoolId) -> Balance {
+		match pool_id {
+			PoolId::Dex(DOT_AUSD_LP) => 300,
+			_ => 0,
+		}
+	}
+
+	fn get_total_vote_weight() -> Balance {
+		400
+	}
+}
+
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
@@ -148,6 +162,7 @@ impl Config for Runtime {
 	type UpdateOrigin = EnsureSignedBy<ROOT, AccountId>;
 	type Currency = TokensModule;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type EmissionVotes = MockEmissionVotes;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = ();
 }
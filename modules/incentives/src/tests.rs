@@ -233,6 +233,77 @@ fn update_incentive_rewards_works() {
 	});
 }
 
+#[test]
+fn update_dex_incentive_rewards_by_votes_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			IncentivesModule::update_dex_incentive_rewards_by_votes(
+				RuntimeOrigin::signed(ALICE::get()),
+				vec![],
+				ACA,
+				1000
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			IncentivesModule::update_dex_incentive_rewards_by_votes(
+				RuntimeOrigin::signed(ROOT::get()),
+				vec![PoolId::Loans(DOT)],
+				ACA,
+				1000
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::update_dex_incentive_rewards_by_votes(
+				RuntimeOrigin::signed(ROOT::get()),
+				vec![PoolId::Dex(DOT)],
+				ACA,
+				1000
+			),
+			Error::<Runtime>::InvalidPoolId
+		);
+
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![(PoolId::Dex(BTC_AUSD_LP), vec![(ACA, 100)])],
+		));
+		assert_eq!(
+			IncentivesModule::incentive_reward_amounts(PoolId::Dex(BTC_AUSD_LP), ACA),
+			100
+		);
+
+		assert_ok!(IncentivesModule::update_dex_incentive_rewards_by_votes(
+			RuntimeOrigin::signed(ROOT::get()),
+			vec![PoolId::Dex(DOT_AUSD_LP), PoolId::Dex(BTC_AUSD_LP)],
+			ACA,
+			1000
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(
+			crate::Event::IncentiveRewardAmountUpdated {
+				pool: PoolId::Dex(DOT_AUSD_LP),
+				reward_currency_id: ACA,
+				reward_amount_per_period: 750,
+			},
+		));
+		System::assert_has_event(RuntimeEvent::IncentivesModule(
+			crate::Event::IncentiveRewardAmountUpdated {
+				pool: PoolId::Dex(BTC_AUSD_LP),
+				reward_currency_id: ACA,
+				reward_amount_per_period: 0,
+			},
+		));
+		assert_eq!(
+			IncentivesModule::incentive_reward_amounts(PoolId::Dex(DOT_AUSD_LP), ACA),
+			750
+		);
+		assert_eq!(
+			IncentiveRewardAmounts::<Runtime>::contains_key(PoolId::Dex(BTC_AUSD_LP), ACA),
+			false
+		);
+	});
+}
+
 #[test]
 fn update_claim_reward_deduction_rates_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -134,6 +134,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureRoot<AccountId>;
 	type OnDust = ();
+	type AssetStatusProvider = ();
 }
 
 thread_local! {
@@ -30,7 +30,7 @@ use frame_support::{
 		ExistenceRequirement::{AllowDeath, KeepAlive},
 		NamedReservableCurrency,
 	},
-	PalletId,
+	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
 use orml_traits::InspectExtended;
@@ -38,11 +38,11 @@ use primitives::{
 	nft::{Attributes, ClassProperty, NFTBalance, Properties, CID},
 	ReserveIdentifier,
 };
-use scale_info::TypeInfo;
+use scale_info::{prelude::format, TypeInfo};
 
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
+	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
 	DispatchResult, RuntimeDebug,
 };
 use sp_std::prelude::*;
@@ -148,6 +148,8 @@ pub mod module {
 		AttributesTooLarge,
 		/// The given token ID is not correct
 		IncorrectTokenId,
+		/// The token is not owned by the given account
+		NotTokenOwner,
 	}
 
 	#[pallet::event]
@@ -190,8 +192,38 @@ pub mod module {
 			owner: T::AccountId,
 			class_id: ClassIdOf<T>,
 		},
+		/// The approved account of NFT token is set.
+		Approval {
+			owner: T::AccountId,
+			approved: Option<T::AccountId>,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// The operator of all the owner's NFT tokens of the class is set.
+		ApprovalForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			class_id: ClassIdOf<T>,
+			approved: bool,
+		},
 	}
 
+	/// The account approved to transfer the token.
+	///
+	/// TokenApprovals: double_map ClassId, TokenId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn token_approvals)]
+	pub type TokenApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, T::AccountId, OptionQuery>;
+
+	/// The operators approved to transfer all the owner's tokens of the class.
+	///
+	/// OperatorApprovals: double_map (ClassId, owner: AccountId), operator: AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn operator_approvals)]
+	pub type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, (ClassIdOf<T>, T::AccountId), Twox64Concat, T::AccountId, bool, ValueQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
@@ -399,6 +431,7 @@ impl<T: Config> Pallet<T> {
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 
 		orml_nft::Pallet::<T>::transfer(from, to, token)?;
+		TokenApprovals::<T>::remove(token.0, token.1);
 
 		let reserve_balance = token_info.data.deposit;
 
@@ -428,6 +461,123 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Set the approved account of the token, `who` must be the owner or an operator of the owner.
+	pub fn approve(
+		who: &T::AccountId,
+		approved: Option<T::AccountId>,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let owner = <Self as Inspect<T::AccountId>>::owner(&token.0, &token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(
+			*who == owner || Self::operator_approvals((token.0, &owner), who),
+			Error::<T>::NoPermission
+		);
+
+		match approved {
+			Some(ref approved) => TokenApprovals::<T>::insert(token.0, token.1, approved),
+			None => TokenApprovals::<T>::remove(token.0, token.1),
+		}
+
+		Self::deposit_event(Event::Approval {
+			owner,
+			approved,
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	/// Approve or remove `operator` as an operator of all the `owner`'s tokens of the class.
+	pub fn set_approval_for_all(owner: &T::AccountId, operator: &T::AccountId, class_id: ClassIdOf<T>, approved: bool) {
+		if approved {
+			OperatorApprovals::<T>::insert((class_id, owner), operator, true);
+		} else {
+			OperatorApprovals::<T>::remove((class_id, owner), operator);
+		}
+
+		Self::deposit_event(Event::ApprovalForAll {
+			owner: owner.clone(),
+			operator: operator.clone(),
+			class_id,
+			approved,
+		});
+	}
+
+	/// Whether `spender` is the owner, the approved account or an operator of the token.
+	pub fn is_approved_or_owner(spender: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		<Self as Inspect<T::AccountId>>::owner(&token.0, &token.1).map_or(false, |owner| {
+			*spender == owner
+				|| Self::token_approvals(token.0, token.1).as_ref() == Some(spender)
+				|| Self::operator_approvals((token.0, &owner), spender)
+		})
+	}
+
+	/// Transfer the token of `from` to `to`, `spender` must be the owner, the approved account or
+	/// an operator of the token.
+	#[transactional]
+	pub fn transfer_from(
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let owner = <Self as Inspect<T::AccountId>>::owner(&token.0, &token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(*from == owner, Error::<T>::NotTokenOwner);
+		ensure!(Self::is_approved_or_owner(spender, token), Error::<T>::NoPermission);
+
+		Self::do_transfer(from, to, token)
+	}
+
+	/// Mint tokens of the class, `who` must be the class owner or a proxy delegate of the class
+	/// owner.
+	#[transactional]
+	pub fn mint_by_class_owner(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		metadata: CID,
+		attributes: Attributes,
+		quantity: u32,
+	) -> Result<Vec<TokenIdOf<T>>, DispatchError> {
+		let owner = <Self as Inspect<T::AccountId>>::collection_owner(&class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(
+			*who == owner || pallet_proxy::Pallet::<T>::find_proxy(&owner, who, None).is_ok(),
+			Error::<T>::NoPermission
+		);
+
+		Self::do_mint(&owner, to, class_id, metadata, attributes, quantity)
+	}
+
+	/// Burn the token, `spender` must be the owner, the approved account or an operator of the
+	/// token.
+	#[transactional]
+	pub fn burn_from(spender: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		let owner = <Self as Inspect<T::AccountId>>::owner(&token.0, &token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(Self::is_approved_or_owner(spender, token), Error::<T>::NoPermission);
+
+		Self::do_burn(owner, token, None)
+	}
+
+	/// The URI of the token. It is the `tokenURI` attribute of the token if set, otherwise the
+	/// token metadata, otherwise the class metadata followed by `/{token_id}`.
+	pub fn token_uri(class_id: ClassIdOf<T>, token_id: TokenIdOf<T>) -> Option<Vec<u8>> {
+		let token_info = orml_nft::Pallet::<T>::tokens(class_id, token_id)?;
+		if let Some(uri) = token_info.data.attributes.get(&b"tokenURI"[..]) {
+			return Some(uri.clone());
+		}
+		if !token_info.metadata.is_empty() {
+			return Some(token_info.metadata.into_inner());
+		}
+
+		let class_info = orml_nft::Pallet::<T>::classes(class_id)?;
+		if class_info.metadata.is_empty() {
+			return Some(Vec::new());
+		}
+		let mut uri = class_info.metadata.into_inner();
+		uri.extend_from_slice(format!("/{}", UniqueSaturatedInto::<u64>::unique_saturated_into(token_id)).as_bytes());
+		Some(uri)
+	}
+
 	#[require_transactional]
 	fn do_mint(
 		who: &T::AccountId,
@@ -500,6 +650,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
 		orml_nft::Pallet::<T>::burn(&who, token)?;
+		TokenApprovals::<T>::remove(token.0, token.1);
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
 
@@ -185,6 +185,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<One, AccountId>;
 	type OnDust = ();
+	type AssetStatusProvider = ();
 }
 
 parameter_types! {
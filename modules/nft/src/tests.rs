@@ -736,3 +736,114 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+#[test]
+fn approve_and_transfer_from_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(&class_id_account(), 10_000));
+
+		// only the class owner or its proxy can mint
+		assert_noop!(
+			NFTModule::mint_by_class_owner(&BOB, &BOB, CLASS_ID, vec![2], Default::default(), 1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_eq!(
+			NFTModule::mint_by_class_owner(&ALICE, &BOB, CLASS_ID, vec![2], Default::default(), 1),
+			Ok(vec![TOKEN_ID])
+		);
+
+		assert_noop!(
+			NFTModule::approve(&ALICE, Some(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::transfer_from(&ALICE, &BOB, &ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::approve(&BOB, Some(ALICE), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::Approval {
+			owner: BOB,
+			approved: Some(ALICE),
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), Some(ALICE));
+
+		assert_noop!(
+			NFTModule::transfer_from(&ALICE, &ALICE, &ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotTokenOwner
+		);
+		assert_ok!(NFTModule::transfer_from(&ALICE, &BOB, &ALICE, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::owner(&CLASS_ID, &TOKEN_ID), Some(ALICE));
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), None);
+
+		// operator
+		NFTModule::set_approval_for_all(&ALICE, &BOB, CLASS_ID, true);
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::ApprovalForAll {
+			owner: ALICE,
+			operator: BOB,
+			class_id: CLASS_ID,
+			approved: true,
+		}));
+		assert!(NFTModule::is_approved_or_owner(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_ok!(NFTModule::approve(&BOB, Some(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_ok!(NFTModule::burn_from(&BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::owner(&CLASS_ID, &TOKEN_ID), None);
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), None);
+
+		NFTModule::set_approval_for_all(&ALICE, &BOB, CLASS_ID, false);
+		assert!(!NFTModule::operator_approvals((CLASS_ID, ALICE), BOB));
+	});
+}
+
+#[test]
+fn token_uri_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			b"ipfs://base".to_vec(),
+			Properties(ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(&class_id_account(), 10_000));
+
+		let mut attributes: Attributes = BTreeMap::new();
+		attributes.insert(b"tokenURI".to_vec(), b"u1".to_vec());
+		assert_ok!(NFTModule::mint_by_class_owner(
+			&ALICE,
+			&BOB,
+			CLASS_ID,
+			Default::default(),
+			Default::default(),
+			1
+		));
+		assert_ok!(NFTModule::mint_by_class_owner(
+			&ALICE,
+			&BOB,
+			CLASS_ID,
+			b"ipfs://token".to_vec(),
+			Default::default(),
+			1
+		));
+		assert_ok!(NFTModule::mint_by_class_owner(
+			&ALICE,
+			&BOB,
+			CLASS_ID,
+			b"ipfs://token".to_vec(),
+			attributes,
+			1
+		));
+
+		assert_eq!(NFTModule::token_uri(CLASS_ID, 0), Some(b"ipfs://base/0".to_vec()));
+		assert_eq!(NFTModule::token_uri(CLASS_ID, 1), Some(b"ipfs://token".to_vec()));
+		assert_eq!(NFTModule::token_uri(CLASS_ID, 2), Some(b"u1".to_vec()));
+		assert_eq!(NFTModule::token_uri(CLASS_ID, 3), None);
+	});
+}
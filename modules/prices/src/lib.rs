@@ -31,7 +31,7 @@
 
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
-use module_support::{DEXManager, Erc20InfoMapping, ExchangeRateProvider, LockablePrice, Price, PriceProvider, Rate};
+use module_support::{DEXManager, Erc20InfoMapping, LiquidStakingPools, LockablePrice, Price, PriceProvider, Rate};
 use orml_traits::{DataFeeder, DataProvider, GetByKey, MultiCurrency};
 use primitives::{Balance, CurrencyId, Lease};
 use sp_core::U256;
@@ -71,16 +71,12 @@ pub mod module {
 		#[pallet::constant]
 		type GetStakingCurrencyId: Get<CurrencyId>;
 
-		/// The liquid currency id, it should be LDOT in Acala.
-		#[pallet::constant]
-		type GetLiquidCurrencyId: Get<CurrencyId>;
-
 		/// The origin which may lock and unlock prices feed to system.
 		type LockOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-		/// The provider of the exchange rate between liquid currency and
-		/// staking currency.
-		type LiquidStakingExchangeRateProvider: ExchangeRateProvider;
+		/// The liquid staking pools which provide the staking currency and the exchange rate for
+		/// liquid currencies.
+		type LiquidStakingPools: LiquidStakingPools;
 
 		/// DEX provide liquidity info.
 		type DEX: DEXManager<Self::AccountId, Balance, CurrencyId>;
@@ -189,10 +185,11 @@ impl<T: Config> Pallet<T> {
 		let maybe_price = if currency_id == T::GetStableCurrencyId::get() {
 			// if is stable currency, use fixed price
 			Some(T::StableCurrencyFixedPrice::get())
-		} else if currency_id == T::GetLiquidCurrencyId::get() {
-			// directly return real-time the multiple of the price of StakingCurrencyId and the exchange rate
-			return Self::access_price(T::GetStakingCurrencyId::get())
-				.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate()));
+		} else if let Some((staking_currency_id, exchange_rate)) =
+			T::LiquidStakingPools::get_staking_currency_and_exchange_rate(currency_id)
+		{
+			// directly return real-time the multiple of the price of staking currency and the exchange rate
+			return Self::access_price(staking_currency_id).and_then(|n| n.checked_mul(&exchange_rate));
 		} else if let CurrencyId::LiquidCrowdloan(lease) = currency_id {
 			// Note: For LiquidCrowdloan, The reliable market price may not be available in the initial stage,
 			// the system simply discounts the price of StakingCurrency according to the StakingRewardRate and
@@ -45,6 +45,7 @@ pub const TAI: CurrencyId = CurrencyId::Token(TokenSymbol::TAI);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
 pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
 pub const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+pub const LKSM: CurrencyId = CurrencyId::Token(TokenSymbol::LKSM);
 pub const TAIKSM: CurrencyId = CurrencyId::StableAssetPoolToken(0);
 pub const LP_AUSD_DOT: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::AUSD), DexShare::Token(TokenSymbol::DOT));
@@ -121,13 +122,19 @@ impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
 	}
 }
 
-pub struct MockLiquidStakingExchangeProvider;
-impl ExchangeRateProvider for MockLiquidStakingExchangeProvider {
-	fn get_exchange_rate() -> ExchangeRate {
-		if CHANGED.with(|v| *v.borrow_mut()) {
-			ExchangeRate::saturating_from_rational(3, 5)
-		} else {
-			ExchangeRate::saturating_from_rational(1, 2)
+pub struct MockLiquidStakingPools;
+impl LiquidStakingPools for MockLiquidStakingPools {
+	fn get_staking_currency_and_exchange_rate(liquid_currency_id: CurrencyId) -> Option<(CurrencyId, ExchangeRate)> {
+		match liquid_currency_id {
+			LDOT => {
+				if CHANGED.with(|v| *v.borrow_mut()) {
+					Some((DOT, ExchangeRate::saturating_from_rational(3, 5)))
+				} else {
+					Some((DOT, ExchangeRate::saturating_from_rational(1, 2)))
+				}
+			}
+			LKSM => Some((KSM, ExchangeRate::saturating_from_rational(1, 4))),
+			_ => None,
 		}
 	}
 }
@@ -247,7 +254,6 @@ ord_parameter_types! {
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
-	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub StableCurrencyFixedPrice: Price = Price::one();
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
 	pub RewardRatePerRelaychainBlock: Rate = Rate::saturating_from_rational(1, 1000);
@@ -259,9 +265,8 @@ impl Config for Runtime {
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
-	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
-	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
+	type LiquidStakingPools = MockLiquidStakingPools;
 	type DEX = MockDEX;
 	type Currency = Tokens;
 	type Erc20InfoMapping = MockErc20InfoMapping;
@@ -185,6 +185,28 @@ fn access_price_of_liquid_currency() {
 	});
 }
 
+#[test]
+fn access_price_of_liquid_currency_of_multiple_pools() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(PricesModule::access_price(KSM), None);
+		assert_eq!(PricesModule::access_price(LKSM), None);
+
+		mock_oracle_update();
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(600000000u128))
+		); // dot_price * 3/5
+		assert_eq!(
+			PricesModule::access_price(KSM),
+			Some(Price::saturating_from_integer(200000000u128))
+		); // 200 USD, right shift the decimal point (18-12) places
+		assert_eq!(
+			PricesModule::access_price(LKSM),
+			Some(Price::saturating_from_integer(50000000u128))
+		); // ksm_price * 1/4
+	});
+}
+
 #[test]
 fn access_price_of_dex_share_currency() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -113,6 +113,18 @@ where
 		swap_path: &[AggregatedSwapPath<CurrencyId>],
 		limit: SwapLimit<Balance>,
 	) -> Result<(Balance, Balance), DispatchError>;
+
+	fn get_swap_amount_by_path(swap_path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		let aggregated_swap_path = AggregatedSwapPath::Dex(swap_path.to_vec());
+		Self::get_swap_amount_by_aggregated_path(&[aggregated_swap_path], limit)
+	}
+
+	fn get_swap_amount_by_aggregated_path(
+		_swap_path: &[AggregatedSwapPath<CurrencyId>],
+		_limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		None
+	}
 }
 
 #[derive(Eq, PartialEq, RuntimeDebug)]
@@ -177,6 +189,10 @@ where
 		<Dex as DEXManager<AccountId, Balance, CurrencyId>>::swap_with_specific_path(who, swap_path, limit)
 	}
 
+	fn get_swap_amount_by_path(swap_path: &[CurrencyId], limit: SwapLimit<Balance>) -> Option<(Balance, Balance)> {
+		<Dex as DEXManager<AccountId, Balance, CurrencyId>>::get_swap_amount(swap_path, limit)
+	}
+
 	// Dex not support aggregated swap.
 	fn swap_by_aggregated_path(
 		_who: &AccountId,
@@ -16,13 +16,14 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use frame_support::pallet_prelude::Weight;
 use parity_scale_codec::{Decode, Encode};
 use primitives::currency::AssetIds;
 use primitives::{
 	evm::{CallInfo, EvmAddress},
 	Balance, CurrencyId,
 };
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize},
 	DispatchError, DispatchResult, RuntimeDebug,
@@ -104,6 +105,17 @@ pub trait EVMBridge<AccountId, Balance> {
 	fn balance_of(context: InvokeContext, address: EvmAddress) -> Result<Balance, DispatchError>;
 	/// Execute ERC20.transfer(address, uint256) to transfer value to `to`
 	fn transfer(context: InvokeContext, to: EvmAddress, value: Balance) -> DispatchResult;
+	/// Execute ERC20.allowance(address, address) to read the amount `spender` is allowed to
+	/// transfer from `owner`
+	fn allowance(context: InvokeContext, owner: EvmAddress, spender: EvmAddress) -> Result<Balance, DispatchError>;
+	/// Execute ERC20.approve(address, uint256) to allow `spender` to transfer value from the sender
+	fn approve(context: InvokeContext, spender: EvmAddress, value: Balance) -> DispatchResult;
+	/// Execute ERC20.transferFrom(address, address, uint256) to transfer value from `from` to `to`
+	/// with the allowance of the sender
+	fn transfer_from(context: InvokeContext, from: EvmAddress, to: EvmAddress, value: Balance) -> DispatchResult;
+	/// Detect whether the contract implements `interface_id` as specified by ERC-165. Returns false if
+	/// the contract doesn't implement ERC-165.
+	fn supports_interface(context: InvokeContext, interface_id: u32) -> bool;
 	/// Get the real origin account and charge storage rent from the origin.
 	fn get_origin() -> Option<AccountId>;
 	/// Set the EVM origin
@@ -140,6 +152,18 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 	fn transfer(_context: InvokeContext, _to: EvmAddress, _value: Balance) -> DispatchResult {
 		Err(DispatchError::Other("unimplemented evm bridge"))
 	}
+	fn allowance(_context: InvokeContext, _owner: EvmAddress, _spender: EvmAddress) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+	fn approve(_context: InvokeContext, _spender: EvmAddress, _value: Balance) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+	fn transfer_from(_context: InvokeContext, _from: EvmAddress, _to: EvmAddress, _value: Balance) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+	fn supports_interface(_context: InvokeContext, _interface_id: u32) -> bool {
+		false
+	}
 	fn get_origin() -> Option<AccountId> {
 		None
 	}
@@ -205,6 +229,9 @@ pub trait EVMManager<AccountId, Balance> {
 	fn enable_account_contract_development(who: AccountId) -> DispatchResult;
 	/// Disable developer mode
 	fn disable_account_contract_development(who: AccountId) -> DispatchResult;
+	/// Query the pending code upgrade of a contract, returns the new code hash and the block
+	/// number from which it can be applied.
+	fn query_contract_upgrade(contract: H160) -> Option<(H256, u64)>;
 }
 
 /// An abstraction of EVMAccountsManager
@@ -238,6 +265,33 @@ pub trait AddressMapping<AccountId> {
 	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool;
 }
 
+/// The EVM state held by an `EvmAddress`.
+pub trait EvmAddressState<AccountId> {
+	/// Returns true if a contract is deployed at the address.
+	fn is_contract(address: &EvmAddress) -> bool;
+	/// Returns true if the address holds a balance of any registered ERC20 token.
+	fn has_erc20_balance(address: &EvmAddress) -> bool;
+	/// The maximum weight of `is_contract` and `has_erc20_balance`.
+	fn weight() -> Weight;
+	/// Moves the contract storage deposits reserved by `from` to `to`.
+	fn transfer_storage_deposit(from: &AccountId, to: &AccountId) -> DispatchResult;
+}
+
+impl<AccountId> EvmAddressState<AccountId> for () {
+	fn is_contract(_address: &EvmAddress) -> bool {
+		false
+	}
+	fn has_erc20_balance(_address: &EvmAddress) -> bool {
+		false
+	}
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+	fn transfer_storage_deposit(_from: &AccountId, _to: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
 /// A mapping between AssetId and AssetMetadata.
 pub trait AssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata> {
 	/// Returns the AssetMetadata associated with a given `AssetIds`.
@@ -296,6 +350,16 @@ impl Erc20InfoMapping for () {
 	}
 }
 
+/// ERC-165 interface identifiers.
+pub mod interface_id {
+	/// `supportsInterface(bytes4)`
+	pub const ERC165: u32 = 0x01ffc9a7;
+	/// The invalid interface identifier which ERC-165 contracts must not support.
+	pub const INVALID: u32 = 0xffffffff;
+	/// The ERC-20 interface excluding the optional metadata methods.
+	pub const ERC20: u32 = 0x36372b07;
+}
+
 pub mod limits {
 	pub struct Limit {
 		pub gas: u64,
@@ -317,6 +381,24 @@ pub mod limits {
 		pub const TOTAL_SUPPLY: Limit = Limit::new(100_000, 0);
 		pub const BALANCE_OF: Limit = Limit::new(100_000, 0);
 		pub const TRANSFER: Limit = Limit::new(200_000, 960);
+		pub const ALLOWANCE: Limit = Limit::new(100_000, 0);
+		pub const APPROVE: Limit = Limit::new(200_000, 960);
+		pub const TRANSFER_FROM: Limit = Limit::new(200_000, 960);
+		// ERC-165 requires `supportsInterface` to use less than 30_000 gas.
+		pub const SUPPORTS_INTERFACE: Limit = Limit::new(30_000, 0);
+	}
+
+	pub mod erc1271 {
+		use super::*;
+
+		// `isValidSignature` runs in the validation of the signed extrinsics before the fee is paid.
+		pub const IS_VALID_SIGNATURE: Limit = Limit::new(30_000, 0);
+	}
+
+	pub mod erc721 {
+		use super::*;
+
+		pub const ON_ERC721_RECEIVED: Limit = Limit::new(200_000, 1_000);
 	}
 
 	pub mod liquidation {
@@ -17,7 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ExchangeRate, Rate};
-use sp_runtime::DispatchResult;
+use primitives::{CurrencyId, EraIndex};
+use sp_runtime::{DispatchError, DispatchResult};
 use xcm::v3::prelude::*;
 
 pub trait HomaSubAccountXcm<AccountId, Balance> {
@@ -41,6 +42,9 @@ pub trait HomaManager<AccountId, Balance> {
 	fn mint(who: AccountId, amount: Balance) -> DispatchResult;
 	/// Request for protocol to redeem liquid currency for staking currency
 	fn request_redeem(who: AccountId, amount: Balance, fast_match: bool) -> DispatchResult;
+	/// Redeem liquid currency instantly by fast match and DEX swap, return the staking currency
+	/// amount received
+	fn instant_redeem(who: AccountId, amount: Balance, min_redemption: Balance) -> Result<Balance, DispatchError>;
 	/// Calculates current exchange rate between staking and liquid currencies (staking : liquid)
 	fn get_exchange_rate() -> ExchangeRate;
 	/// Estimated return rate per era from liquid staking
@@ -49,4 +53,29 @@ pub trait HomaManager<AccountId, Balance> {
 	fn get_commission_rate() -> Rate;
 	/// Fee for fast matching redeem request
 	fn get_fast_match_fee() -> Rate;
+	/// Gets the exchange rate recorded when the era was bumped
+	fn get_exchange_rate_at(era: EraIndex) -> Option<ExchangeRate>;
+	/// Annualized reward rate realised by the exchange rate over the latest eras
+	fn get_realised_apy(eras: EraIndex) -> Option<Rate>;
+	/// Estimated exchange rate after the next era is bumped
+	fn get_projected_exchange_rate() -> ExchangeRate;
+}
+
+/// Liquid staking pools that back liquid currencies by staking currencies.
+pub trait LiquidStakingPools {
+	/// Gets the staking currency and the exchange rate (staking : liquid) of the pool whose liquid
+	/// currency is `liquid_currency_id`, return None if there is no such pool.
+	fn get_staking_currency_and_exchange_rate(liquid_currency_id: CurrencyId) -> Option<(CurrencyId, ExchangeRate)>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl LiquidStakingPools for Tuple {
+	fn get_staking_currency_and_exchange_rate(liquid_currency_id: CurrencyId) -> Option<(CurrencyId, ExchangeRate)> {
+		for_tuples!( #(
+			if let Some(result) = Tuple::get_staking_currency_and_exchange_rate(liquid_currency_id) {
+				return Some(result);
+			}
+		)* );
+		None
+	}
 }
@@ -18,7 +18,7 @@
 
 use crate::Rate;
 use parity_scale_codec::{Decode, Encode};
-use primitives::CurrencyId;
+use primitives::{Balance, CurrencyId};
 use scale_info::TypeInfo;
 use sp_runtime::{DispatchResult, RuntimeDebug};
 use sp_std::prelude::*;
@@ -66,3 +66,21 @@ impl<AccountId, CurrencyId, Balance> DEXIncentives<AccountId, CurrencyId, Balanc
 		Ok(())
 	}
 }
+
+/// The votes to direct the incentive emissions among pools.
+pub trait EmissionVotes {
+	/// Gets the vote weight of the pool
+	fn get_pool_vote_weight(pool_id: &PoolId) -> Balance;
+	/// Gets the total vote weight of all pools
+	fn get_total_vote_weight() -> Balance;
+}
+
+impl EmissionVotes for () {
+	fn get_pool_vote_weight(_: &PoolId) -> Balance {
+		Default::default()
+	}
+
+	fn get_total_vote_weight() -> Balance {
+		Default::default()
+	}
+}
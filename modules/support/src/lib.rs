@@ -22,7 +22,7 @@
 #![allow(clippy::type_complexity)]
 
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
-use primitives::{task::TaskResult, Balance, CurrencyId, Multiplier, Nonce, ReserveIdentifier};
+use primitives::{currency::AssetStatus, task::TaskResult, Balance, CurrencyId, Multiplier, Nonce, ReserveIdentifier};
 use sp_runtime::{
 	traits::CheckedDiv, transaction_validity::TransactionValidityError, DispatchError, DispatchResult, FixedU128,
 };
@@ -104,6 +104,12 @@ pub trait DEXPriceProvider<CurrencyId> {
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<ExchangeRate>;
 }
 
+impl<CurrencyId> DEXPriceProvider<CurrencyId> for () {
+	fn get_relative_price(_base: CurrencyId, _quote: CurrencyId) -> Option<ExchangeRate> {
+		None
+	}
+}
+
 pub trait LockablePrice<CurrencyId> {
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult;
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
@@ -202,3 +208,41 @@ impl<AccountId> LiquidateCollateral<AccountId> for Tuple {
 pub trait BuyWeightRate {
 	fn calculate_rate(location: MultiLocation) -> Option<Ratio>;
 }
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl BuyWeightRate for Tuple {
+	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
+		for_tuples!( #(
+			if let Some(rate) = Tuple::calculate_rate(location) {
+				return Some(rate);
+			}
+		)* );
+		None
+	}
+}
+
+/// Query the lifecycle status of assets.
+pub trait AssetStatusProvider<CurrencyId> {
+	/// Returns the status of `currency_id`, assets without a status are `Active`.
+	fn asset_status(currency_id: CurrencyId) -> AssetStatus;
+
+	fn is_active(currency_id: CurrencyId) -> bool {
+		Self::asset_status(currency_id) == AssetStatus::Active
+	}
+
+	fn is_frozen(currency_id: CurrencyId) -> bool {
+		Self::asset_status(currency_id) == AssetStatus::Frozen
+	}
+
+	/// Returns false if `currency_id` is pending verification by governance, which can't be used
+	/// to pay fees or as collateral.
+	fn is_verified(_currency_id: CurrencyId) -> bool {
+		true
+	}
+}
+
+impl<CurrencyId> AssetStatusProvider<CurrencyId> for () {
+	fn asset_status(_currency_id: CurrencyId) -> AssetStatus {
+		AssetStatus::Active
+	}
+}
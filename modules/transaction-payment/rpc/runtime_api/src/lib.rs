@@ -0,0 +1,47 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use module_support::AggregatedSwapPath;
+use primitives::{
+	transaction_payment::{FeeCurrency, FeePoolInfo, FeeQuote},
+	Balance, CurrencyId,
+};
+use sp_runtime::codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TransactionPaymentRuntimeRPCApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// The exchange rate and the remaining capacity of all charge fee pools.
+		fn fee_pools() -> Vec<FeePoolInfo>;
+
+		/// Quote the fee of the extrinsic with encoded length `len` signed by `who` with `tip`
+		/// paid in `fee_currency`.
+		fn quote_fee(
+			who: AccountId,
+			uxt: Block::Extrinsic,
+			len: u32,
+			tip: Balance,
+			fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+		) -> Option<FeeQuote>;
+	}
+}
@@ -40,11 +40,17 @@ use frame_support::{
 	BoundedVec, PalletId,
 };
 use frame_system::pallet_prelude::*;
-use module_support::{AggregatedSwapPath, BuyWeightRate, PriceProvider, Ratio, Swap, SwapLimit, TransactionPayment};
+use module_support::{
+	AggregatedSwapPath, AssetStatusProvider, BuyWeightRate, DEXPriceProvider, PriceProvider, Ratio, Swap, SwapLimit,
+	TransactionPayment,
+};
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
-use primitives::{Balance, CurrencyId, Multiplier, ReserveIdentifier};
+use primitives::{
+	transaction_payment::{FeeCurrency, FeeMechanism, FeePoolInfo, FeeQuote},
+	Balance, CurrencyId, Multiplier, ReserveIdentifier,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
@@ -268,6 +274,7 @@ pub mod module {
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::TransactionPayment;
 	pub const DEPOSIT_ID: ReserveIdentifier = ReserveIdentifier::TransactionPaymentDeposit;
+	pub const SPONSOR_ID: ReserveIdentifier = ReserveIdentifier::TransactionPaymentSponsor;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -362,6 +369,18 @@ pub mod module {
 		/// The price source to provider external market price.
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// The DEX price source to refresh the exchange rate of charge fee pools, fallback to
+		/// `PriceSource` if DEX has no price.
+		type DEXPriceSource: DEXPriceProvider<CurrencyId>;
+
+		/// The period to refresh the exchange rate of charge fee pools by market price.
+		#[pallet::constant]
+		type RateRefreshPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum deviation of the exchange rate of charge fee pools in one refresh.
+		#[pallet::constant]
+		type MaxRateDeviation: Get<Ratio>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 
@@ -387,6 +406,9 @@ pub mod module {
 
 		/// The origin which change swap balance threshold or enable charge fee pool.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The verification status of the assets, unverified assets can't be used to pay fees.
+		type AssetStatusProvider: AssetStatusProvider<CurrencyId>;
 	}
 
 	#[pallet::type_value]
@@ -408,6 +430,16 @@ pub mod module {
 		DexNotAvailable,
 		/// Charge fee pool is already exist
 		ChargeFeePoolAlreadyExisted,
+		/// The call is not sponsored by the sponsor
+		CallNotSponsored,
+		/// The user is not sponsored by the sponsor
+		UserNotSponsored,
+		/// The fee exceeds the sponsor quota of the user
+		SponsorQuotaExceeded,
+		/// The sponsor budget is not enough to pay the fee
+		SponsorBudgetExhausted,
+		/// The token is pending verification
+		UnverifiedToken,
 	}
 
 	#[pallet::event]
@@ -436,6 +468,13 @@ pub mod module {
 			foreign_amount: Balance,
 			native_amount: Balance,
 		},
+		/// The exchange rate of the charge fee pool is refreshed by market price
+		ChargeFeePoolRateRefreshed {
+			currency_id: CurrencyId,
+			old_exchange_rate: Ratio,
+			market_exchange_rate: Ratio,
+			new_exchange_rate: Ratio,
+		},
 		/// A transaction `actual_fee`, of which `actual_tip` was added to the minimum inclusion
 		/// fee, has been paid by `who`. `actual_surplus` indicate extra amount when paid by none
 		/// native token.
@@ -445,6 +484,36 @@ pub mod module {
 			actual_tip: PalletBalanceOf<T>,
 			actual_surplus: PalletBalanceOf<T>,
 		},
+		/// The sponsor budget is funded.
+		SponsorBudgetFunded {
+			sponsor: T::AccountId,
+			amount: PalletBalanceOf<T>,
+		},
+		/// The sponsor budget is withdrawn.
+		SponsorBudgetWithdrawn {
+			sponsor: T::AccountId,
+			amount: PalletBalanceOf<T>,
+		},
+		/// The sponsor updated a sponsored call, `call_index` is `None` means all the calls of
+		/// the pallet.
+		SponsoredCallUpdated {
+			sponsor: T::AccountId,
+			pallet_index: u8,
+			call_index: Option<u8>,
+			allowed: bool,
+		},
+		/// The sponsor updated the quota of a user, `None` means the user is not sponsored.
+		SponsorQuotaUpdated {
+			sponsor: T::AccountId,
+			user: T::AccountId,
+			quota: Option<PalletBalanceOf<T>>,
+		},
+		/// A transaction `actual_fee` of `who` has been paid by `sponsor`.
+		TransactionFeeSponsored {
+			sponsor: T::AccountId,
+			who: T::AccountId,
+			actual_fee: PalletBalanceOf<T>,
+		},
 	}
 
 	/// The next fee multiplier.
@@ -494,6 +563,36 @@ pub mod module {
 	#[pallet::getter(fn swap_balance_threshold)]
 	pub type SwapBalanceThreshold<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
 
+	/// The block number of the last exchange rate refresh of the charge fee pool.
+	///
+	/// LastRateRefresh: map CurrencyId => BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn last_rate_refresh)]
+	pub type LastRateRefresh<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, BlockNumberFor<T>, ValueQuery>;
+
+	/// The raw key of TokenExchangeRate where the next maintenance of charge fee pools starts
+	/// from.
+	///
+	/// FeePoolMaintenanceCursor: Vec<u8>
+	#[pallet::storage]
+	pub type FeePoolMaintenanceCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// The calls the sponsor pays the fee for, keyed by pallet index and call index. A `None` call
+	/// index means all the calls of the pallet.
+	///
+	/// SponsoredCalls: double_map AccountId, (u8, Option<u8>) => Option<()>
+	#[pallet::storage]
+	pub type SponsoredCalls<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, (u8, Option<u8>), (), OptionQuery>;
+
+	/// The remaining fee the sponsor pays for the user.
+	///
+	/// SponsorQuotas: double_map sponsor: AccountId, user: AccountId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_quotas)]
+	pub type SponsorQuotas<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, PalletBalanceOf<T>, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -510,6 +609,10 @@ pub mod module {
 			});
 		}
 
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::maintain_fee_pools(now, remaining_weight)
+		}
+
 		#[cfg(feature = "std")]
 		fn integrity_test() {
 			// given weight == u64, we build multipliers from `diff` of two weight values,
@@ -576,6 +679,7 @@ pub mod module {
 						&& path.last() == Some(&T::NativeCurrencyId::get()),
 					Error::<T>::InvalidSwapPath
 				);
+				ensure!(Self::is_verified_path(&path), Error::<T>::UnverifiedToken);
 				T::Currency::ensure_reserved_named(&DEPOSIT_ID, &who, T::AlternativeFeeSwapDeposit::get())?;
 				AlternativeFeeSwapPath::<T>::insert(&who, &path);
 			} else {
@@ -656,6 +760,91 @@ pub mod module {
 			ensure_signed(origin.clone())?;
 			call.dispatch(origin)
 		}
+
+		/// Dapp wrap call, and the tx fee is paid by the budget of `sponsor`. The sponsor must
+		/// sponsor both the user and the wrapped call. The tip is not sponsored, so the transaction
+		/// must not have a tip.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(T::WeightInfo::with_fee_sponsor().saturating_add(dispatch_info.weight), dispatch_info.class,)
+		})]
+		pub fn with_fee_sponsor(
+			origin: OriginFor<T>,
+			_sponsor: T::AccountId,
+			call: Box<CallOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin.clone())?;
+			call.dispatch(origin)
+		}
+
+		/// Reserve `amount` of native token into the sponsor budget of the origin.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::fund_sponsor_budget())]
+		pub fn fund_sponsor_budget(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: PalletBalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			T::Currency::reserve_named(&SPONSOR_ID, &sponsor, amount)?;
+			Self::deposit_event(Event::SponsorBudgetFunded { sponsor, amount });
+			Ok(())
+		}
+
+		/// Unreserve at most `amount` of native token from the sponsor budget of the origin.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_sponsor_budget())]
+		pub fn withdraw_sponsor_budget(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: PalletBalanceOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let remaining = T::Currency::unreserve_named(&SPONSOR_ID, &sponsor, amount);
+			Self::deposit_event(Event::SponsorBudgetWithdrawn {
+				sponsor,
+				amount: amount.saturating_sub(remaining),
+			});
+			Ok(())
+		}
+
+		/// Allow or disallow the origin to sponsor a call. `call_index` is `None` means all the
+		/// calls of the pallet.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsored_call())]
+		pub fn set_sponsored_call(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			call_index: Option<u8>,
+			allowed: bool,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			if allowed {
+				SponsoredCalls::<T>::insert(&sponsor, (pallet_index, call_index), ());
+			} else {
+				SponsoredCalls::<T>::remove(&sponsor, (pallet_index, call_index));
+			}
+			Self::deposit_event(Event::SponsoredCallUpdated {
+				sponsor,
+				pallet_index,
+				call_index,
+				allowed,
+			});
+			Ok(())
+		}
+
+		/// Set the fee quota the origin sponsors for `user`, `None` stops sponsoring the user.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsor_quota())]
+		pub fn set_sponsor_quota(
+			origin: OriginFor<T>,
+			user: T::AccountId,
+			quota: Option<PalletBalanceOf<T>>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			SponsorQuotas::<T>::set(&sponsor, &user, quota);
+			Self::deposit_event(Event::SponsorQuotaUpdated { sponsor, user, quota });
+			Ok(())
+		}
 	}
 }
 
@@ -820,6 +1009,201 @@ where
 		T::WeightToFee::weight_to_fee(&capped_weight)
 	}
 
+	/// The amount of `currency_id` that `with_fee_currency` will take to pay the native `fee`,
+	/// including the fee surplus. Return `None` if the fee can't be paid in `currency_id`.
+	pub fn fee_in_currency(currency_id: CurrencyId, fee: PalletBalanceOf<T>) -> Option<Balance> {
+		Self::quote_swap(FeeCurrency::Currency(currency_id), fee, fee).map(|quote| quote.amount)
+	}
+
+	/// Quote the fee of the extrinsic signed by `who` with `tip` paid in `fee_currency`, it's what
+	/// the payment of the extrinsic or of `with_fee_*` will take from `who`.
+	pub fn quote_fee<Extrinsic: GetDispatchInfo>(
+		who: &T::AccountId,
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		tip: PalletBalanceOf<T>,
+		fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+	) -> Option<FeeQuote> {
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let fee = Self::compute_fee(len, &dispatch_info, tip);
+		let reason = if tip.is_zero() {
+			WithdrawReasons::TRANSACTION_PAYMENT
+		} else {
+			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
+		};
+		Self::quote_fee_in_currency(who, fee_currency, fee, reason)
+	}
+
+	/// Quote the native `fee` of `who` paid in `fee_currency`, including the native token swapped
+	/// to keep `who` alive. Return `None` if the fee can't be paid.
+	pub fn quote_fee_in_currency(
+		who: &T::AccountId,
+		fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+	) -> Option<FeeQuote> {
+		match fee_currency {
+			FeeCurrency::Auto => Self::quote_native_then_alternative_or_default(who, fee, reason),
+			fee_currency => {
+				let amount = Self::check_native_is_not_enough(who, fee, reason).unwrap_or(fee);
+				Self::quote_swap(fee_currency, fee, amount)
+			}
+		}
+	}
+
+	/// Quote the native `amount` swapped from `fee_currency` to pay the `fee`, the part of
+	/// `amount` exceed `fee` is the top up.
+	fn quote_swap(
+		fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+		fee: PalletBalanceOf<T>,
+		amount: Balance,
+	) -> Option<FeeQuote> {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let top_up = amount.saturating_sub(fee);
+		let custom_fee_surplus = T::CustomFeeSurplus::get().mul_ceil(amount);
+		let is_valid_swap_path = |path: &[CurrencyId]| {
+			path.len() > 1 && path.first() != Some(&native_currency_id) && path.last() == Some(&native_currency_id)
+		};
+		let dex_quote = |currency_id: CurrencyId, (supply_amount, _): (Balance, Balance)| FeeQuote {
+			currency_id,
+			fee,
+			top_up,
+			surplus: custom_fee_surplus,
+			amount: supply_amount,
+			mechanism: FeeMechanism::Dex,
+		};
+		let limit = SwapLimit::ExactTarget(Balance::MAX, amount.saturating_add(custom_fee_surplus));
+
+		match fee_currency {
+			FeeCurrency::Auto => None,
+			FeeCurrency::Currency(currency_id) if currency_id == native_currency_id => Some(FeeQuote {
+				currency_id,
+				fee,
+				top_up: 0,
+				surplus: 0,
+				amount: fee,
+				mechanism: FeeMechanism::Native,
+			}),
+			FeeCurrency::Currency(currency_id) => {
+				if !T::AssetStatusProvider::is_verified(currency_id) {
+					return None;
+				}
+				if TokenExchangeRate::<T>::contains_key(currency_id) {
+					let surplus = if T::DefaultFeeTokens::get().contains(&currency_id) {
+						T::AlternativeFeeSurplus::get().mul_ceil(amount)
+					} else {
+						custom_fee_surplus
+					};
+					Self::quote_from_pool(currency_id, fee, amount, surplus)
+				} else {
+					T::Swap::get_swap_amount(currency_id, native_currency_id, limit)
+						.map(|swap_amount| dex_quote(currency_id, swap_amount))
+				}
+			}
+			FeeCurrency::SwapPath(fee_swap_path) => {
+				if !is_valid_swap_path(&fee_swap_path) || !Self::is_verified_path(&fee_swap_path) {
+					return None;
+				}
+				T::Swap::get_swap_amount_by_path(&fee_swap_path, limit)
+					.map(|swap_amount| dex_quote(fee_swap_path[0], swap_amount))
+			}
+			FeeCurrency::AggregatedPath(currency_id, fee_aggregated_path) => {
+				let last_should_be_dex = match fee_aggregated_path.last() {
+					Some(AggregatedSwapPath::<CurrencyId>::Dex(fee_swap_path)) => is_valid_swap_path(fee_swap_path),
+					_ => false,
+				};
+				if !last_should_be_dex || !Self::is_verified_aggregated_path(&fee_aggregated_path) {
+					return None;
+				}
+				T::Swap::get_swap_amount_by_aggregated_path(&fee_aggregated_path, limit)
+					.map(|swap_amount| dex_quote(currency_id, swap_amount))
+			}
+		}
+	}
+
+	/// Quote the native `amount` exchanged from the charge fee pool of `currency_id` with the
+	/// `surplus`, return `None` if the pool is not enabled.
+	fn quote_from_pool(
+		currency_id: CurrencyId,
+		fee: PalletBalanceOf<T>,
+		amount: Balance,
+		surplus: Balance,
+	) -> Option<FeeQuote> {
+		if !T::AssetStatusProvider::is_verified(currency_id) {
+			return None;
+		}
+		TokenExchangeRate::<T>::get(currency_id).map(|rate| FeeQuote {
+			currency_id,
+			fee,
+			top_up: amount.saturating_sub(fee),
+			surplus,
+			amount: rate.saturating_mul_int(amount.saturating_add(surplus)),
+			mechanism: FeeMechanism::FeePool,
+		})
+	}
+
+	/// Quote the fee of the call not wrapped by `with_fee_*` in the order of
+	/// `native_then_alternative_or_default`, skip the currency which `who` can't afford.
+	fn quote_native_then_alternative_or_default(
+		who: &T::AccountId,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+	) -> Option<FeeQuote> {
+		let amount = match Self::check_native_is_not_enough(who, fee, reason) {
+			Some(amount) => amount,
+			None => return Self::quote_swap(FeeCurrency::Currency(T::NativeCurrencyId::get()), fee, fee),
+		};
+		let fee_surplus = T::AlternativeFeeSurplus::get().mul_ceil(fee);
+		let custom_fee_surplus = T::CustomFeeSurplus::get().mul_ceil(fee);
+		let affordable = |quote: &FeeQuote| T::MultiCurrency::free_balance(quote.currency_id, who) >= quote.amount;
+
+		if let Some(path) = AlternativeFeeSwapPath::<T>::get(who).filter(|path| Self::is_verified_path(path)) {
+			let quote = path.first().and_then(|currency_id| {
+				T::Swap::get_swap_amount_by_path(
+					&path,
+					SwapLimit::ExactTarget(Balance::MAX, amount.saturating_add(fee_surplus)),
+				)
+				.map(|(supply_amount, _)| FeeQuote {
+					currency_id: *currency_id,
+					fee,
+					top_up: amount.saturating_sub(fee),
+					surplus: fee_surplus,
+					amount: supply_amount,
+					mechanism: FeeMechanism::Dex,
+				})
+			});
+			if let Some(quote) = quote.filter(affordable) {
+				return Some(quote);
+			}
+		}
+
+		T::DefaultFeeTokens::get()
+			.into_iter()
+			.find_map(|currency_id| Self::quote_from_pool(currency_id, fee, amount, fee_surplus).filter(affordable))
+			.or_else(|| {
+				TokenExchangeRate::<T>::iter_keys()
+					.filter(|currency_id| !T::DefaultFeeTokens::get().contains(currency_id))
+					.find_map(|currency_id| {
+						Self::quote_from_pool(currency_id, fee, amount, custom_fee_surplus).filter(affordable)
+					})
+			})
+	}
+
+	/// Returns false if any currency of the swap `path` is unverified.
+	fn is_verified_path(path: &[CurrencyId]) -> bool {
+		path.iter()
+			.all(|currency_id| T::AssetStatusProvider::is_verified(*currency_id))
+	}
+
+	/// Returns false if any currency of the dex paths of the aggregated `path` is unverified. The
+	/// taiga pools are created by governance.
+	fn is_verified_aggregated_path(path: &[AggregatedSwapPath<CurrencyId>]) -> bool {
+		path.iter().all(|path| match path {
+			AggregatedSwapPath::<CurrencyId>::Dex(path) => Self::is_verified_path(path),
+			AggregatedSwapPath::<CurrencyId>::Taiga(..) => true,
+		})
+	}
+
 	/// If native asset is enough, return `None`, else return the fee amount should be swapped.
 	fn check_native_is_not_enough(
 		who: &T::AccountId,
@@ -861,6 +1245,7 @@ where
 						&& fee_swap_path.last() == Some(&T::NativeCurrencyId::get()),
 					Error::<T>::InvalidSwapPath
 				);
+				ensure!(Self::is_verified_path(fee_swap_path), Error::<T>::UnverifiedToken);
 				let fee = Self::check_native_is_not_enough(who, fee, reason).map_or_else(|| fee, |amount| amount);
 				let custom_fee_surplus = T::CustomFeeSurplus::get().mul_ceil(fee);
 				T::Swap::swap_by_path(
@@ -882,6 +1267,10 @@ where
 								&& fee_swap_path.last() == Some(&T::NativeCurrencyId::get()),
 							Error::<T>::InvalidSwapPath
 						);
+						ensure!(
+							Self::is_verified_aggregated_path(fee_aggregated_path),
+							Error::<T>::UnverifiedToken
+						);
 						let fee =
 							Self::check_native_is_not_enough(who, fee, reason).map_or_else(|| fee, |amount| amount);
 						let custom_fee_surplus = T::CustomFeeSurplus::get().mul_ceil(fee);
@@ -896,6 +1285,10 @@ where
 				}
 			}
 			Some(Call::with_fee_currency { currency_id, .. }) => {
+				ensure!(
+					T::AssetStatusProvider::is_verified(*currency_id),
+					Error::<T>::UnverifiedToken
+				);
 				let fee = Self::check_native_is_not_enough(who, fee, reason).map_or_else(|| fee, |amount| amount);
 				let alternative_fee_surplus = T::AlternativeFeeSurplus::get().mul_ceil(fee);
 				let custom_fee_surplus = T::CustomFeeSurplus::get().mul_ceil(fee);
@@ -922,6 +1315,52 @@ where
 		}
 	}
 
+	/// Charge the `fee` of `call` from the budget of `sponsor` and the quota of `who`. The sponsor
+	/// must sponsor `who` and either the pallet or the exact call.
+	fn withdraw_sponsored_fee(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		call: &CallOf<T>,
+		fee: PalletBalanceOf<T>,
+	) -> Result<NegativeImbalanceOf<T>, DispatchError> {
+		let (pallet_index, call_index) = call.using_encoded(|encoded| {
+			(
+				encoded.first().copied().unwrap_or_default(),
+				encoded.get(1).copied().unwrap_or_default(),
+			)
+		});
+		ensure!(
+			SponsoredCalls::<T>::contains_key(sponsor, (pallet_index, None::<u8>))
+				|| SponsoredCalls::<T>::contains_key(sponsor, (pallet_index, Some(call_index))),
+			Error::<T>::CallNotSponsored
+		);
+		ensure!(
+			T::Currency::reserved_balance_named(&SPONSOR_ID, sponsor) >= fee,
+			Error::<T>::SponsorBudgetExhausted
+		);
+		SponsorQuotas::<T>::try_mutate(sponsor, who, |maybe_quota| -> DispatchResult {
+			let quota = maybe_quota.as_mut().ok_or(Error::<T>::UserNotSponsored)?;
+			ensure!(*quota >= fee, Error::<T>::SponsorQuotaExceeded);
+			*quota = quota.saturating_sub(fee);
+			Ok(())
+		})?;
+
+		let (imbalance, _) = T::Currency::slash_reserved_named(&SPONSOR_ID, sponsor, fee);
+		Ok(imbalance)
+	}
+
+	/// Return the unused `refund` of a sponsored transaction back to the budget of `sponsor` and
+	/// the quota of `who`.
+	fn refund_sponsored_fee(sponsor: &T::AccountId, who: &T::AccountId, refund: PalletBalanceOf<T>) {
+		// the refund was just deposited to the sponsor, so reserve it back is expected to succeed.
+		let _ = T::Currency::reserve_named(&SPONSOR_ID, sponsor, refund);
+		SponsorQuotas::<T>::mutate(sponsor, who, |maybe_quota| {
+			if let Some(quota) = maybe_quota {
+				*quota = quota.saturating_add(refund);
+			}
+		});
+	}
+
 	/// If native is enough, do nothing, return `Ok(0)` means there are none extra surplus fee.
 	/// If native is not enough, try swap from tx fee pool or dex:
 	/// - As user can set his own `AlternativeFeeSwapPath`, this will direct swap from dex. Notice:
@@ -950,7 +1389,7 @@ where
 			let custom_fee_amount = custom_fee_surplus.saturating_add(amount);
 
 			// alter native fee swap path, swap from dex: O(1)
-			if let Some(path) = AlternativeFeeSwapPath::<T>::get(who) {
+			if let Some(path) = AlternativeFeeSwapPath::<T>::get(who).filter(|path| Self::is_verified_path(path)) {
 				if T::Swap::swap_by_path(who, &path, SwapLimit::ExactTarget(Balance::MAX, fee_amount)).is_ok() {
 					return Ok(fee_surplus);
 				}
@@ -984,49 +1423,148 @@ where
 	/// asset balance of charge fee pool is not enough, swap from dex.
 	#[transactional]
 	fn swap_from_pool_or_dex(who: &T::AccountId, amount: Balance, supply_currency_id: CurrencyId) -> DispatchResult {
+		ensure!(
+			T::AssetStatusProvider::is_verified(supply_currency_id),
+			Error::<T>::UnverifiedToken
+		);
 		let rate = TokenExchangeRate::<T>::get(supply_currency_id).ok_or(Error::<T>::InvalidRate)?;
 		let sub_account = Self::sub_account_id(supply_currency_id);
 
-		// if sub account has not enough native asset, trigger swap from dex. if `native_balance`
-		// is lt ED, it become 0 because we don't add sub account to whitelist on purpose,
-		// this means the charge fee pool is exhausted for this given token pair.
-		// we normally set the `SwapBalanceThreshold` gt ED to prevent this case.
+		if Self::replenish_pool(supply_currency_id, rate).is_err() {
+			debug_assert!(false, "Swap tx fee pool should not fail!");
+		}
+
+		// use fix rate to calculate the amount of supply asset that equal to native asset.
+		let supply_account = rate.saturating_mul_int(amount);
+		T::MultiCurrency::transfer(supply_currency_id, who, &sub_account, supply_account)?;
+		T::Currency::transfer(&sub_account, who, amount, ExistenceRequirement::KeepAlive)?;
+		Ok(())
+	}
+
+	/// If sub account has not enough native asset, trigger swap from dex, then update the exchange
+	/// rate and the pool size. If `native_balance` is lt ED, it become 0 because we don't add sub
+	/// account to whitelist on purpose, this means the charge fee pool is exhausted for this given
+	/// token pair. we normally set the `SwapBalanceThreshold` gt ED to prevent this case.
+	#[transactional]
+	fn replenish_pool(supply_currency_id: CurrencyId, rate: Ratio) -> DispatchResult {
+		let sub_account = Self::sub_account_id(supply_currency_id);
 		let native_balance = T::Currency::free_balance(&sub_account);
 		let threshold_balance = SwapBalanceThreshold::<T>::get(supply_currency_id);
 		if native_balance < threshold_balance {
 			let supply_balance = T::MultiCurrency::free_balance(supply_currency_id, &sub_account);
 			let supply_amount = supply_balance.saturating_sub(T::MultiCurrency::minimum_balance(supply_currency_id));
-			if let Ok((supply_amount, swap_native_balance)) = T::Swap::swap(
+			let (supply_amount, swap_native_balance) = T::Swap::swap(
 				&sub_account,
 				supply_currency_id,
 				T::NativeCurrencyId::get(),
 				SwapLimit::ExactSupply(supply_amount, 0),
-			) {
-				// calculate and update new rate, also update the pool size
-				let swap_exchange_rate = Ratio::saturating_from_rational(supply_amount, swap_native_balance);
-				let new_pool_size = swap_native_balance.saturating_add(native_balance);
-				let new_exchange_rate = Self::calculate_exchange_rate(supply_currency_id, swap_exchange_rate)?;
-
-				TokenExchangeRate::<T>::insert(supply_currency_id, new_exchange_rate);
-				PoolSize::<T>::insert(supply_currency_id, new_pool_size);
-				Pallet::<T>::deposit_event(Event::<T>::ChargeFeePoolSwapped {
-					sub_account: sub_account.clone(),
-					supply_currency_id,
-					old_exchange_rate: rate,
-					swap_exchange_rate,
-					new_exchange_rate,
-					new_pool_size,
-				});
-			} else {
-				debug_assert!(false, "Swap tx fee pool should not fail!");
+			)?;
+
+			// calculate and update new rate, also update the pool size
+			let swap_exchange_rate = Ratio::saturating_from_rational(supply_amount, swap_native_balance);
+			let new_pool_size = swap_native_balance.saturating_add(native_balance);
+			let new_exchange_rate = Self::calculate_exchange_rate(supply_currency_id, swap_exchange_rate)?;
+
+			TokenExchangeRate::<T>::insert(supply_currency_id, new_exchange_rate);
+			PoolSize::<T>::insert(supply_currency_id, new_pool_size);
+			Pallet::<T>::deposit_event(Event::<T>::ChargeFeePoolSwapped {
+				sub_account,
+				supply_currency_id,
+				old_exchange_rate: rate,
+				swap_exchange_rate,
+				new_exchange_rate,
+				new_pool_size,
+			});
+		}
+		Ok(())
+	}
+
+	/// Move the exchange rate of the charge fee pool towards the market price, the change is
+	/// bounded by `MaxRateDeviation` of the current rate.
+	fn refresh_exchange_rate(currency_id: CurrencyId, old_exchange_rate: Ratio) {
+		let native_currency_id = T::NativeCurrencyId::get();
+		// the exchange rate is the amount of `currency_id` for one native token.
+		let market_exchange_rate = match T::DEXPriceSource::get_relative_price(native_currency_id, currency_id)
+			.or_else(|| T::PriceSource::get_relative_price(native_currency_id, currency_id))
+		{
+			Some(rate) if !rate.is_zero() => rate,
+			_ => return,
+		};
+
+		let max_deviation = T::MaxRateDeviation::get().saturating_mul(old_exchange_rate);
+		let new_exchange_rate = market_exchange_rate.clamp(
+			old_exchange_rate.saturating_sub(max_deviation),
+			old_exchange_rate.saturating_add(max_deviation),
+		);
+		if new_exchange_rate != old_exchange_rate {
+			TokenExchangeRate::<T>::insert(currency_id, new_exchange_rate);
+			Self::deposit_event(Event::ChargeFeePoolRateRefreshed {
+				currency_id,
+				old_exchange_rate,
+				market_exchange_rate,
+				new_exchange_rate,
+			});
+		}
+	}
+
+	/// Refresh the exchange rates of charge fee pools every `RateRefreshPeriod` blocks, and
+	/// replenish the pools whose native balance is below the swap threshold. The pools are
+	/// visited in batches bounded by `remaining_weight`, the next batch starts after the last
+	/// visited pool.
+	pub(crate) fn maintain_fee_pools(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		// read and write `FeePoolMaintenanceCursor`
+		let base_weight = T::DbWeight::get().reads_writes(1, 1);
+		// read the last refresh, the pool info and the prices, write the new rate and the refresh
+		let refresh_weight = T::DbWeight::get().reads_writes(5, 2);
+		// the swap from dex is as heavy as `with_fee_path`
+		let replenish_weight = <T as Config>::WeightInfo::with_fee_path();
+		let weight_per_pool = refresh_weight.saturating_add(replenish_weight);
+		if remaining_weight.any_lt(base_weight.saturating_add(weight_per_pool)) {
+			return Weight::zero();
+		}
+
+		let mut iterator = match FeePoolMaintenanceCursor::<T>::take() {
+			Some(key) => TokenExchangeRate::<T>::iter_from(key),
+			None => TokenExchangeRate::<T>::iter(),
+		};
+
+		let mut used_weight = base_weight;
+		loop {
+			// the pools not visited are maintained in the next `on_idle`.
+			if remaining_weight.any_lt(used_weight.saturating_add(weight_per_pool)) {
+				FeePoolMaintenanceCursor::<T>::put(iterator.last_raw_key());
+				break;
+			}
+
+			match iterator.next() {
+				Some((currency_id, rate)) => {
+					used_weight = used_weight.saturating_add(weight_per_pool);
+
+					if now.saturating_sub(LastRateRefresh::<T>::get(currency_id)) >= T::RateRefreshPeriod::get() {
+						Self::refresh_exchange_rate(currency_id, rate);
+						LastRateRefresh::<T>::insert(currency_id, now);
+					}
+					let rate = TokenExchangeRate::<T>::get(currency_id).unwrap_or(rate);
+					// the pool will be replenished again when charging fee if the swap failed.
+					let _ = Self::replenish_pool(currency_id, rate);
+				}
+				None => break,
 			}
 		}
+		used_weight
+	}
 
-		// use fix rate to calculate the amount of supply asset that equal to native asset.
-		let supply_account = rate.saturating_mul_int(amount);
-		T::MultiCurrency::transfer(supply_currency_id, who, &sub_account, supply_account)?;
-		T::Currency::transfer(&sub_account, who, amount, ExistenceRequirement::KeepAlive)?;
-		Ok(())
+	/// The exchange rate and the remaining native token of all charge fee pools.
+	pub fn fee_pools() -> Vec<FeePoolInfo> {
+		TokenExchangeRate::<T>::iter()
+			.map(|(currency_id, exchange_rate)| FeePoolInfo {
+				currency_id,
+				exchange_rate,
+				native_balance: T::Currency::free_balance(&Self::sub_account_id(currency_id)),
+				pool_size: PoolSize::<T>::get(currency_id),
+				swap_threshold: SwapBalanceThreshold::<T>::get(currency_id),
+			})
+			.collect()
 	}
 
 	/// The sub account derivated by `PalletId`.
@@ -1051,6 +1589,10 @@ where
 	/// Initiate a charge fee pool, transfer token from treasury account to sub account.
 	pub fn initialize_pool(currency_id: CurrencyId, pool_size: Balance, swap_threshold: Balance) -> DispatchResult {
 		ensure!(currency_id != T::NativeCurrencyId::get(), Error::<T>::InvalidSwapPath);
+		ensure!(
+			T::AssetStatusProvider::is_verified(currency_id),
+			Error::<T>::UnverifiedToken
+		);
 
 		// do tx fee pool pre-check
 		let treasury_account = T::TreasuryAccount::get();
@@ -1123,6 +1665,7 @@ where
 		)?;
 
 		TokenExchangeRate::<T>::remove(currency_id);
+		LastRateRefresh::<T>::remove(currency_id);
 		PoolSize::<T>::remove(currency_id);
 		SwapBalanceThreshold::<T>::remove(currency_id);
 		GlobalFeeSwapPath::<T>::remove(currency_id);
@@ -1220,6 +1763,17 @@ where
 			return Ok((fee, None, 0, who.clone()));
 		}
 
+		// the sponsor pays the fee from its budget, there is no surplus as no swap happens.
+		if let Some(Call::with_fee_sponsor { sponsor, call }) = call.is_sub_type() {
+			// the tip is not sponsored
+			if !tip.is_zero() {
+				return Err(InvalidTransaction::Payment.into());
+			}
+			let imbalance = Pallet::<T>::withdraw_sponsored_fee(sponsor, who, call, fee)
+				.map_err(|_| InvalidTransaction::Payment)?;
+			return Ok((fee, Some(imbalance), 0, sponsor.clone()));
+		}
+
 		let reason = if tip.is_zero() {
 			WithdrawReasons::TRANSACTION_PAYMENT
 		} else {
@@ -1332,8 +1886,9 @@ where
 		PalletBalanceOf<T>,
 		Self::AccountId,
 		Option<NegativeImbalanceOf<T>>,
-		PalletBalanceOf<T>, // fee includes surplus
-		PalletBalanceOf<T>, // surplus
+		PalletBalanceOf<T>,      // fee includes surplus
+		PalletBalanceOf<T>,      // surplus
+		Option<Self::AccountId>, // the signer when the fee is paid by a sponsor
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -1363,7 +1918,8 @@ where
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
 		let (fee, imbalance, surplus, payer) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, payer, imbalance, fee, surplus))
+		let sponsored = matches!(call.is_sub_type(), Some(Call::with_fee_sponsor { .. })).then(|| who.clone());
+		Ok((self.0, payer, imbalance, fee, surplus, sponsored))
 	}
 
 	fn post_dispatch(
@@ -1373,7 +1929,7 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		if let Some((tip, who, Some(payed), fee, surplus)) = pre {
+		if let Some((tip, who, Some(payed), fee, surplus, sponsored)) = pre {
 			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
 			let refund_fee = fee.saturating_sub(actual_fee);
 			let mut refund = refund_fee;
@@ -1400,6 +1956,9 @@ where
 
 			let actual_payment = match <T as Config>::Currency::deposit_into_existing(&who, refund) {
 				Ok(refund_imbalance) => {
+					if let Some(user) = &sponsored {
+						Pallet::<T>::refund_sponsored_fee(&who, user, refund);
+					}
 					// The refund cannot be larger than the up front payed max weight.
 					// `PostDispatchInfo::calc_unspent` guards against such a case.
 					match payed.offset(refund_imbalance) {
@@ -1417,6 +1976,13 @@ where
 			// distribute fee
 			<T as Config>::OnTransactionPayment::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
 
+			if let Some(user) = sponsored {
+				Pallet::<T>::deposit_event(Event::<T>::TransactionFeeSponsored {
+					sponsor: who.clone(),
+					who: user,
+					actual_fee,
+				});
+			}
 			Pallet::<T>::deposit_event(Event::<T>::TransactionFeePaid {
 				who,
 				actual_fee,
@@ -34,7 +34,7 @@ use module_support::{
 	Price, SpecificJointsSwap,
 };
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, ReserveIdentifier, TokenSymbol, TradingPair};
+use primitives::{currency::AssetStatus, Amount, ReserveIdentifier, TokenSymbol, TradingPair};
 use smallvec::smallvec;
 use sp_core::{crypto::AccountId32, H160, H256};
 use sp_runtime::{
@@ -160,6 +160,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<Zero, AccountId>;
 	type OnDust = ();
+	type AssetStatusProvider = ();
 }
 
 thread_local! {
@@ -193,6 +194,7 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Zero, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type AssetStatusProvider = ();
 }
 
 impl module_aggregated_dex::Config for Runtime {
@@ -206,6 +208,7 @@ impl module_aggregated_dex::Config for Runtime {
 
 parameter_types! {
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(1, 2);
+	pub MaxRateDeviation: Ratio = Ratio::saturating_from_rational(1, 10);
 	pub static TransactionByteFee: u128 = 1;
 	pub static TipPerWeightStep: u128 = 1;
 	pub DefaultFeeTokens: Vec<CurrencyId> = vec![AUSD];
@@ -277,6 +280,20 @@ impl WeightToFeeT for TransactionByteFee {
 	}
 }
 
+parameter_types! {
+	pub static MockUnverifiedCurrencyId: Option<CurrencyId> = None;
+}
+
+impl AssetStatusProvider<CurrencyId> for MockUnverifiedCurrencyId {
+	fn asset_status(_currency_id: CurrencyId) -> AssetStatus {
+		AssetStatus::Active
+	}
+
+	fn is_verified(currency_id: CurrencyId) -> bool {
+		Self::get() != Some(currency_id)
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -295,6 +312,9 @@ impl Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
+	type DEXPriceSource = ();
+	type RateRefreshPeriod = ConstU64<10>;
+	type MaxRateDeviation = MaxRateDeviation;
 	type WeightInfo = ();
 	type PalletId = TransactionPaymentPalletId;
 	type TreasuryAccount = KaruraTreasuryAccount;
@@ -302,6 +322,7 @@ impl Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AssetStatusProvider = MockUnverifiedCurrencyId;
 }
 
 thread_local! {
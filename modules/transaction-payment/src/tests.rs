@@ -27,9 +27,9 @@ use frame_support::{
 	dispatch::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Currencies, DEXModule, ExtBuilder, FeePoolSize, MockPriceSource, Runtime, RuntimeCall,
-	RuntimeOrigin, System, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DAVE, DOT, FEE_UNBALANCED_AMOUNT, LDOT,
-	TIP_UNBALANCED_AMOUNT,
+	AccountId, BlockWeights, Currencies, DEXModule, ExtBuilder, FeePoolSize, MockPriceSource, MockUnverifiedCurrencyId,
+	Runtime, RuntimeCall, RuntimeOrigin, System, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DAVE, DOT,
+	FEE_UNBALANCED_AMOUNT, LDOT, TIP_UNBALANCED_AMOUNT,
 };
 use module_support::{BuyWeightRate, DEXManager, Price, TransactionPayment as TransactionPaymentT};
 use orml_traits::{MultiCurrency, MultiLockableCurrency};
@@ -103,6 +103,15 @@ fn with_fee_aggregated_path_by_call(
 	fee_call
 }
 
+fn with_fee_sponsor_call(sponsor: AccountId) -> <Runtime as Config>::RuntimeCall {
+	let fee_call: <Runtime as Config>::RuntimeCall =
+		RuntimeCall::TransactionPayment(crate::mock::transaction_payment::Call::with_fee_sponsor {
+			sponsor,
+			call: Box::new(CALL),
+		});
+	fee_call
+}
+
 fn enable_dex_and_tx_fee_pool() {
 	let treasury_account: AccountId = <Runtime as Config>::TreasuryAccount::get();
 	let init_balance = FeePoolSize::get();
@@ -354,6 +363,139 @@ fn pre_post_dispatch_and_refund_native_is_enough() {
 	});
 }
 
+#[test]
+fn pre_post_dispatch_and_refund_with_fee_sponsor_call_works() {
+	builder_with_dex_and_fee_pool(false).execute_with(|| {
+		let fee = 23 * 2 + 1000; // len * byte + weight
+		let sponsored_call = with_fee_sponsor_call(ALICE);
+
+		// the sponsor does not sponsor the call or the user
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&BOB, &sponsored_call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+
+		assert_ok!(TransactionPayment::fund_sponsor_budget(
+			RuntimeOrigin::signed(ALICE),
+			10000
+		));
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::SponsorBudgetFunded {
+				sponsor: ALICE,
+				amount: 10000,
+			},
+		));
+		// Currencies is the pallet of `CALL`
+		assert_ok!(TransactionPayment::set_sponsored_call(
+			RuntimeOrigin::signed(ALICE),
+			4,
+			None,
+			true
+		));
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&BOB, &sponsored_call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+
+		assert_ok!(TransactionPayment::set_sponsor_quota(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			Some(2000)
+		));
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::SponsorQuotaUpdated {
+				sponsor: ALICE,
+				user: BOB,
+				quota: Some(2000),
+			},
+		));
+
+		// the tip is not sponsored
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(100).pre_dispatch(&BOB, &sponsored_call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&BOB, &sponsored_call, &INFO, 23)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), 90000);
+		assert_eq!(PalletBalances::reserved_balance_named(&SPONSOR_ID, &ALICE), 10000 - fee);
+		assert_eq!(TransactionPayment::sponsor_quotas(ALICE, BOB), Some(2000 - fee));
+
+		let actual_fee = TransactionPayment::compute_actual_fee(23, &INFO, &POST_INFO, 0);
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&INFO,
+			&POST_INFO,
+			23,
+			&Ok(())
+		));
+
+		// the unused fee is returned back to the budget and the quota
+		let refund = 200; // 1000 - 800
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), 90000);
+		assert_eq!(
+			PalletBalances::reserved_balance_named(&SPONSOR_ID, &ALICE),
+			10000 - fee + refund
+		);
+		assert_eq!(
+			TransactionPayment::sponsor_quotas(ALICE, BOB),
+			Some(2000 - fee + refund)
+		);
+		assert_eq!(FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), fee - refund);
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::TransactionFeeSponsored {
+				sponsor: ALICE,
+				who: BOB,
+				actual_fee,
+			},
+		));
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::TransactionFeePaid {
+				who: ALICE,
+				actual_fee,
+				actual_tip: 0,
+				actual_surplus: 0,
+			},
+		));
+
+		// the quota is not enough
+		assert_ok!(TransactionPayment::set_sponsor_quota(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			Some(fee - 1)
+		));
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&BOB, &sponsored_call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+
+		// the budget is not enough
+		assert_ok!(TransactionPayment::set_sponsor_quota(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			Some(2000)
+		));
+		assert_ok!(TransactionPayment::withdraw_sponsor_budget(
+			RuntimeOrigin::signed(ALICE),
+			10000
+		));
+		System::assert_last_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::SponsorBudgetWithdrawn {
+				sponsor: ALICE,
+				amount: 10000 - fee + refund,
+			},
+		));
+		assert_eq!(PalletBalances::reserved_balance_named(&SPONSOR_ID, &ALICE), 0);
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&BOB, &sponsored_call, &INFO, 23),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
+	});
+}
+
 #[test]
 fn pre_post_dispatch_and_refund_with_fee_currency_call_default_fee_tokens_work() {
 	// default fee token, and enabled by charge fee pool
@@ -1003,6 +1145,101 @@ fn payment_reserve_fee() {
 	});
 }
 
+#[test]
+fn on_idle_refresh_rate_and_replenish_fee_pools() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let ausd_rate = Ratio::saturating_from_rational(10, 1);
+		let dot_rate = Ratio::saturating_from_rational(1, 10);
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(20, 1)));
+
+		// no enough weight
+		assert_eq!(TransactionPayment::on_idle(10, Weight::zero()), Weight::zero());
+		assert_eq!(TokenExchangeRate::<Runtime>::get(AUSD), Some(ausd_rate));
+
+		// the pools are maintained in batches bounded by the weight
+		let weight_per_pool = <Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(5, 2)
+			.saturating_add(<Runtime as Config>::WeightInfo::with_fee_path());
+		let batch_weight = <Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(weight_per_pool);
+		assert_eq!(TransactionPayment::on_idle(10, batch_weight), batch_weight);
+		assert_eq!(
+			[AUSD, DOT]
+				.into_iter()
+				.filter(|currency_id| TransactionPayment::last_rate_refresh(currency_id) == 10)
+				.count(),
+			1
+		);
+		assert!(FeePoolMaintenanceCursor::<Runtime>::exists());
+
+		// the rate is moved towards the market price at most `MaxRateDeviation`
+		assert!(!TransactionPayment::on_idle(10, Weight::MAX).is_zero());
+		let new_ausd_rate = Ratio::saturating_from_rational(11, 1);
+		let new_dot_rate = Ratio::saturating_from_rational(11, 100);
+		assert_eq!(TokenExchangeRate::<Runtime>::get(AUSD), Some(new_ausd_rate));
+		assert_eq!(TokenExchangeRate::<Runtime>::get(DOT), Some(new_dot_rate));
+		assert_eq!(TransactionPayment::last_rate_refresh(AUSD), 10);
+		assert_eq!(TransactionPayment::last_rate_refresh(DOT), 10);
+		assert!(!FeePoolMaintenanceCursor::<Runtime>::exists());
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::ChargeFeePoolRateRefreshed {
+				currency_id: AUSD,
+				old_exchange_rate: ausd_rate,
+				market_exchange_rate: Ratio::saturating_from_rational(20, 1),
+				new_exchange_rate: new_ausd_rate,
+			},
+		));
+		System::assert_has_event(crate::mock::RuntimeEvent::TransactionPayment(
+			crate::Event::ChargeFeePoolRateRefreshed {
+				currency_id: DOT,
+				old_exchange_rate: dot_rate,
+				market_exchange_rate: Ratio::saturating_from_rational(20, 1),
+				new_exchange_rate: new_dot_rate,
+			},
+		));
+
+		// not refreshed before `RateRefreshPeriod` passed
+		TransactionPayment::on_idle(15, Weight::MAX);
+		assert_eq!(TokenExchangeRate::<Runtime>::get(AUSD), Some(new_ausd_rate));
+		assert_eq!(TransactionPayment::last_rate_refresh(AUSD), 10);
+
+		// the pool is replenished from dex once below the threshold
+		let sub_account: AccountId = <Runtime as Config>::PalletId::get().into_sub_account_truncating(AUSD);
+		assert_ok!(<Currencies as MultiCurrency<AccountId>>::transfer(
+			ACA,
+			&sub_account,
+			&BOB,
+			FeePoolSize::get() - 10
+		));
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			sub_account.clone(),
+			AUSD,
+			100,
+		));
+		TransactionPayment::on_idle(16, Weight::MAX);
+		let native_balance = Currencies::free_balance(ACA, &sub_account);
+		assert!(native_balance > crate::mock::LowerSwapThreshold::get());
+		assert_eq!(PoolSize::<Runtime>::get(AUSD), native_balance);
+
+		let ausd_pool = TransactionPayment::fee_pools()
+			.into_iter()
+			.find(|pool| pool.currency_id == AUSD)
+			.unwrap();
+		assert_eq!(
+			ausd_pool,
+			FeePoolInfo {
+				currency_id: AUSD,
+				exchange_rate: TokenExchangeRate::<Runtime>::get(AUSD).unwrap(),
+				native_balance,
+				pool_size: native_balance,
+				swap_threshold: crate::mock::LowerSwapThreshold::get(),
+			}
+		);
+	});
+}
+
 #[test]
 fn charges_fee_failed_by_slippage_limit() {
 	builder_with_dex_and_fee_pool(true).execute_with(|| {
@@ -1075,6 +1312,16 @@ fn set_alternative_fee_swap_path_work() {
 				TransactionPayment::set_alternative_fee_swap_path(RuntimeOrigin::signed(ALICE), Some(vec![ACA, ACA])),
 				Error::<Runtime>::InvalidSwapPath
 			);
+
+			MockUnverifiedCurrencyId::set(Some(DOT));
+			assert_noop!(
+				TransactionPayment::set_alternative_fee_swap_path(
+					RuntimeOrigin::signed(ALICE),
+					Some(vec![DOT, AUSD, ACA])
+				),
+				Error::<Runtime>::UnverifiedToken
+			);
+			MockUnverifiedCurrencyId::set(None);
 		});
 }
 
@@ -2090,6 +2337,12 @@ fn charge_fee_pool_operation_works() {
 			Error::<Runtime>::ChargeFeePoolAlreadyExisted
 		);
 
+		MockUnverifiedCurrencyId::set(Some(KSM));
+		assert_noop!(
+			Pallet::<Runtime>::enable_charge_fee_pool(RuntimeOrigin::signed(ALICE), KSM, pool_size, swap_threshold),
+			Error::<Runtime>::UnverifiedToken
+		);
+		MockUnverifiedCurrencyId::set(None);
 		assert_noop!(
 			Pallet::<Runtime>::enable_charge_fee_pool(RuntimeOrigin::signed(ALICE), KSM, pool_size, swap_threshold),
 			Error::<Runtime>::DexNotAvailable
@@ -2205,5 +2458,264 @@ fn with_fee_call_validation_works() {
 				),
 				TransactionValidityError::Invalid(InvalidTransaction::Payment)
 			);
+
+			// unverified token can't be used to pay fee
+			let reason = WithdrawReasons::TRANSACTION_PAYMENT;
+			MockUnverifiedCurrencyId::set(Some(AUSD));
+			assert_eq!(
+				Pallet::<Runtime>::ensure_can_charge_fee_with_call(
+					&ALICE,
+					200,
+					&with_fee_path_call(vec![DOT, AUSD, ACA]),
+					reason
+				),
+				Err(Error::<Runtime>::UnverifiedToken.into())
+			);
+			let aggregated_path = vec![AggregatedSwapPath::Dex(vec![DOT, AUSD, ACA])];
+			assert_eq!(
+				Pallet::<Runtime>::ensure_can_charge_fee_with_call(
+					&ALICE,
+					200,
+					&with_fee_aggregated_path_by_call(aggregated_path),
+					reason
+				),
+				Err(Error::<Runtime>::UnverifiedToken.into())
+			);
+			MockUnverifiedCurrencyId::set(None);
 		});
 }
+
+#[test]
+fn fee_in_currency_works() {
+	builder_with_dex_and_fee_pool(false).execute_with(|| {
+		assert_eq!(Pallet::<Runtime>::fee_in_currency(ACA, 200), Some(200));
+		// no fee pool and no liquidity
+		assert_eq!(Pallet::<Runtime>::fee_in_currency(LDOT, 200), None);
+	});
+
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		// AUSD is default fee token, rate is 10 and surplus is 25%
+		assert_eq!(Pallet::<Runtime>::fee_in_currency(AUSD, 200), Some(2500));
+		// DOT is not default fee token, rate is 1/10 and surplus is 50%
+		assert_eq!(Pallet::<Runtime>::fee_in_currency(DOT, 200), Some(30));
+		// LDOT is not enabled charge fee pool, swap from dex
+		let fee_surplus = 200 + CustomFeeSurplus::get().mul_ceil(200);
+		let (supply_amount, _) =
+			DEXModule::get_swap_amount(&[LDOT, ACA], SwapLimit::ExactTarget(Balance::MAX, fee_surplus)).unwrap();
+		assert_eq!(Pallet::<Runtime>::fee_in_currency(LDOT, 200), Some(supply_amount));
+	});
+}
+
+#[test]
+fn quote_fee_works() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let reason = WithdrawReasons::TRANSACTION_PAYMENT;
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::Currency(ACA), 200, reason),
+			Some(FeeQuote {
+				currency_id: ACA,
+				fee: 200,
+				top_up: 0,
+				surplus: 0,
+				amount: 200,
+				mechanism: FeeMechanism::Native,
+			})
+		);
+		// AUSD is default fee token, rate is 10 and surplus is 25%
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::Currency(AUSD), 200, reason),
+			Some(FeeQuote {
+				currency_id: AUSD,
+				fee: 200,
+				top_up: 0,
+				surplus: 50,
+				amount: 2500,
+				mechanism: FeeMechanism::FeePool,
+			})
+		);
+
+		// LDOT is not enabled charge fee pool, swap from dex
+		let fee_surplus = 200 + CustomFeeSurplus::get().mul_ceil(200);
+		let (supply_amount, _) =
+			DEXModule::get_swap_amount(&[LDOT, ACA], SwapLimit::ExactTarget(Balance::MAX, fee_surplus)).unwrap();
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::Currency(LDOT), 200, reason),
+			Some(FeeQuote {
+				currency_id: LDOT,
+				fee: 200,
+				top_up: 0,
+				surplus: 100,
+				amount: supply_amount,
+				mechanism: FeeMechanism::Dex,
+			})
+		);
+
+		// swap by the given path even DOT is enabled charge fee pool
+		let (supply_amount, _) =
+			DEXModule::get_swap_amount(&[DOT, AUSD, ACA], SwapLimit::ExactTarget(Balance::MAX, fee_surplus)).unwrap();
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::SwapPath(vec![DOT, AUSD, ACA]), 200, reason),
+			Some(FeeQuote {
+				currency_id: DOT,
+				fee: 200,
+				top_up: 0,
+				surplus: 100,
+				amount: supply_amount,
+				mechanism: FeeMechanism::Dex,
+			})
+		);
+		// invalid swap path
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::SwapPath(vec![ACA]), 200, reason),
+			None
+		);
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::SwapPath(vec![DOT, AUSD]), 200, reason),
+			None
+		);
+		// the last of aggregated path must be dex swap to native token
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(
+				&ALICE,
+				FeeCurrency::AggregatedPath(DOT, vec![AggregatedSwapPath::Taiga(0, 0, 1)]),
+				200,
+				reason
+			),
+			None
+		);
+		// the mock swap doesn't support aggregated path, same as `with_fee_aggregated_path`
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(
+				&ALICE,
+				FeeCurrency::AggregatedPath(DOT, vec![AggregatedSwapPath::Dex(vec![DOT, AUSD, ACA])]),
+				200,
+				reason
+			),
+			None
+		);
+
+		// unverified token can't be used to pay fee
+		MockUnverifiedCurrencyId::set(Some(AUSD));
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::Currency(AUSD), 200, reason),
+			None
+		);
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::SwapPath(vec![DOT, AUSD, ACA]), 200, reason),
+			None
+		);
+		MockUnverifiedCurrencyId::set(None);
+
+		// native is enough
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&ALICE, FeeCurrency::Auto, 200, reason)
+				.map(|quote| quote.mechanism),
+			Some(FeeMechanism::Native)
+		);
+
+		// the fee of the extrinsic with tip
+		let xt = TestXt::new(CALL, Some((111111, ())));
+		let len = xt.encode().len() as u32;
+		let fee = Pallet::<Runtime>::compute_fee(len, &xt.get_dispatch_info(), 100);
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee(&ALICE, xt, len, 100, FeeCurrency::Currency(ACA)).map(|quote| quote.amount),
+			Some(fee)
+		);
+	});
+}
+
+#[test]
+fn quote_fee_for_payer_without_native_works() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let reason = WithdrawReasons::TRANSACTION_PAYMENT;
+		let ed = Currencies::minimum_balance(ACA);
+		assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+
+		// swap the fee and the ED of native token to keep BOB alive
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&BOB, FeeCurrency::Currency(AUSD), 200, reason),
+			Some(FeeQuote {
+				currency_id: AUSD,
+				fee: 200,
+				top_up: ed,
+				surplus: 53,
+				amount: 2630,
+				mechanism: FeeMechanism::FeePool,
+			})
+		);
+
+		// BOB can't afford any fee token
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&BOB, FeeCurrency::Auto, 200, reason),
+			None
+		);
+
+		// DOT is not default fee token, rate is 1/10 and surplus is 50%
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 300));
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&BOB, FeeCurrency::Auto, 200, reason),
+			Some(FeeQuote {
+				currency_id: DOT,
+				fee: 200,
+				top_up: ed,
+				surplus: 100,
+				amount: 31,
+				mechanism: FeeMechanism::FeePool,
+			})
+		);
+
+		// default fee token is prior to other fee pools
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(AUSD, &ALICE, &BOB, 3000));
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&BOB, FeeCurrency::Auto, 200, reason),
+			Some(FeeQuote {
+				currency_id: AUSD,
+				fee: 200,
+				top_up: ed,
+				surplus: 50,
+				amount: 2600,
+				mechanism: FeeMechanism::FeePool,
+			})
+		);
+
+		// alternative fee swap path is prior to default fee tokens
+		let alternative_fee_swap_deposit: u128 =
+			<<Runtime as Config>::AlternativeFeeSwapDeposit as frame_support::traits::Get<u128>>::get();
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			BOB,
+			ACA,
+			(alternative_fee_swap_deposit + ed).try_into().unwrap(),
+		));
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			RuntimeOrigin::signed(BOB),
+			Some(vec![DOT, AUSD, ACA])
+		));
+		// fee=500*2+1000=2000ACA, surplus=2000*0.25=500ACA, swap 51 DOT to 2500ACA by the path
+		let fee = Pallet::<Runtime>::compute_fee(500, &INFO, 0);
+		assert_eq!(fee, 2000);
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&BOB, FeeCurrency::Auto, fee, reason),
+			Some(FeeQuote {
+				currency_id: DOT,
+				fee: 2000,
+				top_up: 0,
+				surplus: 500,
+				amount: 51,
+				mechanism: FeeMechanism::Dex,
+			})
+		);
+
+		// skip the alternative fee swap path and the fee pool of unverified token
+		MockUnverifiedCurrencyId::set(Some(DOT));
+		assert_eq!(
+			Pallet::<Runtime>::quote_fee_in_currency(&BOB, FeeCurrency::Auto, fee, reason),
+			None
+		);
+		MockUnverifiedCurrencyId::set(None);
+
+		// the quote is what the payment takes
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).validate(&BOB, &CALL2, &INFO, 500));
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 249);
+	});
+}
@@ -53,6 +53,11 @@ pub trait WeightInfo {
 	fn with_fee_path() -> Weight;
 	fn with_fee_aggregated_path() -> Weight;
 	fn with_fee_currency() -> Weight;
+	fn with_fee_sponsor() -> Weight;
+	fn fund_sponsor_budget() -> Weight;
+	fn withdraw_sponsor_budget() -> Weight;
+	fn set_sponsored_call() -> Weight;
+	fn set_sponsor_quota() -> Weight;
 }
 
 /// Weights for module_transaction_payment using the Acala node and recommended hardware.
@@ -108,6 +113,33 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn with_fee_sponsor() -> Weight {
+		Weight::from_parts(9_687_000, 0)
+	}
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn fund_sponsor_budget() -> Weight {
+		Weight::from_parts(32_106_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_sponsor_budget() -> Weight {
+		Weight::from_parts(32_811_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: TransactionPayment SponsoredCalls (r:0 w:1)
+	fn set_sponsored_call() -> Weight {
+		Weight::from_parts(13_302_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TransactionPayment SponsorQuotas (r:0 w:1)
+	fn set_sponsor_quota() -> Weight {
+		Weight::from_parts(13_569_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -142,4 +174,25 @@ impl WeightInfo for () {
 		Weight::from_parts(193_000_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 	}
+	fn with_fee_sponsor() -> Weight {
+		Weight::from_parts(9_687_000, 0)
+	}
+	fn fund_sponsor_budget() -> Weight {
+		Weight::from_parts(32_106_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn withdraw_sponsor_budget() -> Weight {
+		Weight::from_parts(32_811_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn set_sponsored_call() -> Weight {
+		Weight::from_parts(13_302_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_sponsor_quota() -> Weight {
+		Weight::from_parts(13_569_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
@@ -0,0 +1,35 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use primitives::{Balance, CurrencyId, XcmTransferFee};
+use xcm::VersionedMultiLocation;
+
+sp_api::decl_runtime_apis! {
+	pub trait XcmInterfaceRuntimeRPCApi {
+		/// Estimate the local fee, destination fee and received amount of transferring `amount`
+		/// of `currency_id` to `dest` by XTokens. Returns `None` if it can't be estimated.
+		fn estimate_transfer_fee(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: VersionedMultiLocation,
+		) -> Option<XcmTransferFee>;
+	}
+}
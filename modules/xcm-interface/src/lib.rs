@@ -23,17 +23,20 @@
 //! send the messages out to the intended destination.
 //!
 //! This module hides away XCM layer from native modules via the use of traits.
+//!
+//! The configured dest weight and fees are also used to estimate the end-to-end cost of XTokens
+//! transfers, which is exposed through the runtime API and the XTokens precompile.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, traits::Get};
+use frame_support::{pallet_prelude::*, traits::Get, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
 use frame_system::pallet_prelude::*;
-use module_support::{relaychain::CallBuilder, CrowdloanVaultXcm, HomaSubAccountXcm};
+use module_support::{relaychain::CallBuilder, BuyWeightRate, CrowdloanVaultXcm, HomaSubAccountXcm, Ratio};
 use orml_traits::XcmTransfer;
-use primitives::{Balance, CurrencyId, EraIndex};
+use primitives::{Balance, CurrencyId, EraIndex, XcmTransferFee};
 use scale_info::TypeInfo;
-use sp_runtime::traits::Convert;
+use sp_runtime::{traits::Convert, FixedPointNumber};
 use sp_std::{convert::From, prelude::*, vec, vec::Vec};
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 
@@ -95,6 +98,20 @@ pub mod module {
 
 		/// Convert AccountId to MultiLocation to build XCM message.
 		type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+		/// Convert CurrencyId to MultiLocation.
+		type CurrencyIdConvert: Convert<CurrencyId, Option<MultiLocation>>;
+
+		/// The fee in native currency of the XTokens transfer extrinsic of `(currency_id, amount,
+		/// dest)`.
+		type XtokensTransferFee: Convert<(CurrencyId, Balance, MultiLocation), Balance>;
+
+		/// The amount of native currency charged for one second of XCM execution weight.
+		#[pallet::constant]
+		type BaseRate: Get<u128>;
+
+		/// The rate of buying XCM execution weight with the transferred currency.
+		type BuyWeightRate: BuyWeightRate;
 	}
 
 	#[pallet::error]
@@ -202,6 +219,55 @@ pub mod module {
 
 			Ok(xcm_message)
 		}
+
+		/// Estimate the cost of transferring `amount` of `currency_id` to `dest` by XTokens.
+		///
+		/// The destination fee of the staking currency is the configured `XtokensTransfer` fee to
+		/// the relaychain or `ParachainFee` to the parachains. Other currencies buy the
+		/// `XtokensTransfer` dest weight at their `BuyWeightRate`. Returns `None` if the
+		/// destination fee can't be estimated.
+		pub fn estimate_transfer_fee(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: MultiLocation,
+		) -> Option<XcmTransferFee> {
+			let destination_fee = if currency_id == T::StakingCurrencyId::get() {
+				Self::staking_currency_fee_of(&dest)?
+			} else {
+				Self::buy_weight_fee_of(currency_id)?
+			};
+			let local_fee = T::XtokensTransferFee::convert((currency_id, amount, dest));
+
+			Some(XcmTransferFee {
+				local_fee,
+				destination_fee,
+				amount_received: amount.saturating_sub(destination_fee),
+			})
+		}
+
+		/// The configured fee of transferring the staking currency to `dest`, if any.
+		fn staking_currency_fee_of(dest: &MultiLocation) -> Option<Balance> {
+			let operation = match (dest.parents, dest.first_interior()) {
+				(1, Some(Parachain(para_id))) => {
+					XcmInterfaceOperation::ParachainFee(Box::new(MultiLocation::new(1, X1(Parachain(*para_id)))))
+				}
+				(1, _) => XcmInterfaceOperation::XtokensTransfer,
+				_ => return None,
+			};
+			XcmDestWeightAndFee::<T>::try_get(operation).ok().map(|(_, fee)| fee)
+		}
+
+		/// The fee of buying the `XtokensTransfer` dest weight with `currency_id`, if it has a
+		/// `BuyWeightRate`.
+		fn buy_weight_fee_of(currency_id: CurrencyId) -> Option<Balance> {
+			let (xcm_dest_weight, _) =
+				XcmDestWeightAndFee::<T>::try_get(XcmInterfaceOperation::XtokensTransfer).ok()?;
+			let location = T::CurrencyIdConvert::convert(currency_id)?;
+			let ratio = T::BuyWeightRate::calculate_rate(location)?;
+			let weight_ratio =
+				Ratio::saturating_from_rational(xcm_dest_weight.ref_time() as u128, WEIGHT_REF_TIME_PER_SECOND as u128);
+			Some(ratio.saturating_mul_int(weight_ratio.saturating_mul_int(T::BaseRate::get())))
+		}
 	}
 
 	impl<T: Config> HomaSubAccountXcm<T::AccountId, Balance> for Pallet<T> {
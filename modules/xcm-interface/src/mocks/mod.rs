@@ -38,7 +38,9 @@ pub type AccountId = AccountId32;
 
 pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 
 parameter_types! {
 	pub const UnitWeightCost: XcmWeight = XcmWeight::from_parts(10, 10);
@@ -141,6 +143,41 @@ impl XcmTransfer<AccountId, Balance, CurrencyId> for MockXcmTransfer {
 	}
 }
 
+pub struct MockXtokensTransferFee;
+impl Convert<(CurrencyId, Balance, MultiLocation), Balance> for MockXtokensTransferFee {
+	fn convert(_: (CurrencyId, Balance, MultiLocation)) -> Balance {
+		1_000
+	}
+}
+
+pub struct CurrencyIdConvert;
+impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
+	fn convert(currency_id: CurrencyId) -> Option<MultiLocation> {
+		match currency_id {
+			DOT => Some(MultiLocation::parent()),
+			AUSD => Some(MultiLocation::new(
+				0,
+				X1(GeneralKey {
+					length: 2,
+					data: [1u8; 32],
+				}),
+			)),
+			_ => None,
+		}
+	}
+}
+
+pub struct MockBuyWeightRate;
+impl BuyWeightRate for MockBuyWeightRate {
+	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
+		if Some(location) == CurrencyIdConvert::convert(AUSD) {
+			Some(Ratio::saturating_from_rational(1, 10))
+		} else {
+			None
+		}
+	}
+}
+
 pub struct AccountIdToMultiLocation;
 impl Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
 	fn convert(account: AccountId) -> MultiLocation {
@@ -240,6 +277,10 @@ macro_rules! impl_mock {
 			type XcmTransfer = MockXcmTransfer;
 			type SelfLocation = SelfLocation;
 			type AccountIdToMultiLocation = AccountIdToMultiLocation;
+			type CurrencyIdConvert = CurrencyIdConvert;
+			type XtokensTransferFee = MockXtokensTransferFee;
+			type BaseRate = ConstU128<1_000_000_000_000>;
+			type BuyWeightRate = MockBuyWeightRate;
 		}
 
 		construct_runtime!(
@@ -20,7 +20,9 @@
 
 #![cfg(test)]
 
-use crate::mocks::{kusama, polkadot, ExtBuilder, ALICE, BOB};
+use super::*;
+use crate::mocks::{kusama, polkadot, ExtBuilder, ACA, ALICE, AUSD, BOB, DOT};
+use frame_support::assert_ok;
 use insta::assert_debug_snapshot;
 
 #[test]
@@ -40,3 +42,117 @@ fn build_transfer_to_liquid_crowdloan_module_account_kusama() {
 		assert_debug_snapshot!(xcm);
 	});
 }
+
+#[test]
+fn estimate_transfer_fee_works() {
+	ExtBuilder::default().build::<polkadot::Runtime>().execute_with(|| {
+		let relaychain_dest = MultiLocation::new(
+			1,
+			X1(Junction::AccountId32 {
+				network: None,
+				id: BOB.into(),
+			}),
+		);
+		let parachain_dest = |para_id: u32| {
+			MultiLocation::new(
+				1,
+				X2(
+					Parachain(para_id),
+					Junction::AccountId32 {
+						network: None,
+						id: BOB.into(),
+					},
+				),
+			)
+		};
+
+		// the fees and the `XtokensTransfer` dest weight are not configured
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(AUSD, 1_000_000_000, relaychain_dest),
+			None
+		);
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(DOT, 1_000_000, relaychain_dest),
+			None
+		);
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(DOT, 1_000_000, parachain_dest(1000)),
+			None
+		);
+
+		assert_ok!(polkadot::XcmInterface::update_xcm_dest_weight_and_fee(
+			polkadot::RuntimeOrigin::signed(ALICE),
+			vec![
+				(
+					XcmInterfaceOperation::XtokensTransfer,
+					Some(XcmWeight::from_parts(1_000_000_000, 0)),
+					Some(5_000)
+				),
+				(
+					XcmInterfaceOperation::ParachainFee(Box::new((Parent, Parachain(1000)).into())),
+					None,
+					Some(2_000)
+				),
+			]
+		));
+
+		// destination fee is the `XtokensTransfer` fee to the relaychain
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(DOT, 1_000_000, relaychain_dest),
+			Some(XcmTransferFee {
+				local_fee: 1_000,
+				destination_fee: 5_000,
+				amount_received: 995_000,
+			})
+		);
+
+		// destination fee is the `ParachainFee` of the destination parachain
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(DOT, 1_000_000, parachain_dest(1000)),
+			Some(XcmTransferFee {
+				local_fee: 1_000,
+				destination_fee: 2_000,
+				amount_received: 998_000,
+			})
+		);
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(DOT, 1_000, parachain_dest(1000)),
+			Some(XcmTransferFee {
+				local_fee: 1_000,
+				destination_fee: 2_000,
+				amount_received: 0,
+			})
+		);
+
+		// the `ParachainFee` of the destination parachain is not configured
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(DOT, 1_000_000, parachain_dest(2000)),
+			None
+		);
+
+		// other currencies buy the `XtokensTransfer` dest weight at their rate: 1 second of weight
+		// costs 10^12 at the base rate, AUSD buys weight at 1/10 of it
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(AUSD, 1_000_000_000, relaychain_dest),
+			Some(XcmTransferFee {
+				local_fee: 1_000,
+				destination_fee: 100_000_000,
+				amount_received: 900_000_000,
+			})
+		);
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(AUSD, 1_000_000_000, parachain_dest(1000)),
+			Some(XcmTransferFee {
+				local_fee: 1_000,
+				destination_fee: 100_000_000,
+				amount_received: 900_000_000,
+			})
+		);
+
+		// ACA has no location to buy weight
+		assert_eq!(
+			polkadot::XcmInterface::estimate_transfer_fee(ACA, 1_000_000_000, relaychain_dest),
+			None
+		);
+	});
+}
@@ -39,6 +39,8 @@ pub trait RuntimeApiCollection:
 	+ sp_block_builder::BlockBuilder<Block>
 	+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+	+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
+	+ module_transaction_payment_rpc_runtime_api::TransactionPaymentRuntimeRPCApi<Block, AccountId>
 	+ sp_api::Metadata<Block>
 	+ sp_offchain::OffchainWorkerApi<Block>
 	+ sp_session::SessionKeys<Block>
@@ -52,6 +54,8 @@ impl<Api> RuntimeApiCollection for Api where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+		+ module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>
+		+ module_transaction_payment_rpc_runtime_api::TransactionPaymentRuntimeRPCApi<Block, AccountId>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
 		+ sp_session::SessionKeys<Block>
@@ -665,3 +665,18 @@ pub struct AssetMetadata<Balance> {
 	pub decimals: u8,
 	pub minimal_balance: Balance,
 }
+
+/// The lifecycle status of a registered asset.
+#[derive(
+	Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Default, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub enum AssetStatus {
+	/// The asset can be used without restrictions.
+	#[default]
+	Active,
+	/// The asset is being delisted. Existing balances can still be transferred and sent out, but
+	/// the asset can not be listed on the DEX or received via XCM any more.
+	Deprecated,
+	/// All transfers of the asset are frozen.
+	Frozen,
+}
@@ -21,6 +21,7 @@ use crate::{
 	Balance, BlockNumber, Nonce,
 };
 use core::ops::Range;
+use frame_support::weights::Weight;
 use hex_literal::hex;
 pub use module_evm_utility::{
 	ethereum::{AccessListItem, Log, TransactionAction},
@@ -32,7 +33,7 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_core::{H160, H256, U256};
 use sp_runtime::{traits::Zero, RuntimeDebug, SaturatedConversion};
-use sp_std::vec::Vec;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 /// Evm Address.
 pub type EvmAddress = sp_core::H160;
@@ -116,6 +117,180 @@ pub struct EstimateResourcesRequest {
 	pub access_list: Option<Vec<AccessListItem>>,
 }
 
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OpcodeConfig {
+	/// The page of the steps to return, starts from 0.
+	pub page: u32,
+	/// The number of the steps in a page, 0 means no paging.
+	pub page_size: u32,
+	/// Don't collect the stack of the steps.
+	pub disable_stack: bool,
+	/// Collect the memory of the steps.
+	pub enable_memory: bool,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TracerConfig {
+	/// Trace the call frames.
+	CallTracer,
+	/// Trace the executed opcodes, a.k.a. struct logs.
+	OpcodeTracer(OpcodeConfig),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CallType {
+	CALL,
+	CALLCODE,
+	STATICCALL,
+	DELEGATECALL,
+	CREATE,
+	CREATE2,
+	SUICIDE,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CallTrace {
+	#[cfg_attr(feature = "std", serde(rename = "type"))]
+	pub call_type: CallType,
+	pub from: H160,
+	pub to: H160,
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub input: Vec<u8>,
+	pub value: U256,
+	/// The gas limit of the call frame.
+	pub gas: u64,
+	/// The gas used by the call frame, including the sub calls.
+	pub gas_used: u64,
+	/// The storage used by the call frame, including the sub calls.
+	pub used_storage: i32,
+	/// The return value, or the revert reason if the call reverted.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub output: Vec<u8>,
+	/// None if the call frame has not exited.
+	pub exit_reason: Option<ExitReason>,
+	pub depth: u32,
+	pub calls: Vec<CallTrace>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Step {
+	pub op: u8,
+	pub pc: u64,
+	pub depth: u32,
+	/// The remaining gas before executing the opcode.
+	pub gas: u64,
+	pub gas_cost: u64,
+	pub stack: Vec<H256>,
+	pub memory: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TraceOutcome {
+	Calls(Vec<CallTrace>),
+	Steps(Vec<Step>),
+}
+
+/// The receipt of an EVM execution, built from the EVM events of the block.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EvmReceipt {
+	/// Index of the extrinsic in the block.
+	pub extrinsic_index: u32,
+	pub from: EvmAddress,
+	/// The called contract, `None` for a create.
+	pub to: Option<EvmAddress>,
+	/// The created contract, `None` for a call.
+	pub contract_address: Option<EvmAddress>,
+	pub logs: Vec<Log>,
+	pub used_gas: u64,
+	pub used_storage: i32,
+	/// Whether the execution succeeded.
+	pub status: bool,
+}
+
+/// The account state overridden for a dry-run call, it is never persisted.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AccountOverride {
+	/// The balance in EVM decimals.
+	pub balance: Option<U256>,
+	pub nonce: Option<Nonce>,
+	/// The code of the account, empty code removes the contract.
+	pub code: Option<Vec<u8>>,
+	/// Replace the whole storage of the account.
+	pub state: Option<BTreeMap<H256, H256>>,
+	/// Override the given storage slots, applied after `state`.
+	pub state_diff: Option<BTreeMap<H256, H256>>,
+}
+
+/// The overridden accounts of a dry-run call.
+pub type StateOverride = BTreeMap<EvmAddress, AccountOverride>;
+
+/// The decoded revert data of a failed execution.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum RevertReason {
+	/// `Error(string)` raised by `revert` or `require`.
+	Error(#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))] Vec<u8>),
+	/// `Panic(uint256)` raised by the compiler checks, e.g. overflow or division by zero.
+	Panic(U256),
+	/// The raw revert data of custom errors.
+	Custom(#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))] Vec<u8>),
+}
+
+/// The resources needed by an EVM call or create.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct EstimateResourcesResponse {
+	/// The lowest gas limit the execution succeeds with.
+	pub gas: u64,
+	/// The gas used with the estimated gas limit.
+	pub used_gas: u64,
+	/// The lowest storage limit the execution succeeds with.
+	pub storage: u32,
+	/// The net storage used, negative if storage is released.
+	pub used_storage: i32,
+	/// The weight of the extrinsic with the estimated gas limit.
+	pub weight: Weight,
+	/// The transaction fee in native currency.
+	pub fee: Balance,
+	/// The transaction fee in the requested fee currency, `None` if it can't pay the fee.
+	pub fee_in_currency: Option<(CurrencyId, Balance)>,
+	pub exit_reason: ExitReason,
+	/// The return value of the execution, the revert data if reverted.
+	#[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+	pub output: Vec<u8>,
+	pub revert_reason: Option<RevertReason>,
+}
+
+/// The storage usage and storage deposit of a contract.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ContractStorageUsage {
+	pub contract: H160,
+	/// The storage size in bytes, including the code size and the extra bytes.
+	pub storage_size: u32,
+	/// The storage deposit required by the current storage deposit per byte.
+	pub required_deposit: Balance,
+	/// The storage deposit reserved by the contract.
+	pub reserved_deposit: Balance,
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct EthereumTransactionMessage {
@@ -285,6 +460,67 @@ pub fn decode_gas_limit(gas_limit: u64) -> (u64, u32) {
 	(actual_gas_limit, actual_storage_limit)
 }
 
+/// The inverse of `decode_gas_limit`, the gas limit is rounded up to the `GAS_LIMIT_CHUNK` and the
+/// storage limit is rounded up to the power of 2.
+pub fn encode_gas_limit(gas_limit: u64, storage_limit: u32) -> u64 {
+	let max_gas_limit_chunks = GAS_MASK / STORAGE_MASK - 1;
+	let gas_limit_chunks = gas_limit
+		.saturating_add(GAS_LIMIT_CHUNK - 1)
+		.checked_div(GAS_LIMIT_CHUNK)
+		.expect("constant never failed; qed")
+		.min(max_gas_limit_chunks);
+
+	let storage_limit_number: u32 = if storage_limit.is_zero() {
+		Zero::zero()
+	} else {
+		// ceil(log2(storage_limit)), 2^0 is encoded as 2^1 since 0 means no storage
+		(u32::BITS - storage_limit.saturating_sub(1).leading_zeros())
+			.max(1)
+			.min(MAX_GAS_LIMIT_CC)
+	};
+
+	gas_limit_chunks
+		.saturating_mul(STORAGE_MASK)
+		.saturating_add(storage_limit_number.into())
+}
+
+/// Decode the revert data returned by a reverted execution.
+pub fn decode_revert_reason(output: &[u8]) -> Option<RevertReason> {
+	// Error(string)
+	const ERROR_SELECTOR: [u8; 4] = hex!("08c379a0");
+	// Panic(uint256)
+	const PANIC_SELECTOR: [u8; 4] = hex!("4e487b71");
+
+	if output.is_empty() {
+		return None;
+	}
+	if output.len() < 4 {
+		return Some(RevertReason::Custom(output.to_vec()));
+	}
+
+	let (selector, data) = output.split_at(4);
+	let read_usize = |offset: usize| -> Option<usize> {
+		let word = data.get(offset..offset.checked_add(32)?)?;
+		let value = U256::from_big_endian(word);
+		(value <= U256::from(u32::MAX)).then(|| value.as_usize())
+	};
+
+	let reason = if selector == ERROR_SELECTOR {
+		read_usize(0).and_then(|offset| {
+			let len = read_usize(offset)?;
+			let start = offset.checked_add(32)?;
+			data.get(start..start.checked_add(len)?)
+				.map(|message| RevertReason::Error(message.to_vec()))
+		})
+	} else if selector == PANIC_SELECTOR && data.len() == 32 {
+		Some(RevertReason::Panic(U256::from_big_endian(data)))
+	} else {
+		None
+	};
+
+	Some(reason.unwrap_or_else(|| RevertReason::Custom(output.to_vec())))
+}
+
 #[cfg(not(feature = "evm-tests"))]
 mod convert {
 	use sp_runtime::traits::{CheckedDiv, Saturating, Zero};
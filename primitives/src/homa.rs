@@ -0,0 +1,38 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Balance;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{FixedU128, RuntimeDebug};
+
+/// The snapshot of Homa liquid staking which is recorded when era is bumped.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExchangeRateRecord {
+	/// The total amount of staking currency belong to Homa after rebalance.
+	pub total_staking: Balance,
+	/// The total amount of liquid currency (including void liquid) after rebalance.
+	pub total_liquid: Balance,
+	/// The exchange rate (staking : liquid) after rebalance.
+	pub exchange_rate: FixedU128,
+	/// The staking rewards accumulated for the bumped eras.
+	pub rewards: Balance,
+}
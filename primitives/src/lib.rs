@@ -23,10 +23,12 @@
 pub mod bonding;
 pub mod currency;
 pub mod evm;
+pub mod homa;
 pub mod nft;
 pub mod signature;
 pub mod task;
 pub mod testing;
+pub mod transaction_payment;
 pub mod unchecked_extrinsic;
 pub use testing::*;
 
@@ -179,6 +181,18 @@ pub struct Position {
 	pub debit: Balance,
 }
 
+/// The estimated cost of a cross-chain transfer.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct XcmTransferFee {
+	/// The fee in native currency to execute the transfer locally.
+	pub local_fee: Balance,
+	/// The fee in the transferred currency expected to be charged on the destination chain.
+	pub destination_fee: Balance,
+	/// The amount expected to be received by the recipient.
+	pub amount_received: Balance,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, MaxEncodedLen, TypeInfo)]
 #[repr(u8)]
 pub enum ReserveIdentifier {
@@ -189,6 +203,8 @@ pub enum ReserveIdentifier {
 	Nft,
 	TransactionPayment,
 	TransactionPaymentDeposit,
+	TransactionPaymentSponsor,
+	EvmSponsorshipDeposit,
 
 	// always the last, indicate number of variants
 	Count,
@@ -16,6 +16,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::evm::EvmAddress;
+use frame_support::weights::Weight;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -41,6 +43,27 @@ pub enum AcalaMultiSignature {
 	Eip1559([u8; 65]),
 	// An Ethereum SECP256k1 signature using Eip712 for message encoding.
 	AcalaEip712([u8; 65]),
+	// A smart contract wallet signature using Eip712 for message encoding, verified by
+	// EIP-1271 `isValidSignature` of the contract. The contract must have claimed an account
+	// with `claim_contract_account`.
+	AcalaEip1271(EvmAddress, Vec<u8>),
+}
+
+/// Verify signatures of smart contract wallets.
+pub trait VerifyContractSignature {
+	/// Returns true if `contract` returns the EIP-1271 magic value for `hash` and `signature`.
+	fn is_valid_signature(contract: EvmAddress, hash: [u8; 32], signature: &[u8]) -> bool;
+	/// The maximum weight of `is_valid_signature`.
+	fn weight() -> Weight;
+}
+
+impl VerifyContractSignature for () {
+	fn is_valid_signature(_contract: EvmAddress, _hash: [u8; 32], _signature: &[u8]) -> bool {
+		false
+	}
+	fn weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl From<ed25519::Signature> for AcalaMultiSignature {
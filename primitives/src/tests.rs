@@ -18,8 +18,8 @@
 
 use super::*;
 use crate::evm::{
-	decode_gas_limit, decode_gas_price, is_system_contract, EvmAddress, MAX_GAS_LIMIT_CC,
-	SYSTEM_CONTRACT_ADDRESS_PREFIX,
+	decode_gas_limit, decode_gas_price, decode_revert_reason, encode_gas_limit, is_system_contract, EvmAddress,
+	RevertReason, MAX_GAS_LIMIT_CC, SYSTEM_CONTRACT_ADDRESS_PREFIX,
 };
 use frame_support::assert_ok;
 use sp_core::H160;
@@ -238,3 +238,50 @@ fn decode_gas_limit_works() {
 		(15330000, 2u32.pow(MAX_GAS_LIMIT_CC))
 	);
 }
+
+#[test]
+fn encode_gas_limit_works() {
+	assert_eq!(encode_gas_limit(0, 0), 0);
+	assert_eq!(encode_gas_limit(30_000, 1), 101);
+	assert_eq!(encode_gas_limit(30_001, 64), 206);
+	assert_eq!(encode_gas_limit(200_000, 65), 707);
+	assert_eq!(
+		encode_gas_limit(u64::MAX, u32::MAX),
+		99_900 + u64::from(MAX_GAS_LIMIT_CC)
+	);
+
+	assert_eq!(decode_gas_limit(encode_gas_limit(30_001, 64)), (60_000, 64));
+	assert_eq!(decode_gas_limit(encode_gas_limit(200_000, 65)), (210_000, 128));
+}
+
+#[test]
+fn decode_revert_reason_works() {
+	assert_eq!(decode_revert_reason(&[]), None);
+	assert_eq!(
+		decode_revert_reason(&[1, 2, 3]),
+		Some(RevertReason::Custom(vec![1, 2, 3]))
+	);
+
+	// Error("error message")
+	let output = hex_literal::hex!("08c379a00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000d6572726f72206d65737361676500000000000000000000000000000000000000");
+	assert_eq!(
+		decode_revert_reason(&output),
+		Some(RevertReason::Error(b"error message".to_vec()))
+	);
+	// truncated message
+	assert_eq!(
+		decode_revert_reason(&output[..80]),
+		Some(RevertReason::Custom(output[..80].to_vec()))
+	);
+
+	// Panic(0x11), arithmetic overflow
+	let output = hex_literal::hex!("4e487b710000000000000000000000000000000000000000000000000000000000000011");
+	assert_eq!(decode_revert_reason(&output), Some(RevertReason::Panic(0x11.into())));
+
+	// custom error `Unauthorized()`
+	let output = hex_literal::hex!("82b42900");
+	assert_eq!(
+		decode_revert_reason(&output),
+		Some(RevertReason::Custom(output.to_vec()))
+	);
+}
@@ -0,0 +1,88 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Balance, CurrencyId};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{FixedU128, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The state of a charge fee pool of transaction payment.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FeePoolInfo {
+	/// The currency used to pay fee through the pool.
+	pub currency_id: CurrencyId,
+	/// The amount of the currency to pay for one native token.
+	pub exchange_rate: FixedU128,
+	/// The remaining native token the pool can supply.
+	pub native_balance: Balance,
+	/// The native token of the pool after it was last replenished.
+	pub pool_size: Balance,
+	/// The pool is replenished from dex when `native_balance` is below it.
+	pub swap_threshold: Balance,
+}
+
+/// The currency to pay the transaction fee with, `AggregatedPath` is the step of an aggregated
+/// swap path.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FeeCurrency<AggregatedPath> {
+	/// Pay without `with_fee_*`: native token if it's enough, otherwise the signer's alternative
+	/// fee swap path, the default fee tokens and then the other charge fee pools.
+	Auto,
+	/// Pay by `with_fee_currency` with the currency.
+	Currency(CurrencyId),
+	/// Pay by `with_fee_path` with the dex swap path, which ends with native token.
+	SwapPath(Vec<CurrencyId>),
+	/// Pay by `with_fee_aggregated_path` with the aggregated swap path which supplies the currency
+	/// and ends with a dex swap to native token.
+	AggregatedPath(CurrencyId, Vec<AggregatedPath>),
+}
+
+/// How the fee currency is exchanged to native token.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FeeMechanism {
+	/// Paid in native token directly.
+	Native,
+	/// Exchanged from the charge fee pool at its exchange rate.
+	FeePool,
+	/// Swapped from dex.
+	Dex,
+}
+
+/// The quote of the transaction fee paid in other currency.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FeeQuote {
+	/// The currency to pay the fee.
+	pub currency_id: CurrencyId,
+	/// The fee in native token including the tip, excluding the surplus.
+	pub fee: Balance,
+	/// The extra native token swapped to keep the payer above the existential deposit.
+	pub top_up: Balance,
+	/// The extra native token charged for not paying in native token.
+	pub surplus: Balance,
+	/// The amount of `currency_id` to pay for the fee, the top up and the surplus.
+	pub amount: Balance,
+	/// How `currency_id` is exchanged to native token.
+	pub mechanism: FeeMechanism,
+}
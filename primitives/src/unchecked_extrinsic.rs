@@ -16,10 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{evm::EthereumTransactionMessage, signature::AcalaMultiSignature, to_bytes, Address, Balance};
+use crate::{
+	evm::EthereumTransactionMessage,
+	signature::{AcalaMultiSignature, VerifyContractSignature},
+	to_bytes, Address, Balance,
+};
 use frame_support::{
-	dispatch::{DispatchInfo, GetDispatchInfo},
+	dispatch::{DispatchErrorWithPostInfo, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
 	traits::{ExtrinsicCall, Get},
+	weights::Weight,
 };
 use module_evm_utility::ethereum::{EIP1559TransactionMessage, LegacyTransactionMessage, TransactionAction};
 use module_evm_utility_macro::keccak256;
@@ -29,23 +34,38 @@ use sp_core::{H160, H256};
 use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
 use sp_runtime::{
 	generic::{CheckedExtrinsic, UncheckedExtrinsic},
-	traits::{self, Checkable, Convert, Extrinsic, ExtrinsicMetadata, Member, SignedExtension, Zero},
-	transaction_validity::{InvalidTransaction, TransactionValidityError},
-	AccountId32, RuntimeDebug,
+	traits::{
+		self, Checkable, Convert, DispatchInfoOf, Dispatchable, Extrinsic, ExtrinsicMetadata, MaybeDisplay, Member,
+		PostDispatchInfoOf, SignedExtension, ValidateUnsigned, Zero,
+	},
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError},
+	AccountId32, ApplyExtrinsicResultWithInfo, RuntimeDebug,
 };
 #[cfg(not(feature = "std"))]
 use sp_std::alloc::format;
 use sp_std::{marker::PhantomData, prelude::*};
 
+/// The maximum length of the signatures of smart contract wallets, which bounds the input of
+/// the EIP-1271 call verifying them.
+pub const MAX_CONTRACT_SIGNATURE_LEN: usize = 1024;
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(ConvertEthTx))]
-pub struct AcalaUncheckedExtrinsic<Call, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>(
+#[scale_info(skip_type_params(ConvertEthTx, VerifyContractSig))]
+pub struct AcalaUncheckedExtrinsic<
+	Call,
+	Extra: SignedExtension,
+	ConvertEthTx,
+	StorageDepositPerByte,
+	TxFeePerGas,
+	VerifyContractSig = (),
+>(
 	pub UncheckedExtrinsic<Address, Call, AcalaMultiSignature, Extra>,
-	PhantomData<(ConvertEthTx, StorageDepositPerByte, TxFeePerGas)>,
+	PhantomData<(ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig)>,
 );
 
-impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> Extrinsic
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
+	Extrinsic
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 {
 	type Call = Call;
 
@@ -67,32 +87,35 @@ impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte
 	}
 }
 
-impl<Call, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> ExtrinsicMetadata
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
+	ExtrinsicMetadata
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 {
 	const VERSION: u8 = UncheckedExtrinsic::<Address, Call, AcalaMultiSignature, Extra>::VERSION;
 	type SignedExtensions = Extra;
 }
 
-impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> ExtrinsicCall
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call: TypeInfo, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
+	ExtrinsicCall
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 {
 	fn call(&self) -> &Self::Call {
 		self.0.call()
 	}
 }
 
-impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, Lookup> Checkable<Lookup>
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig, Lookup> Checkable<Lookup>
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 where
 	Call: Encode + Member,
 	Extra: SignedExtension<AccountId = AccountId32>,
 	ConvertEthTx: Convert<(Call, Extra), Result<(EthereumTransactionMessage, Extra), InvalidTransaction>>,
 	StorageDepositPerByte: Get<Balance>,
 	TxFeePerGas: Get<Balance>,
+	VerifyContractSig: VerifyContractSignature,
 	Lookup: traits::Lookup<Source = Address, Target = AccountId32>,
 {
-	type Checked = CheckedExtrinsic<AccountId32, Call, Extra>;
+	type Checked = AcalaCheckedExtrinsic<AccountId32, Call, Extra>;
 
 	fn check(self, lookup: &Lookup) -> Result<Self::Checked, TransactionValidityError> {
 		let function = self.0.function.clone();
@@ -141,9 +164,10 @@ where
 					return Err(InvalidTransaction::BadProof.into());
 				}
 
-				Ok(CheckedExtrinsic {
+				Ok(AcalaCheckedExtrinsic {
 					signed: Some((account_id, eth_extra)),
 					function,
+					signature_weight: Weight::zero(),
 				})
 			}
 			Some((addr, AcalaMultiSignature::Eip1559(sig), extra)) => {
@@ -189,9 +213,10 @@ where
 					return Err(InvalidTransaction::BadProof.into());
 				}
 
-				Ok(CheckedExtrinsic {
+				Ok(AcalaCheckedExtrinsic {
 					signed: Some((account_id, eth_extra)),
 					function,
+					signature_weight: Weight::zero(),
 				})
 			}
 			Some((addr, AcalaMultiSignature::AcalaEip712(sig), extra)) => {
@@ -209,12 +234,52 @@ where
 					return Err(InvalidTransaction::BadProof.into());
 				}
 
-				Ok(CheckedExtrinsic {
+				Ok(AcalaCheckedExtrinsic {
+					signed: Some((account_id, eth_extra)),
+					function,
+					signature_weight: Weight::zero(),
+				})
+			}
+			Some((addr, AcalaMultiSignature::AcalaEip1271(contract, sig), extra)) => {
+				let (eth_msg, eth_extra) = ConvertEthTx::convert((function.clone(), extra))?;
+				log::trace!(
+					target: "evm", "AcalaEip1271 eth_msg: {:?}", eth_msg
+				);
+
+				if sig.len() > MAX_CONTRACT_SIGNATURE_LEN {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+
+				let account_id = lookup.lookup(Address::Address20(contract.into()))?;
+				let expected_account_id = lookup.lookup(addr)?;
+
+				if account_id != expected_account_id {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+
+				// only the contracts which claimed an account with `claim_contract_account` can sign,
+				// so the contract code is not executed for the arbitrary contracts before any fee is
+				// paid. The unclaimed contracts are mapped to their default accounts.
+				let mut default_account_id = [0u8; 32];
+				default_account_id[0..4].copy_from_slice(b"evm:");
+				default_account_id[4..24].copy_from_slice(contract.as_bytes());
+				if account_id == AccountId32::from(default_account_id) {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+
+				let msg_hash = eip712_message_hash(eth_msg);
+				if !VerifyContractSig::is_valid_signature(contract, msg_hash, &sig) {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+
+				// the EVM call to verify the signature is charged on top of the call
+				Ok(AcalaCheckedExtrinsic {
 					signed: Some((account_id, eth_extra)),
 					function,
+					signature_weight: VerifyContractSig::weight(),
 				})
 			}
-			_ => self.0.check(lookup),
+			_ => self.0.check(lookup).map(Into::into),
 		}
 	}
 
@@ -227,19 +292,112 @@ where
 	}
 }
 
-impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> GetDispatchInfo
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig> GetDispatchInfo
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 where
 	Call: GetDispatchInfo,
 	Extra: SignedExtension,
+	VerifyContractSig: VerifyContractSignature,
 {
 	fn get_dispatch_info(&self) -> DispatchInfo {
-		self.0.get_dispatch_info()
+		let mut info = self.0.get_dispatch_info();
+		if let Some((_, AcalaMultiSignature::AcalaEip1271(..), _)) = self.0.signature {
+			info.weight = info.weight.saturating_add(VerifyContractSig::weight());
+		}
+		info
 	}
 }
 
-impl<Call: Encode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> serde::Serialize
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+/// An extrinsic that has passed the verification. The weight of verifying its signature is
+/// charged on top of the weight of its call, and isn't refunded.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug)]
+pub struct AcalaCheckedExtrinsic<AccountId, Call, Extra> {
+	/// Who this purports to be from, if anyone.
+	pub signed: Option<(AccountId, Extra)>,
+	/// The function that should be called.
+	pub function: Call,
+	/// The weight of verifying the signature.
+	pub signature_weight: Weight,
+}
+
+impl<AccountId, Call, Extra> From<CheckedExtrinsic<AccountId, Call, Extra>>
+	for AcalaCheckedExtrinsic<AccountId, Call, Extra>
+{
+	fn from(checked: CheckedExtrinsic<AccountId, Call, Extra>) -> Self {
+		Self {
+			signed: checked.signed,
+			function: checked.function,
+			signature_weight: Weight::zero(),
+		}
+	}
+}
+
+impl<AccountId, Call: GetDispatchInfo, Extra> GetDispatchInfo for AcalaCheckedExtrinsic<AccountId, Call, Extra> {
+	fn get_dispatch_info(&self) -> DispatchInfo {
+		let mut info = self.function.get_dispatch_info();
+		info.weight = info.weight.saturating_add(self.signature_weight);
+		info
+	}
+}
+
+impl<AccountId, Call, Extra, RuntimeOrigin> traits::Applyable for AcalaCheckedExtrinsic<AccountId, Call, Extra>
+where
+	AccountId: Member + MaybeDisplay,
+	Call: Member + Dispatchable<RuntimeOrigin = RuntimeOrigin, PostInfo = PostDispatchInfo>,
+	Extra: SignedExtension<AccountId = AccountId, Call = Call>,
+	RuntimeOrigin: From<Option<AccountId>>,
+{
+	type Call = Call;
+
+	fn validate<U: ValidateUnsigned<Call = Self::Call>>(
+		&self,
+		source: TransactionSource,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		if let Some((ref id, ref extra)) = self.signed {
+			Extra::validate(extra, id, &self.function, info, len)
+		} else {
+			let valid = Extra::validate_unsigned(&self.function, info, len)?;
+			let unsigned_validation = U::validate_unsigned(source, &self.function)?;
+			Ok(valid.combine_with(unsigned_validation))
+		}
+	}
+
+	fn apply<U: ValidateUnsigned<Call = Self::Call>>(
+		self,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> ApplyExtrinsicResultWithInfo<PostDispatchInfoOf<Self::Call>> {
+		let (maybe_who, maybe_pre) = if let Some((id, extra)) = self.signed {
+			let pre = Extra::pre_dispatch(extra, &id, &self.function, info, len)?;
+			(Some(id), Some(pre))
+		} else {
+			Extra::pre_dispatch_unsigned(&self.function, info, len)?;
+			U::pre_dispatch(&self.function)?;
+			(None, None)
+		};
+		let res = self.function.dispatch(RuntimeOrigin::from(maybe_who));
+		let mut post_info = match res {
+			Ok(info) => info,
+			Err(err) => err.post_info,
+		};
+		// the signature has been verified, so its weight is not refunded
+		post_info.actual_weight = post_info
+			.actual_weight
+			.map(|weight| weight.saturating_add(self.signature_weight));
+		let res = res.map(|_| post_info).map_err(|err| DispatchErrorWithPostInfo {
+			post_info,
+			error: err.error,
+		});
+		Extra::post_dispatch(maybe_pre, info, &post_info, len, &res.map(|_| ()).map_err(|e| e.error))?;
+		Ok(res)
+	}
+}
+
+impl<Call: Encode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
+	serde::Serialize
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 {
 	fn serialize<S>(&self, seq: S) -> Result<S::Ok, S::Error>
 	where
@@ -249,8 +407,9 @@ impl<Call: Encode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte,
 	}
 }
 
-impl<'a, Call: Decode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas> serde::Deserialize<'a>
-	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas>
+impl<'a, Call: Decode, Extra: SignedExtension, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
+	serde::Deserialize<'a>
+	for AcalaUncheckedExtrinsic<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, VerifyContractSig>
 {
 	fn deserialize<D>(de: D) -> Result<Self, D::Error>
 	where
@@ -268,6 +427,14 @@ fn recover_signer(sig: &[u8; 65], msg_hash: &[u8; 32]) -> Option<H160> {
 }
 
 fn verify_eip712_signature(eth_msg: EthereumTransactionMessage, sig: [u8; 65]) -> Option<H160> {
+	let msg_hash = eip712_message_hash(eth_msg);
+
+	recover_signer(&sig, &msg_hash)
+}
+
+/// Returns the hash of the EIP-712 message of `eth_msg`, which is signed by the EIP-712 and
+/// EIP-1271 signatures.
+pub fn eip712_message_hash(eth_msg: EthereumTransactionMessage) -> [u8; 32] {
 	let domain_hash = keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)");
 	let access_list_type_hash = keccak256!("AccessList(address address,uint256[] storageKeys)");
 	let tx_type_hash = keccak256!("Transaction(string action,address to,uint256 nonce,uint256 tip,bytes data,uint256 value,uint256 gasLimit,uint256 storageLimit,AccessList[] accessList,uint256 validUntil)AccessList(address address,uint256[] storageKeys)");
@@ -313,9 +480,7 @@ fn verify_eip712_signature(eth_msg: EthereumTransactionMessage, sig: [u8; 65]) -
 	msg.extend_from_slice(&domain_separator);
 	msg.extend_from_slice(&keccak_256(tx_msg.as_slice()));
 
-	let msg_hash = keccak_256(msg.as_slice());
-
-	recover_signer(&sig, &msg_hash)
+	keccak_256(msg.as_slice())
 }
 
 fn recover_sign_data(
@@ -0,0 +1,66 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Errors of the RPC methods.
+
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	types::error::{CallError, ErrorObject},
+};
+use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
+use primitives::{Balance, Block};
+use serde::Serialize;
+use sp_api::ApiExt;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code of the runtime api call failures.
+pub const RUNTIME_ERROR: i32 = 1;
+/// Error code of the invalid requests.
+pub const INVALID_PARAMS_ERROR: i32 = 2;
+/// Error code of the reverted EVM executions, the revert output is the error data.
+pub const EXECUTION_REVERTED: i32 = 3;
+
+/// Build the error with the code and message.
+pub fn error(code: i32, message: impl ToString) -> JsonRpseeError {
+	error_with_data(code, message, None::<()>)
+}
+
+/// Build the error with the code, message and data.
+pub fn error_with_data<T: Serialize>(code: i32, message: impl ToString, data: Option<T>) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(code, message.to_string(), data)))
+}
+
+/// Build the error of the runtime api call failures.
+pub fn runtime_error(message: impl std::fmt::Debug) -> JsonRpseeError {
+	error(RUNTIME_ERROR, format!("{:?}", message))
+}
+
+/// Ensure the runtime at `at` supports the `EVMRuntimeRPCApi` of `version`.
+pub fn ensure_api_version<Api>(api: &Api, at: <Block as BlockT>::Hash, version: u32) -> RpcResult<()>
+where
+	Api: ApiExt<Block> + EVMRuntimeRPCApi<Block, Balance>,
+{
+	let api_version = api
+		.api_version::<dyn EVMRuntimeRPCApi<Block, Balance>>(at)
+		.map_err(runtime_error)?
+		.unwrap_or_default();
+	if api_version < version {
+		return Err(error(RUNTIME_ERROR, "Not supported by the runtime"));
+	}
+	Ok(())
+}
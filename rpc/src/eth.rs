@@ -0,0 +1,640 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Ethereum JSON-RPC methods, served from the runtime api of the EVM module.
+//!
+//! The `gas` of the requests and the result of `eth_estimateGas` are encoded with the storage limit,
+//! in the same way as the Ethereum transactions of `AcalaUncheckedExtrinsic`, see `decode_gas_limit`.
+
+use crate::error::{
+	ensure_api_version, error, error_with_data, runtime_error, EXECUTION_REVERTED, INVALID_PARAMS_ERROR,
+};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
+use primitives::{
+	evm::{
+		convert_decimals_from_evm, decode_gas_limit, encode_gas_limit, AccessListItem, EvmReceipt, ExitReason,
+		StateOverride,
+	},
+	Balance, Block, BlockNumber,
+};
+use sc_client_api::BlockBackend;
+use sc_rpc_api::DenyUnsafe;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256, U256};
+use sp_runtime::traits::{Block as BlockT, Hash as HashT};
+use std::{str::FromStr, sync::Arc};
+
+/// The runtime api version which supports the Ethereum RPC methods.
+const ETH_API_VERSION: u32 = 4;
+/// The runtime api version which supports the state override of `eth_call`.
+const STATE_OVERRIDE_API_VERSION: u32 = 6;
+/// The runtime api version which supports the sponsored `eth_call`.
+const SPONSORED_CALL_API_VERSION: u32 = 8;
+/// The number of the recent blocks searched by `eth_getTransactionReceipt`.
+const RECEIPT_LOOKUP_DEPTH: BlockNumber = 256;
+/// The maximum number of blocks queried by `eth_getLogs`.
+const MAX_LOGS_BLOCK_RANGE: BlockNumber = 1024;
+
+/// Block number, block hash or block tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockNumberOrTag {
+	/// Block number.
+	Number(BlockNumber),
+	/// Block hash.
+	Hash(H256),
+	/// The genesis block.
+	Earliest,
+	/// The best block.
+	Latest,
+	/// The best block, there is no pending block.
+	Pending,
+	/// The finalized block.
+	Safe,
+	/// The finalized block.
+	Finalized,
+}
+
+impl<'de> Deserialize<'de> for BlockNumberOrTag {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let value = String::deserialize(deserializer)?;
+		match value.as_str() {
+			"earliest" => Ok(Self::Earliest),
+			"latest" => Ok(Self::Latest),
+			"pending" => Ok(Self::Pending),
+			"safe" => Ok(Self::Safe),
+			"finalized" => Ok(Self::Finalized),
+			// 0x prefixed 32 bytes
+			hash if hash.len() == 66 => H256::from_str(hash).map(Self::Hash).map_err(D::Error::custom),
+			number => {
+				let number = number
+					.strip_prefix("0x")
+					.ok_or_else(|| D::Error::custom("missing 0x prefix"))?;
+				BlockNumber::from_str_radix(number, 16)
+					.map(Self::Number)
+					.map_err(D::Error::custom)
+			}
+		}
+	}
+}
+
+/// A single value or an array of values.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ValueOrArray<T> {
+	/// A single value.
+	Value(T),
+	/// Any of the values.
+	Array(Vec<T>),
+}
+
+impl<T: PartialEq> ValueOrArray<T> {
+	fn contains(&self, value: &T) -> bool {
+		match self {
+			Self::Value(v) => v == value,
+			Self::Array(values) => values.contains(value),
+		}
+	}
+}
+
+/// Request of `eth_call` and `eth_estimateGas`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallRequest {
+	/// The caller.
+	pub from: Option<H160>,
+	/// The callee, `None` for a create.
+	pub to: Option<H160>,
+	/// The encoded gas limit and storage limit, the block limits are used if it's not set.
+	pub gas: Option<U256>,
+	/// Value in EVM decimals.
+	pub value: Option<U256>,
+	/// The input of the call or the init code of the create.
+	#[serde(alias = "input")]
+	pub data: Option<Bytes>,
+	/// EIP-2930 access list.
+	pub access_list: Option<Vec<AccessListItem>>,
+	/// The paymaster sponsoring the call of `from`, the call fails if it's not sponsored.
+	pub paymaster: Option<H160>,
+}
+
+/// Filter of `eth_getLogs`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+	/// The first block of the range, the best block by default.
+	pub from_block: Option<BlockNumberOrTag>,
+	/// The last block of the range, the best block by default.
+	pub to_block: Option<BlockNumberOrTag>,
+	/// Query the block only, `from_block` and `to_block` are ignored.
+	pub block_hash: Option<H256>,
+	/// The log emitters.
+	pub address: Option<ValueOrArray<H160>>,
+	/// The topics by position, `None` matches any topic.
+	pub topics: Option<Vec<Option<ValueOrArray<H256>>>>,
+}
+
+impl Filter {
+	fn matches(&self, address: &H160, topics: &[H256]) -> bool {
+		if let Some(filter) = &self.address {
+			if !filter.contains(address) {
+				return false;
+			}
+		}
+
+		self.topics
+			.iter()
+			.flatten()
+			.enumerate()
+			.all(|(i, filter)| match filter {
+				Some(filter) => topics.get(i).map_or(false, |topic| filter.contains(topic)),
+				None => true,
+			})
+	}
+}
+
+/// Log of `eth_getLogs` and `eth_getTransactionReceipt`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+	/// The log emitter.
+	pub address: H160,
+	/// The log topics.
+	pub topics: Vec<H256>,
+	/// The log data.
+	pub data: Bytes,
+	/// Hash of the block.
+	pub block_hash: H256,
+	/// Number of the block.
+	pub block_number: U256,
+	/// Hash of the extrinsic.
+	pub transaction_hash: H256,
+	/// Index of the extrinsic in the block.
+	pub transaction_index: U256,
+	/// Index of the log in the block.
+	pub log_index: U256,
+	/// Always false, the logs of the best chain are returned.
+	pub removed: bool,
+}
+
+/// Receipt of `eth_getTransactionReceipt`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Receipt {
+	/// Hash of the extrinsic.
+	pub transaction_hash: H256,
+	/// Index of the extrinsic in the block.
+	pub transaction_index: U256,
+	/// Hash of the block.
+	pub block_hash: H256,
+	/// Number of the block.
+	pub block_number: U256,
+	/// The caller.
+	pub from: H160,
+	/// The callee, `None` for a create.
+	pub to: Option<H160>,
+	/// The created contract, `None` for a call.
+	pub contract_address: Option<H160>,
+	/// The gas used by the EVM executions of the block up to this one.
+	pub cumulative_gas_used: U256,
+	/// The gas used by the execution.
+	pub gas_used: U256,
+	/// The logs emitted by the execution.
+	pub logs: Vec<Log>,
+	/// 1 for success, 0 for failure.
+	pub status: U256,
+}
+
+/// Ethereum RPC methods.
+#[rpc(server, namespace = "eth")]
+pub trait EthApi {
+	/// Returns the EVM chain id.
+	#[method(name = "chainId")]
+	fn chain_id(&self) -> RpcResult<U256>;
+
+	/// Returns the number of the best block.
+	#[method(name = "blockNumber")]
+	fn block_number(&self) -> RpcResult<U256>;
+
+	/// Returns the free balance in EVM decimals.
+	#[method(name = "getBalance")]
+	fn balance(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<U256>;
+
+	/// Returns the contract code.
+	#[method(name = "getCode")]
+	fn code_at(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<Bytes>;
+
+	/// Returns the contract storage.
+	#[method(name = "getStorageAt")]
+	fn storage_at(&self, address: H160, index: U256, block: Option<BlockNumberOrTag>) -> RpcResult<H256>;
+
+	/// Returns the output of the call, or empty bytes for a create.
+	/// The call can be executed on top of the overridden account states.
+	#[method(name = "call")]
+	fn call(
+		&self,
+		request: CallRequest,
+		block: Option<BlockNumberOrTag>,
+		state_override: Option<StateOverride>,
+	) -> RpcResult<Bytes>;
+
+	/// Returns the encoded gas limit and storage limit used by the execution.
+	#[method(name = "estimateGas")]
+	fn estimate_gas(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<U256>;
+
+	/// The transaction hash is the extrinsic hash, only the recent blocks are searched.
+	/// Unsafe as it scans `RECEIPT_LOOKUP_DEPTH` blocks.
+	#[method(name = "getTransactionReceipt")]
+	fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<Receipt>>;
+
+	/// Returns the logs matching the filter.
+	/// Unsafe as it scans up to `MAX_LOGS_BLOCK_RANGE` blocks.
+	#[method(name = "getLogs")]
+	fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+}
+
+/// Provides the Ethereum RPC methods.
+pub struct Eth<C> {
+	client: Arc<C>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<C> Eth<C> {
+	/// Create new `Eth` with the given reference to the client.
+	pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe }
+	}
+}
+
+impl<C> Eth<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+{
+	fn resolve_block_hash(&self, block: Option<BlockNumberOrTag>) -> RpcResult<H256> {
+		let number = match block.unwrap_or(BlockNumberOrTag::Latest) {
+			BlockNumberOrTag::Hash(hash) => return Ok(hash),
+			BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => return Ok(self.client.info().best_hash),
+			BlockNumberOrTag::Safe | BlockNumberOrTag::Finalized => return Ok(self.client.info().finalized_hash),
+			BlockNumberOrTag::Earliest => 0,
+			BlockNumberOrTag::Number(number) => number,
+		};
+
+		self.client
+			.hash(number)
+			.map_err(runtime_error)?
+			.ok_or_else(|| error(INVALID_PARAMS_ERROR, "Block not found"))
+	}
+
+	fn resolve_block_number(&self, block: Option<BlockNumberOrTag>) -> RpcResult<BlockNumber> {
+		match block.unwrap_or(BlockNumberOrTag::Latest) {
+			BlockNumberOrTag::Number(number) => Ok(number),
+			BlockNumberOrTag::Earliest => Ok(0),
+			block => {
+				let hash = self.resolve_block_hash(Some(block))?;
+				self.client
+					.number(hash)
+					.map_err(runtime_error)?
+					.ok_or_else(|| error(INVALID_PARAMS_ERROR, "Block not found"))
+			}
+		}
+	}
+
+	fn extrinsic_hashes(&self, block_hash: H256) -> RpcResult<Vec<H256>> {
+		Ok(self
+			.client
+			.block_body(block_hash)
+			.map_err(runtime_error)?
+			.unwrap_or_default()
+			.iter()
+			.map(<Block as BlockT>::Hashing::hash_of)
+			.collect())
+	}
+
+	/// The receipts of the block, empty if the runtime doesn't support it.
+	fn receipts(&self, block_hash: H256) -> RpcResult<Vec<EvmReceipt>> {
+		let api = self.client.runtime_api();
+		if ensure_api_version(&*api, block_hash, ETH_API_VERSION).is_err() {
+			return Ok(Vec::new());
+		}
+		api.receipts(block_hash).map_err(runtime_error)
+	}
+
+	/// Execute the request and returns the output of the call and the used resources.
+	fn execute(
+		&self,
+		request: CallRequest,
+		block: Option<BlockNumberOrTag>,
+		estimate: bool,
+		state_override: Option<StateOverride>,
+	) -> RpcResult<(Vec<u8>, u64, i32)> {
+		let at = self.resolve_block_hash(block)?;
+		let api = self.client.runtime_api();
+
+		let (gas_limit, storage_limit) = match request.gas {
+			Some(gas) => decode_gas_limit(gas.try_into().unwrap_or(u64::MAX)),
+			None => {
+				let block_limits = api.block_limits(at).map_err(runtime_error)?;
+				(block_limits.max_gas_limit, block_limits.max_storage_limit)
+			}
+		};
+		let value = request
+			.value
+			.unwrap_or_default()
+			.try_into()
+			.ok()
+			.and_then(convert_decimals_from_evm)
+			.ok_or_else(|| error(INVALID_PARAMS_ERROR, "Invalid value"))?;
+		let from = request.from.unwrap_or_default();
+		let data = request.data.map(|data| data.0).unwrap_or_default();
+
+		let (exit_reason, output, used_gas, used_storage) = match (request.to, state_override, request.paymaster) {
+			(Some(to), None, Some(paymaster)) => {
+				ensure_api_version(&*api, at, SPONSORED_CALL_API_VERSION)?;
+				let info = api
+					.sponsored_call(
+						at,
+						from,
+						paymaster,
+						to,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						request.access_list,
+					)
+					.map_err(runtime_error)?
+					.map_err(runtime_error)?;
+				(info.exit_reason, info.value, info.used_gas, info.used_storage)
+			}
+			(_, _, Some(_)) => {
+				return Err(error(
+					INVALID_PARAMS_ERROR,
+					"Paymaster is not supported for create or with state override",
+				))
+			}
+			(Some(to), Some(state_override), None) => {
+				ensure_api_version(&*api, at, STATE_OVERRIDE_API_VERSION)?;
+				let info = api
+					.call_with_state_override(
+						at,
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						request.access_list,
+						state_override,
+					)
+					.map_err(runtime_error)?
+					.map_err(runtime_error)?;
+				(info.exit_reason, info.value, info.used_gas, info.used_storage)
+			}
+			(None, Some(_), None) => {
+				return Err(error(
+					INVALID_PARAMS_ERROR,
+					"State override is not supported for create",
+				))
+			}
+			(Some(to), None, None) => {
+				let info = api
+					.call(
+						at,
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						request.access_list,
+						estimate,
+					)
+					.map_err(runtime_error)?
+					.map_err(runtime_error)?;
+				(info.exit_reason, info.value, info.used_gas, info.used_storage)
+			}
+			(None, None, None) => {
+				let info = api
+					.create(
+						at,
+						from,
+						data,
+						value,
+						gas_limit,
+						storage_limit,
+						request.access_list,
+						estimate,
+					)
+					.map_err(runtime_error)?
+					.map_err(runtime_error)?;
+				(info.exit_reason, Vec::new(), info.used_gas, info.used_storage)
+			}
+		};
+
+		match exit_reason {
+			ExitReason::Succeed(_) => Ok((output, used_gas.try_into().unwrap_or(u64::MAX), used_storage)),
+			ExitReason::Revert(_) => Err(error_with_data(
+				EXECUTION_REVERTED,
+				"execution reverted",
+				Some(Bytes(output)),
+			)),
+			reason => Err(runtime_error(reason)),
+		}
+	}
+
+	fn to_logs(
+		&self,
+		receipt: &EvmReceipt,
+		block_hash: H256,
+		block_number: BlockNumber,
+		transaction_hash: H256,
+		first_log_index: usize,
+	) -> Vec<Log> {
+		receipt
+			.logs
+			.iter()
+			.enumerate()
+			.map(|(i, log)| Log {
+				address: log.address,
+				topics: log.topics.clone(),
+				data: Bytes(log.data.clone()),
+				block_hash,
+				block_number: block_number.into(),
+				transaction_hash,
+				transaction_index: receipt.extrinsic_index.into(),
+				log_index: (first_log_index + i).into(),
+				removed: false,
+			})
+			.collect()
+	}
+}
+
+#[async_trait]
+impl<C> EthApiServer for Eth<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+{
+	fn chain_id(&self) -> RpcResult<U256> {
+		let at = self.client.info().best_hash;
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, at, ETH_API_VERSION)?;
+		api.chain_id(at).map(Into::into).map_err(runtime_error)
+	}
+
+	fn block_number(&self) -> RpcResult<U256> {
+		Ok(self.client.info().best_number.into())
+	}
+
+	fn balance(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<U256> {
+		let at = self.resolve_block_hash(block)?;
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, at, ETH_API_VERSION)?;
+		api.balance(at, address).map_err(runtime_error)
+	}
+
+	fn code_at(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<Bytes> {
+		let at = self.resolve_block_hash(block)?;
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, at, ETH_API_VERSION)?;
+		api.code_at(at, address).map(Bytes).map_err(runtime_error)
+	}
+
+	fn storage_at(&self, address: H160, index: U256, block: Option<BlockNumberOrTag>) -> RpcResult<H256> {
+		let at = self.resolve_block_hash(block)?;
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, at, ETH_API_VERSION)?;
+
+		let mut key = [0u8; 32];
+		index.to_big_endian(&mut key);
+		api.storage_at(at, address, H256(key)).map_err(runtime_error)
+	}
+
+	fn call(
+		&self,
+		request: CallRequest,
+		block: Option<BlockNumberOrTag>,
+		state_override: Option<StateOverride>,
+	) -> RpcResult<Bytes> {
+		self.execute(request, block, false, state_override)
+			.map(|(output, _, _)| Bytes(output))
+	}
+
+	fn estimate_gas(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<U256> {
+		let (_, used_gas, used_storage) = self.execute(request, block, true, None)?;
+		Ok(encode_gas_limit(used_gas, used_storage.max(0).unsigned_abs()).into())
+	}
+
+	fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<Receipt>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let best_number = self.client.info().best_number;
+
+		for number in (best_number.saturating_sub(RECEIPT_LOOKUP_DEPTH)..=best_number).rev() {
+			let Some(block_hash) = self.client.hash(number).map_err(runtime_error)? else {
+				continue;
+			};
+			let Some(extrinsic_index) = self.extrinsic_hashes(block_hash)?.iter().position(|h| *h == hash) else {
+				continue;
+			};
+
+			let mut cumulative_gas_used: u64 = 0;
+			let mut log_index: usize = 0;
+			for receipt in self.receipts(block_hash)? {
+				cumulative_gas_used = cumulative_gas_used.saturating_add(receipt.used_gas);
+				if receipt.extrinsic_index as usize == extrinsic_index {
+					return Ok(Some(Receipt {
+						transaction_hash: hash,
+						transaction_index: receipt.extrinsic_index.into(),
+						block_hash,
+						block_number: number.into(),
+						from: receipt.from,
+						to: receipt.to,
+						contract_address: receipt.contract_address,
+						cumulative_gas_used: cumulative_gas_used.into(),
+						gas_used: receipt.used_gas.into(),
+						logs: self.to_logs(&receipt, block_hash, number, hash, log_index),
+						status: u8::from(receipt.status).into(),
+					}));
+				}
+				log_index += receipt.logs.len();
+			}
+
+			// not an EVM transaction
+			return Ok(None);
+		}
+
+		Ok(None)
+	}
+
+	fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let blocks = match filter.block_hash {
+			Some(block_hash) => vec![(
+				block_hash,
+				self.resolve_block_number(Some(BlockNumberOrTag::Hash(block_hash)))?,
+			)],
+			None => {
+				let from = self.resolve_block_number(filter.from_block)?;
+				let to = self.resolve_block_number(filter.to_block)?;
+				if to.saturating_sub(from) >= MAX_LOGS_BLOCK_RANGE {
+					return Err(error(INVALID_PARAMS_ERROR, "Block range is too large"));
+				}
+				(from..=to)
+					.map(|number| Ok((self.resolve_block_hash(Some(BlockNumberOrTag::Number(number)))?, number)))
+					.collect::<RpcResult<Vec<_>>>()?
+			}
+		};
+
+		let mut logs = Vec::new();
+		for (block_hash, block_number) in blocks {
+			let receipts = self.receipts(block_hash)?;
+			if !receipts
+				.iter()
+				.flat_map(|receipt| receipt.logs.iter())
+				.any(|log| filter.matches(&log.address, &log.topics))
+			{
+				continue;
+			}
+
+			let extrinsic_hashes = self.extrinsic_hashes(block_hash)?;
+			let mut log_index: usize = 0;
+			for receipt in receipts {
+				let transaction_hash = extrinsic_hashes
+					.get(receipt.extrinsic_index as usize)
+					.copied()
+					.unwrap_or_default();
+				logs.extend(
+					self.to_logs(&receipt, block_hash, block_number, transaction_hash, log_index)
+						.into_iter()
+						.filter(|log| filter.matches(&log.address, &log.topics)),
+				);
+				log_index += receipt.logs.len();
+			}
+		}
+
+		Ok(logs)
+	}
+}
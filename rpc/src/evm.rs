@@ -0,0 +1,97 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the EVM resources estimation.
+
+use crate::error::{ensure_api_version, runtime_error};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
+use primitives::{
+	evm::{EstimateResourcesRequest, EstimateResourcesResponse},
+	Balance, Block, CurrencyId,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// The runtime api version which supports the resources estimation.
+const ESTIMATE_RESOURCES_API_VERSION: u32 = 5;
+
+/// EVM RPC methods.
+#[rpc(server)]
+pub trait EVMApi<BlockHash> {
+	/// Estimate the lowest gas and storage limits of the call, or the create if `to` is not set,
+	/// and the fee in native currency and in `fee_currency_id` if given.
+	#[method(name = "evm_estimateResources")]
+	fn estimate_resources(
+		&self,
+		request: EstimateResourcesRequest,
+		fee_currency_id: Option<CurrencyId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<EstimateResourcesResponse>;
+}
+
+/// Provides the EVM RPC methods.
+pub struct EVM<C> {
+	client: Arc<C>,
+}
+
+impl<C> EVM<C> {
+	/// Create new `EVM` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl<C> EVMApiServer<<Block as BlockT>::Hash> for EVM<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+{
+	fn estimate_resources(
+		&self,
+		request: EstimateResourcesRequest,
+		fee_currency_id: Option<CurrencyId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<EstimateResourcesResponse> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, at, ESTIMATE_RESOURCES_API_VERSION)?;
+
+		let block_limits = api.block_limits(at).map_err(runtime_error)?;
+
+		api.estimate_resources(
+			at,
+			request.from.unwrap_or_default(),
+			request.to,
+			request.data.unwrap_or_default(),
+			request.value.unwrap_or_default(),
+			request.gas_limit.unwrap_or(block_limits.max_gas_limit),
+			request.storage_limit.unwrap_or(block_limits.max_storage_limit),
+			request.access_list,
+			fee_currency_id,
+		)
+		.map_err(runtime_error)?
+		.map_err(runtime_error)
+	}
+}
@@ -0,0 +1,153 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the EVM tracing.
+
+use crate::error::{ensure_api_version, error, runtime_error, INVALID_PARAMS_ERROR};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
+use primitives::{
+	evm::{EstimateResourcesRequest, TraceOutcome, TracerConfig},
+	Balance, Block,
+};
+use sc_client_api::BlockBackend;
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::sync::Arc;
+
+/// The runtime api version which supports the tracing.
+const TRACING_API_VERSION: u32 = 3;
+
+/// EVM tracing RPC methods, the methods are unsafe as they replay the blocks and execute the
+/// calls without the gas being paid.
+#[rpc(server)]
+pub trait EVMTracingApi<BlockHash> {
+	/// Trace the EVM executions of the extrinsic at `extrinsic_index` in the block.
+	#[method(name = "evm_traceExtrinsic")]
+	fn trace_extrinsic(
+		&self,
+		block_hash: BlockHash,
+		extrinsic_index: u32,
+		tracer_config: TracerConfig,
+	) -> RpcResult<TraceOutcome>;
+
+	/// Trace the EVM call, or the create if `to` is not set, on top of the block state.
+	#[method(name = "evm_traceCall")]
+	fn trace_call(
+		&self,
+		request: EstimateResourcesRequest,
+		tracer_config: TracerConfig,
+		at: Option<BlockHash>,
+	) -> RpcResult<TraceOutcome>;
+}
+
+/// Provides the EVM tracing RPC methods.
+pub struct EVMTracing<C> {
+	client: Arc<C>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl<C> EVMTracing<C> {
+	/// Create new `EVMTracing` with the given reference to the client.
+	pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe }
+	}
+}
+
+#[async_trait]
+impl<C> EVMTracingApiServer<<Block as BlockT>::Hash> for EVMTracing<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<Block, Balance> + BlockBuilder<Block>,
+{
+	fn trace_extrinsic(
+		&self,
+		block_hash: <Block as BlockT>::Hash,
+		extrinsic_index: u32,
+		tracer_config: TracerConfig,
+	) -> RpcResult<TraceOutcome> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let header = self
+			.client
+			.header(block_hash)
+			.map_err(runtime_error)?
+			.ok_or_else(|| error(INVALID_PARAMS_ERROR, "Block not found"))?;
+		let extrinsics = self
+			.client
+			.block_body(block_hash)
+			.map_err(runtime_error)?
+			.ok_or_else(|| error(INVALID_PARAMS_ERROR, "Block body not found"))?;
+		let index = extrinsic_index as usize;
+		if index >= extrinsics.len() {
+			return Err(error(INVALID_PARAMS_ERROR, "Extrinsic not found"));
+		}
+
+		// replay the block on top of the parent state
+		let parent_hash = *header.parent_hash();
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, parent_hash, TRACING_API_VERSION)?;
+
+		api.initialize_block(parent_hash, &header).map_err(runtime_error)?;
+		for extrinsic in extrinsics.iter().take(index) {
+			// the failed extrinsics are part of the block too
+			let _ = api
+				.apply_extrinsic(parent_hash, extrinsic.clone())
+				.map_err(runtime_error)?;
+		}
+
+		api.trace_extrinsic(parent_hash, extrinsics[index].clone(), tracer_config)
+			.map_err(runtime_error)?
+			.map_err(runtime_error)
+	}
+
+	fn trace_call(
+		&self,
+		request: EstimateResourcesRequest,
+		tracer_config: TracerConfig,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TraceOutcome> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		ensure_api_version(&*api, at, TRACING_API_VERSION)?;
+
+		let block_limits = api.block_limits(at).map_err(runtime_error)?;
+
+		api.trace_call(
+			at,
+			request.from.unwrap_or_default(),
+			request.to,
+			request.data.unwrap_or_default(),
+			request.value.unwrap_or_default(),
+			request.gas_limit.unwrap_or(block_limits.max_gas_limit),
+			request.storage_limit.unwrap_or(block_limits.max_storage_limit),
+			request.access_list,
+			tracer_config,
+		)
+		.map_err(runtime_error)?
+		.map_err(runtime_error)
+	}
+}
@@ -35,6 +35,17 @@ pub use sc_rpc_api::{dev::DevApiServer, DenyUnsafe};
 use sc_transaction_pool_api::TransactionPool;
 use substrate_frame_rpc_system::{System, SystemApiServer};
 
+mod error;
+mod eth;
+mod evm;
+mod evm_tracing;
+mod transaction_payment;
+
+pub use eth::{Eth, EthApiServer};
+pub use evm::{EVMApiServer, EVM};
+pub use evm_tracing::{EVMTracing, EVMTracingApiServer};
+pub use transaction_payment::{TransactionFee, TransactionFeeApiServer};
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
@@ -59,6 +70,8 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance>,
+	C::Api: module_transaction_payment_rpc_runtime_api::TransactionPaymentRuntimeRPCApi<Block, AccountId>,
 	P: TransactionPool + Sync + Send + 'static,
 {
 	let mut module = RpcExtension::new(());
@@ -71,6 +84,10 @@ where
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(TransactionFee::new(client.clone()).into_rpc())?;
+	module.merge(EVM::new(client.clone()).into_rpc())?;
+	module.merge(EVMTracing::new(client.clone(), deny_unsafe).into_rpc())?;
+	module.merge(Eth::new(client.clone(), deny_unsafe).into_rpc())?;
 
 	module.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
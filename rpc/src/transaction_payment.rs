@@ -0,0 +1,91 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the transaction fee paid in other currencies.
+
+use crate::error::{error, runtime_error, INVALID_PARAMS_ERROR};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+use module_support::AggregatedSwapPath;
+use module_transaction_payment_rpc_runtime_api::TransactionPaymentRuntimeRPCApi;
+use parity_scale_codec::Decode;
+use primitives::{
+	transaction_payment::{FeeCurrency, FeeQuote},
+	AccountId, Balance, Block, CurrencyId,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// Transaction fee RPC methods.
+#[rpc(server)]
+pub trait TransactionFeeApi<BlockHash> {
+	/// Quote the fee of the encoded extrinsic signed by `who` with `tip` paid in `fee_currency`,
+	/// and whether it's exchanged from the charge fee pool or swapped from dex.
+	#[method(name = "payment_quoteFee")]
+	fn quote_fee(
+		&self,
+		who: AccountId,
+		encoded_xt: Bytes,
+		tip: Option<Balance>,
+		fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<FeeQuote>>;
+}
+
+/// Provides the transaction fee RPC methods.
+pub struct TransactionFee<C> {
+	client: Arc<C>,
+}
+
+impl<C> TransactionFee<C> {
+	/// Create new `TransactionFee` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl<C> TransactionFeeApiServer<<Block as BlockT>::Hash> for TransactionFee<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TransactionPaymentRuntimeRPCApi<Block, AccountId>,
+{
+	fn quote_fee(
+		&self,
+		who: AccountId,
+		encoded_xt: Bytes,
+		tip: Option<Balance>,
+		fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<FeeQuote>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let encoded_len = encoded_xt.len() as u32;
+		let uxt: <Block as BlockT>::Extrinsic = Decode::decode(&mut &*encoded_xt)
+			.map_err(|e| error(INVALID_PARAMS_ERROR, format!("Unable to decode the extrinsic: {:?}", e)))?;
+
+		self.client
+			.runtime_api()
+			.quote_fee(at, who, uxt, encoded_len, tip.unwrap_or_default(), fee_currency)
+			.map_err(runtime_error)
+	}
+}
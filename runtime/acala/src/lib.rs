@@ -54,10 +54,10 @@ use frame_system::{EnsureRoot, EnsureSigned, RawOrigin};
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
 use module_cdp_engine::CollateralCurrencyIds;
 use module_currencies::BasicCurrencyAdapter;
-use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
+use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmHardfork, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 use module_relaychain::RelayChainCallBuilder;
-use module_support::{AssetIdMapping, DispatchableTask, PoolId};
+use module_support::{AggregatedSwapPath, AssetIdMapping, DispatchableTask, PoolId};
 use module_transaction_payment::TargetedFeeAdjustment;
 
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
@@ -93,14 +93,18 @@ use primitives::currency::AssetIds;
 pub use primitives::{
 	define_combined_task,
 	evm::{
-		decode_gas_limit, decode_gas_price, AccessListItem, BlockLimits, EstimateResourcesRequest,
-		EthereumTransactionMessage,
+		decode_gas_limit, decode_gas_price, AccessListItem, BlockLimits, ContractStorageUsage,
+		EstimateResourcesRequest, EstimateResourcesResponse, EthereumTransactionMessage, EvmReceipt, StateOverride,
+		TraceOutcome, TracerConfig,
 	},
+	homa::ExchangeRateRecord,
+	signature::VerifyContractSignature,
 	task::TaskResult,
-	unchecked_extrinsic::AcalaUncheckedExtrinsic,
+	transaction_payment::{FeeCurrency, FeePoolInfo, FeeQuote},
+	unchecked_extrinsic::{AcalaCheckedExtrinsic, AcalaUncheckedExtrinsic},
 	AccountId, AccountIndex, Address, Amount, AuctionId, AuthoritysOriginId, Balance, BlockNumber, CurrencyId,
 	DataProviderId, DexShare, EraIndex, Hash, Lease, Moment, Multiplier, Nonce, ReserveIdentifier, Share, Signature,
-	TokenSymbol, TradingPair,
+	TokenSymbol, TradingPair, XcmTransferFee,
 };
 use runtime_common::{
 	cent, dollar, millicent, AllPrecompiles, CheckRelayNumber, CurrencyHooks, EnsureRootOrAllGeneralCouncil,
@@ -872,9 +876,8 @@ impl module_prices::Config for Runtime {
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
-	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
-	type LiquidStakingExchangeRateProvider = Homa;
+	type LiquidStakingPools = Homa;
 	type DEX = Dex;
 	type Currency = Currencies;
 	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
@@ -905,6 +908,7 @@ impl module_currencies::Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type SweepOrigin = EnsureRootOrOneGeneralCouncil;
 	type OnDust = module_currencies::TransferDust<Runtime, AcalaTreasuryAccount>;
+	type AssetStatusProvider = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1113,6 +1117,7 @@ impl module_cdp_engine::Config for Runtime {
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
 	type Swap = AcalaSwap;
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
+	type AssetStatusProvider = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1155,6 +1160,7 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
+	type AssetStatusProvider = AssetIdMaps<Runtime>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
@@ -1216,6 +1222,8 @@ parameter_types! {
 	pub const CustomFeeSurplus: Percent = Percent::from_percent(50);
 	pub const AlternativeFeeSurplus: Percent = Percent::from_percent(25);
 	pub DefaultFeeTokens: Vec<CurrencyId> = vec![AUSD, LCDOT, DOT, LDOT];
+	pub const RateRefreshPeriod: BlockNumber = HOURS;
+	pub MaxRateDeviation: Ratio = Ratio::saturating_from_rational(5, 100);
 }
 
 type NegativeImbalance = <Balances as PalletCurrency<AccountId>>::NegativeImbalance;
@@ -1253,6 +1261,9 @@ impl module_transaction_payment::Config for Runtime {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
+	type DEXPriceSource = module_dex_oracle::AverageDEXPriceProvider<Runtime>;
+	type RateRefreshPeriod = RateRefreshPeriod;
+	type MaxRateDeviation = MaxRateDeviation;
 	type WeightInfo = weights::module_transaction_payment::WeightInfo<Runtime>;
 	type PalletId = TransactionPaymentPalletId;
 	type TreasuryAccount = AcalaTreasuryAccount;
@@ -1260,6 +1271,7 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AssetStatusProvider = AssetIdMaps<Runtime>;
 }
 
 impl module_evm_accounts::Config for Runtime {
@@ -1268,15 +1280,23 @@ impl module_evm_accounts::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
 	type ChainId = EvmChainId<Runtime>;
+	type ContractSignatureVerifier = ContractSignatureVerifier;
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = EvmAddressState;
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub ForeignAssetRegistrationDeposit: Balance = 100 * dollar(ACA);
+}
+
 impl module_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type StakingCurrencyId = GetStakingCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type ForeignAssetRegistrationDeposit = ForeignAssetRegistrationDeposit;
 	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
 }
 
@@ -1300,6 +1320,7 @@ impl module_incentives::Config for Runtime {
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
 	type Currency = Currencies;
 	type EmergencyShutdown = EmergencyShutdown;
+	type EmissionVotes = Earning;
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
@@ -1386,6 +1407,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					c,
 					RuntimeCall::Homa(module_homa::Call::mint { .. })
 						| RuntimeCall::Homa(module_homa::Call::request_redeem { .. })
+						| RuntimeCall::Homa(module_homa::Call::instant_redeem { .. })
 				)
 			}
 		}
@@ -1427,8 +1449,11 @@ impl pallet_proxy::Config for Runtime {
 parameter_types! {
 	pub const NewContractExtraBytes: u32 = 10_000;
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
+	pub const Hardfork: EvmHardfork = EvmHardfork::London;
 	pub DeveloperDeposit: Balance = 50 * dollar(ACA);
 	pub PublicationFee: Balance = 10 * dollar(ACA);
+	pub const ContractUpgradeDelay: BlockNumber = 2 * DAYS;
+	pub SponsorshipDeposit: Balance = dollar(ACA);
 	pub PrecompilesValue: AllPrecompiles<
 		Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, AcalaPrecompiles<Runtime>
 	> = AllPrecompiles::<_, _, _>::acala();
@@ -1478,11 +1503,15 @@ impl module_evm::Config for Runtime {
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Runtime>;
 	type NetworkContractOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = DeveloperDeposit;
 	type PublicationFee = PublicationFee;
 	type TreasuryAccount = AcalaTreasuryAccount;
 	type FreePublicationOrigin = EnsureRootOrHalfGeneralCouncil;
+	type ContractUpgradeDelay = ContractUpgradeDelay;
+	type SponsorshipDeposit = SponsorshipDeposit;
+	type StorageDepositCheckLimit = ConstU32<20>;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type Task = ScheduledTasks;
@@ -1545,8 +1574,11 @@ impl module_homa::Config for Runtime {
 	type BondingDuration = ConstU32<28>;
 	type MintThreshold = MintThreshold;
 	type RedeemThreshold = RedeemThreshold;
+	type MaxExchangeRateHistory = ConstU32<365>;
+	type ErasPerYear = ConstU32<365>;
 	type RelayChainBlockNumber = RelaychainDataProvider<Runtime>;
 	type XcmInterface = XcmInterface;
+	type Swap = AcalaSwap;
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 }
 
@@ -1582,6 +1614,10 @@ impl module_xcm_interface::Config for Runtime {
 	type XcmTransfer = XTokens;
 	type SelfLocation = xcm_config::SelfLocation;
 	type AccountIdToMultiLocation = runtime_common::xcm_config::AccountIdToMultiLocation;
+	type CurrencyIdConvert = xcm_config::CurrencyIdConvert;
+	type XtokensTransferFee = runtime_common::XtokensTransferFee<Runtime>;
+	type BaseRate = xcm_config::BaseRate;
+	type BuyWeightRate = xcm_config::BuyWeightRates;
 }
 
 impl orml_unknown_tokens::Config for Runtime {
@@ -1717,6 +1753,7 @@ impl module_liquid_crowdloan::Config for Runtime {
 parameter_types! {
 	pub MinBond: Balance = 100 * dollar(ACA);
 	pub const UnbondingPeriod: BlockNumber = 28 * DAYS;
+	pub const MaxVeLockDuration: BlockNumber = 4 * 365 * DAYS;
 	pub const EarningLockIdentifier: LockIdentifier = *b"aca/earn";
 }
 
@@ -1727,10 +1764,17 @@ impl module_earning::Config for Runtime {
 	type OnBonded = module_incentives::OnEarningBonded<Runtime>;
 	type OnUnbonded = module_incentives::OnEarningUnbonded<Runtime>;
 	type OnUnstakeFee = Treasury; // fee goes to treasury
+	type OnAutoCompoundFee = Treasury; // fee goes to treasury
+	type IncentivesManager = Incentives;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type MinBond = MinBond;
 	type UnbondingPeriod = UnbondingPeriod;
 	type MaxUnbondingChunks = ConstU32<10>;
 	type LockIdentifier = EarningLockIdentifier;
+	type MaxAutoCompoundPerBlock = ConstU32<50>;
+	type MaxVeLockDuration = MaxVeLockDuration;
+	type MaxDexVotes = ConstU32<10>;
+	type MaxVeCheckpointPerBlock = ConstU32<50>;
 	type WeightInfo = ();
 }
 
@@ -1882,12 +1926,18 @@ pub type SignedExtra = (
 	module_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
-pub type UncheckedExtrinsic =
-	AcalaUncheckedExtrinsic<RuntimeCall, SignedExtra, ConvertEthereumTx, StorageDepositPerByte, TxFeePerGas>;
+pub type UncheckedExtrinsic = AcalaUncheckedExtrinsic<
+	RuntimeCall,
+	SignedExtra,
+	ConvertEthereumTx,
+	StorageDepositPerByte,
+	TxFeePerGas,
+	ContractSignatureVerifier,
+>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
-pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+pub type CheckedExtrinsic = AcalaCheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
 pub type Executive =
 	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem, ()>;
@@ -2079,6 +2129,51 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl module_homa_rpc_runtime_api::HomaRuntimeRPCApi<Block> for Runtime {
+		fn exchange_rate_history() -> Vec<(EraIndex, ExchangeRateRecord)> {
+			Homa::exchange_rate_history().into_inner()
+		}
+
+		fn realised_apy(eras: EraIndex) -> Option<ExchangeRate> {
+			Homa::realised_apy(eras)
+		}
+
+		fn current_exchange_rate() -> ExchangeRate {
+			Homa::current_exchange_rate()
+		}
+
+		fn projected_exchange_rate() -> ExchangeRate {
+			Homa::projected_exchange_rate()
+		}
+	}
+
+	impl module_xcm_interface_rpc_runtime_api::XcmInterfaceRuntimeRPCApi<Block> for Runtime {
+		fn estimate_transfer_fee(
+			currency_id: CurrencyId,
+			amount: Balance,
+			dest: xcm::VersionedMultiLocation,
+		) -> Option<XcmTransferFee> {
+			let dest: MultiLocation = dest.try_into().ok()?;
+			XcmInterface::estimate_transfer_fee(currency_id, amount, dest)
+		}
+	}
+
+	impl module_transaction_payment_rpc_runtime_api::TransactionPaymentRuntimeRPCApi<Block, AccountId> for Runtime {
+		fn fee_pools() -> Vec<FeePoolInfo> {
+			TransactionPayment::fee_pools()
+		}
+
+		fn quote_fee(
+			who: AccountId,
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+			fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
+		) -> Option<FeeQuote> {
+			TransactionPayment::quote_fee(&who, uxt, len, tip, fee_currency)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
@@ -2165,6 +2260,207 @@ sp_api::impl_runtime_apis! {
 
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
+
+		fn trace_extrinsic(
+			extrinsic: <Block as BlockT>::Extrinsic,
+			tracer_config: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError> {
+			#[cfg(feature = "tracing")]
+			{
+				let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config);
+				// the executions are traced even if the dispatch fails
+				let _ = tracer
+					.trace(|| Executive::apply_extrinsic(extrinsic))
+					.map_err(|e| sp_runtime::DispatchError::Other(e.into()))?;
+				Ok(tracer.finalize())
+			}
+			#[cfg(not(feature = "tracing"))]
+			{
+				let _ = (extrinsic, tracer_config);
+				Err(sp_runtime::DispatchError::Other("Tracing is not enabled"))
+			}
+		}
+
+		fn trace_call(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			tracer_config: TracerConfig,
+		) -> Result<TraceOutcome, sp_runtime::DispatchError> {
+			#[cfg(feature = "tracing")]
+			{
+				let mut tracer = module_evm::runner::tracing::Tracer::new(tracer_config);
+				let access_list = access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect();
+				tracer.trace(|| {
+					simulate_execution(|| match to {
+						Some(to) => <Runtime as module_evm::Config>::Runner::rpc_call(
+							from,
+							from,
+							to,
+							data,
+							value,
+							gas_limit,
+							storage_limit,
+							access_list,
+							<Runtime as module_evm::Config>::config(),
+						)
+						.map(|_| ()),
+						None => <Runtime as module_evm::Config>::Runner::rpc_create(
+							from,
+							data,
+							value,
+							gas_limit,
+							storage_limit,
+							access_list,
+							<Runtime as module_evm::Config>::config(),
+						)
+						.map(|_| ()),
+					})
+				})?;
+				Ok(tracer.finalize())
+			}
+			#[cfg(not(feature = "tracing"))]
+			{
+				let _ = (from, to, data, value, gas_limit, storage_limit, access_list, tracer_config);
+				Err(sp_runtime::DispatchError::Other("Tracing is not enabled"))
+			}
+		}
+
+		fn chain_id() -> u64 {
+			EVM::chain_id()
+		}
+
+		fn balance(address: H160) -> sp_core::U256 {
+			EVM::account_basic(&address).balance
+		}
+
+		fn code_at(address: H160) -> Vec<u8> {
+			EVM::code_at_address(&address).into_inner()
+		}
+
+		fn storage_at(address: H160, index: sp_core::H256) -> sp_core::H256 {
+			module_evm::AccountStorages::<Runtime>::get(address, index)
+		}
+
+		fn receipts() -> Vec<EvmReceipt> {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match (record.phase, record.event) {
+					(frame_system::Phase::ApplyExtrinsic(index), RuntimeEvent::EVM(event)) => {
+						EVM::receipt_from_event(index, event)
+					}
+					_ => None,
+				})
+				.collect()
+		}
+
+		fn estimate_resources(
+			from: H160,
+			to: Option<H160>,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			fee_currency_id: Option<CurrencyId>,
+		) -> Result<EstimateResourcesResponse, sp_runtime::DispatchError> {
+			let access_list = access_list.unwrap_or_default();
+			let mut response = EVM::estimate_resources(
+				from,
+				to,
+				data.clone(),
+				value,
+				gas_limit,
+				storage_limit,
+				access_list.iter().map(|v| (v.address, v.storage_keys.clone())).collect(),
+			)?;
+
+			// the fee of the call with the estimated limits, the signature is not included in the length
+			let call = match to {
+				Some(target) => RuntimeCall::EVM(module_evm::Call::call {
+					target,
+					input: data,
+					value,
+					gas_limit: response.gas,
+					storage_limit: response.storage,
+					access_list,
+				}),
+				None => RuntimeCall::EVM(module_evm::Call::create {
+					input: data,
+					value,
+					gas_limit: response.gas,
+					storage_limit: response.storage,
+					access_list,
+				}),
+			};
+			let info = frame_support::dispatch::GetDispatchInfo::get_dispatch_info(&call);
+			response.fee = TransactionPayment::compute_fee(call.encoded_size() as u32, &info, 0);
+			response.fee_in_currency = fee_currency_id.and_then(|currency_id| {
+				TransactionPayment::fee_in_currency(currency_id, response.fee).map(|fee| (currency_id, fee))
+			});
+
+			Ok(response)
+		}
+
+		fn call_with_state_override(
+			from: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+			state_override: StateOverride,
+		) -> Result<CallInfo, sp_runtime::DispatchError> {
+			// the overrides are discarded with the execution
+			simulate_execution(|| {
+				EVM::apply_state_override(state_override)?;
+				<Runtime as module_evm::Config>::Runner::rpc_call(
+					from,
+					from,
+					to,
+					data,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+					<Runtime as module_evm::Config>::config(),
+				)
+			})
+		}
+
+		fn contract_storage_usages(start: Option<H160>, limit: u32) -> Vec<ContractStorageUsage> {
+			EVM::contract_storage_usages(start, limit)
+		}
+
+		fn sponsored_call(
+			from: H160,
+			paymaster: H160,
+			to: H160,
+			data: Vec<u8>,
+			value: Balance,
+			gas_limit: u64,
+			storage_limit: u32,
+			access_list: Option<Vec<AccessListItem>>,
+		) -> Result<CallInfo, sp_runtime::DispatchError> {
+			// the sponsor charges are discarded with the execution
+			simulate_execution(|| {
+				EVM::sponsored_rpc_call(
+					from,
+					paymaster,
+					to,
+					data,
+					value,
+					gas_limit,
+					storage_limit,
+					access_list.unwrap_or_default().into_iter().map(|v| (v.address, v.storage_keys)).collect(),
+				)
+			})
+		}
 	}
 
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
@@ -2275,6 +2571,64 @@ cumulus_pallet_parachain_system::register_validate_block!(
 	CheckInherents = CheckInherents,
 );
 
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct ContractSignatureVerifier;
+
+impl VerifyContractSignature for ContractSignatureVerifier {
+	fn is_valid_signature(contract: primitives::evm::EvmAddress, hash: [u8; 32], signature: &[u8]) -> bool {
+		module_evm_bridge::Eip1271Verifier::<Runtime, GasToWeight>::is_valid_signature(contract, hash, signature)
+	}
+
+	fn weight() -> Weight {
+		module_evm_bridge::Eip1271Verifier::<Runtime, GasToWeight>::weight()
+	}
+}
+
+/// The maximum number of registered ERC20 tokens checked by `EvmAddressState`.
+const MAX_ERC20_BALANCE_CHECKS: u32 = 32;
+
+pub struct EvmAddressState;
+
+impl module_support::EvmAddressState<AccountId> for EvmAddressState {
+	fn is_contract(address: &primitives::evm::EvmAddress) -> bool {
+		module_evm::Pallet::<Runtime>::is_contract(address)
+	}
+
+	// Returns true if the address holds any registered ERC20 token. If more tokens are registered
+	// than can be checked, the address is treated as having balances.
+	fn has_erc20_balance(address: &primitives::evm::EvmAddress) -> bool {
+		let mut contracts = module_asset_registry::Erc20IdToAddress::<Runtime>::iter_values();
+		for contract in contracts.by_ref().take(MAX_ERC20_BALANCE_CHECKS as usize) {
+			let balance =
+				<module_evm_bridge::EVMBridge<Runtime> as module_support::EVMBridge<AccountId, Balance>>::balance_of(
+					module_support::InvokeContext {
+						contract,
+						sender: Default::default(),
+						origin: Default::default(),
+					},
+					*address,
+				);
+			if matches!(balance, Ok(balance) if balance != 0) {
+				return true;
+			}
+		}
+		contracts.next().is_some()
+	}
+
+	fn weight() -> Weight {
+		RocksDbWeight::get()
+			.reads(MAX_ERC20_BALANCE_CHECKS.saturating_add(2).into())
+			.saturating_add(
+				GasToWeight::convert(module_support::evm::limits::erc20::BALANCE_OF.gas)
+					.saturating_mul(MAX_ERC20_BALANCE_CHECKS.into()),
+			)
+	}
+
+	fn transfer_storage_deposit(from: &AccountId, to: &AccountId) -> DispatchResult {
+		module_evm::Pallet::<Runtime>::transfer_storage_deposit(from, to)
+	}
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
 pub struct ConvertEthereumTx;
 
@@ -53,13 +53,15 @@ impl<T: frame_system::Config> module_currencies::WeightInfo for WeightInfo<T> {
 	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::AssetStatuses` (r:1 w:0)
+	// Proof: `AssetRegistry::AssetStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn transfer_non_native_currency() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1610`
 		//  Estimated: `6234`
 		// Minimum execution time: 54_660 nanoseconds.
 		Weight::from_parts(55_755_000, 6234)
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: `System::Account` (r:1 w:1)
@@ -79,4 +79,40 @@ impl<T: frame_system::Config> module_evm_accounts::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::Accounts` (r:1 w:1)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::LinkNonces` (r:1 w:1)
+	// Proof: `EvmAccounts::LinkNonces` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	// Storage: `EVM::ChainId` (r:1 w:0)
+	// Proof: `EVM::ChainId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `System::BlockHash` (r:1 w:0)
+	// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::LinkedAddresses` (r:1 w:1)
+	// Proof: `EvmAccounts::LinkedAddresses` (`max_values`: None, `max_size`: Some(241), added: 2716, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn link_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2583`
+		//  Estimated: `23314`
+		// Minimum execution time: 98_214 nanoseconds.
+		Weight::from_parts(100_127_000, 23314)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: `EvmAccounts::LinkedAddresses` (r:1 w:1)
+	// Proof: `EvmAccounts::LinkedAddresses` (`max_values`: None, `max_size`: Some(241), added: 2716, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::Accounts` (r:0 w:1)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn unlink_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1290`
+		//  Estimated: `3706`
+		// Minimum execution time: 21_837 nanoseconds.
+		Weight::from_parts(22_412_000, 3706)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -39,8 +39,9 @@ use parity_scale_codec::{Decode, Encode};
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use primitives::evm::is_system_contract;
 use runtime_common::{
-	local_currency_location, native_currency_location, AcalaDropAssets, EnsureRootOrHalfGeneralCouncil,
-	EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
+	local_currency_location, native_currency_location, AcalaDropAssets, AssetStatusTransactor,
+	BuyWeightRateOfNativeCurrency, EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil,
+	FixedRateOfAsset,
 };
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 use xcm_builder::{EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, SignedToAccountId32};
@@ -104,6 +105,16 @@ pub type Trader = (
 	FixedRateOfFungible<TapPerSecond, ToTreasury>,
 );
 
+/// The rates of buying weight, used to estimate the destination fee of XTokens transfers.
+pub type BuyWeightRates = (
+	BuyWeightRateOfNativeCurrency<CurrencyIdConvert, GetNativeCurrencyId>,
+	BuyWeightRateOfTransactionFeePool<Runtime, CurrencyIdConvert>,
+	BuyWeightRateOfForeignAsset<Runtime>,
+	BuyWeightRateOfErc20<Runtime>,
+	BuyWeightRateOfStableAsset<Runtime>,
+	BuyWeightRateOfLiquidCrowdloan<Runtime>,
+);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
@@ -216,15 +227,19 @@ impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type ExecuteOverweightOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
-pub type LocalAssetTransactor = MultiCurrencyAdapter<
-	Currencies,
-	UnknownTokens,
-	IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-	AccountId,
-	LocationToAccountId,
-	CurrencyId,
+pub type LocalAssetTransactor = AssetStatusTransactor<
+	MultiCurrencyAdapter<
+		Currencies,
+		UnknownTokens,
+		IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+		AccountId,
+		LocationToAccountId,
+		CurrencyId,
+		CurrencyIdConvert,
+		DepositToAlternative<AcalaTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	>,
 	CurrencyIdConvert,
-	DepositToAlternative<AcalaTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	AssetIdMaps<Runtime>,
 >;
 
 pub struct CurrencyIdConvert;
@@ -55,7 +55,10 @@ pub use primitives::{
 	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KBTC, KINT, KSM, KUSD, LCDOT, LDOT, LKSM, PHA, TAI, TAP, VSKSM},
 	AccountId,
 };
-pub use xcm_impl::{local_currency_location, native_currency_location, AcalaDropAssets, FixedRateOfAsset, XcmExecutor};
+pub use xcm_impl::{
+	local_currency_location, native_currency_location, AcalaDropAssets, AssetStatusTransactor,
+	BuyWeightRateOfNativeCurrency, FixedRateOfAsset, XcmExecutor, XtokensTransferFee,
+};
 
 #[cfg(feature = "std")]
 use module_evm::GenesisAccount;
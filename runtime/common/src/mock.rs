@@ -22,7 +22,7 @@ use frame_support::{
 	weights::Weight,
 	ConsensusEngineId,
 };
-use module_evm::{EvmChainId, EvmTask};
+use module_evm::{EvmChainId, EvmHardfork, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 use module_support::{mocks::MockAddressMapping, DispatchableTask};
 use orml_traits::parameter_type_with_key;
@@ -179,6 +179,7 @@ impl FindAuthor<AccountId32> for AuthorGiven {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(1);
+	pub const Hardfork: EvmHardfork = EvmHardfork::TransientStorage;
 }
 
 ord_parameter_types! {
@@ -194,6 +195,9 @@ impl module_evm_accounts::Config for TestRuntime {
 	type AddressMapping = EvmAddressMapping<TestRuntime>;
 	type TransferAll = Currencies;
 	type ChainId = EvmChainId<TestRuntime>;
+	type ContractSignatureVerifier = ();
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = ();
 	type WeightInfo = ();
 }
 
@@ -212,11 +216,15 @@ impl module_evm::Config for TestRuntime {
 	type ChargeTransactionPayment = ();
 
 	type NetworkContractOrigin = frame_system::EnsureSignedBy<NetworkContractAccount, AccountId32>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = ConstU128<1000>;
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = frame_system::EnsureSignedBy<CouncilAccount, AccountId32>;
+	type ContractUpgradeDelay = ConstU64<10>;
+	type SponsorshipDeposit = ConstU128<100>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
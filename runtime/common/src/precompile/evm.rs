@@ -30,6 +30,7 @@ use module_evm::{
 use module_support::EVMManager;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::Balance;
+use sp_core::U256;
 use sp_runtime::{traits::Convert, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -44,6 +45,7 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - QueryDeveloperDeposit.
 /// - QueryPublicationFee.
 /// - TransferMaintainer. Rest `input` bytes: `from`, `contract`, `new_maintainer`.
+/// - QueryContractUpgrade. Rest `input` bytes: `contract`.
 pub struct EVMPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -60,6 +62,7 @@ pub enum Action {
 	DisableDeveloperAccount = "developerDisable(address)",
 	QueryDeveloperStatus = "developerStatus(address)",
 	PublishContract = "publishContract(address,address)",
+	QueryContractUpgrade = "contractUpgradeOf(address)",
 }
 
 impl<Runtime> Precompile for EVMPrecompile<Runtime>
@@ -231,6 +234,23 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::QueryContractUpgrade => {
+				let contract = input.evm_address_at(1)?;
+
+				// returns (0, 0) if there is no pending upgrade
+				let (code_hash, effective_block) =
+					module_evm::Pallet::<Runtime>::query_contract_upgrade(contract).unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint_tuple(vec![
+						U256::from_big_endian(code_hash.as_bytes()),
+						U256::from(effective_block),
+					]),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -256,7 +276,7 @@ where
 				let weight = PrecompileWeights::<Runtime>::evm_query_storage_deposit_per_byte();
 				WeightToGas::convert(weight)
 			}
-			Action::QueryMaintainer => {
+			Action::QueryMaintainer | Action::QueryContractUpgrade => {
 				let weight = PrecompileWeights::<Runtime>::evm_query_maintainer();
 				WeightToGas::convert(weight)
 			}
@@ -310,8 +330,8 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		alice_evm_addr, bob, bob_evm_addr, new_test_ext, EVMModule, RuntimeEvent as TestEvent, RuntimeOrigin, System,
-		Test,
+		alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, EVMModule, RuntimeEvent as TestEvent, RuntimeOrigin,
+		System, Test,
 	};
 	use frame_support::assert_ok;
 	use hex_literal::hex;
@@ -515,4 +535,73 @@ mod tests {
 			}));
 		});
 	}
+
+	#[test]
+	fn query_contract_upgrade_works() {
+		new_test_ext().execute_with(|| {
+			// pragma solidity ^0.5.0;
+			//
+			// contract Test {
+			//	 function multiply(uint a, uint b) public pure returns(uint) {
+			// 	 	return a * b;
+			// 	 }
+			// }
+			let contract = hex! {"
+				608060405234801561001057600080fd5b5060b88061001f6000396000f3fe60
+				80604052348015600f57600080fd5b506004361060285760003560e01c806316
+				5c4a1614602d575b600080fd5b606060048036036040811015604157600080fd
+				5b8101908080359060200190929190803590602001909291905050506076565b
+				6040518082815260200191505060405180910390f35b60008183029050929150
+				5056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d1
+				6b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032
+			"};
+
+			// create contract
+			let info = <Test as module_evm::Config>::Runner::create(
+				alice_evm_addr(),
+				contract.to_vec(),
+				0,
+				21_000_000,
+				21_000_000,
+				vec![],
+				<Test as module_evm::Config>::config(),
+			)
+			.unwrap();
+			let contract_address = info.value;
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// contractUpgradeOf(address) -> 0x062e6693
+			// contract_address
+			let input = hex! {"
+				062e6693
+				000000000000000000000000 5f8bd49cd9f0cb2bd5bb9d4320dfe9b61023249d
+			"};
+
+			// no pending upgrade
+			let resp = EVMPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 64].to_vec());
+
+			let new_code = hex!("6080604052").to_vec();
+			assert_ok!(EVMModule::propose_contract_upgrade(
+				RuntimeOrigin::signed(alice()),
+				contract_address,
+				new_code.clone(),
+			));
+
+			let mut expected_output = [0u8; 64];
+			expected_output[..32].copy_from_slice(module_evm::code_hash(&new_code).as_bytes());
+			// proposed at block 1 with 10 blocks delay
+			expected_output[63] = 11;
+
+			let resp = EVMPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
 }
@@ -42,10 +42,14 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// Actions:
 /// - Mint. Rest `input` bytes: `who`, `amount`.
 /// - Request redeem. Rest `input` bytes: `who`, `amount`, `fast_match`.
+/// - Instant redeem. Rest `input` bytes: `who`, `amount`, `min_redemption`.
 /// - Get exchange rate.
 /// - Get estimated reward rate.
 /// - Get commission rate.
 /// - Get fast match fee.
+/// - Get exchange rate at era. Rest `input` bytes: `era`.
+/// - Get realised APY. Rest `input` bytes: `eras`.
+/// - Get projected exchange rate.
 
 pub struct HomaPrecompile<R>(PhantomData<R>);
 
@@ -55,10 +59,14 @@ pub struct HomaPrecompile<R>(PhantomData<R>);
 pub enum Action {
 	Mint = "mint(address,uint256)",
 	RequestRedeem = "requestRedeem(address,uint256,bool)",
+	InstantRedeem = "instantRedeem(address,uint256,uint256)",
 	GetExchangeRate = "getExchangeRate()",
 	GetEstimatedRewardRate = "getEstimatedRewardRate()",
 	GetCommissionRate = "getCommissionRate()",
 	GetFastMatchFee = "getFastMatchFee()",
+	GetExchangeRateAt = "getExchangeRateAt(uint32)",
+	GetRealisedAPY = "getRealisedAPY(uint32)",
+	GetProjectedExchangeRate = "getProjectedExchangeRate()",
 }
 
 impl<Runtime> Precompile for HomaPrecompile<Runtime>
@@ -139,6 +147,35 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::InstantRedeem => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+				let min_redemption = input.balance_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"homa: instant_redeem, who: {:?}, amount: {:?}, min_redemption: {:?}",
+					&who, amount, min_redemption
+				);
+
+				let redemption = <module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::instant_redeem(
+					who,
+					amount,
+					min_redemption,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Homa InstantRedeem failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(redemption),
+					logs: Default::default(),
+				})
+			}
 			Action::GetExchangeRate => {
 				let rate =
 					<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::get_exchange_rate();
@@ -178,6 +215,41 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::GetExchangeRateAt => {
+				let era = input.u32_at(1)?;
+				let rate =
+					<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::get_exchange_rate_at(
+						era,
+					)
+					.unwrap_or_default();
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+			Action::GetRealisedAPY => {
+				let eras = input.u32_at(1)?;
+				let rate =
+					<module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::get_realised_apy(eras)
+						.unwrap_or_default();
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+			Action::GetProjectedExchangeRate => {
+				let rate = <module_homa::Pallet<Runtime> as HomaManager<Runtime::AccountId, Balance>>::get_projected_exchange_rate();
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -208,6 +280,12 @@ where
 
 				cost.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::InstantRedeem => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_homa::Config>::WeightInfo::instant_redeem();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
 			Action::GetExchangeRate => {
 				// Homa::TotalVoidLiquid (r: 1)
 				// Homa::ToBondPool (r: 1)
@@ -227,6 +305,19 @@ where
 				// Homa::FastMatchFeeRate (r: 1)
 				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
 			}
+			Action::GetExchangeRateAt | Action::GetRealisedAPY => {
+				// Homa::ExchangeRateHistory (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetProjectedExchangeRate => {
+				// Homa::TotalVoidLiquid (r: 1)
+				// Homa::ToBondPool (r: 1)
+				// Tokens::TotalIssuance(r: 1)
+				// Homa::TotalStakingBonded(r: 1)
+				// Homa::EstimatedRewardRatePerEra (r: 1)
+				// Homa::CommissionRate (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(6))
+			}
 		};
 		Ok(Self::BASE_COST.saturating_add(cost))
 	}
@@ -338,6 +429,58 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn instant_redeem_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				Some(1_000_000_000_000),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 10)),
+			));
+
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				ACA,
+				1_000_000_000
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				StakingCurrencyId::get(),
+				1_000_000_000_000
+			));
+
+			assert_ok!(Homa::mint(RuntimeOrigin::signed(alice()), 1_000_000_000));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// instantRedeem(address,uint256,uint256) => 0x66a24d0a
+			// who
+			// amount
+			// min_redemption
+			let input = hex! {"
+				66a24d0a
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000aca00
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// fully fast matched, redemption is 63_636
+			let expected_output = hex! {"00000000000000000000000000000000 0000000000000000000000000000f894"}.to_vec();
+
+			let res = HomaPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output);
+		});
+	}
+
 	#[test]
 	fn get_exchange_rate_works() {
 		new_test_ext().execute_with(|| {
@@ -421,6 +564,102 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn get_exchange_rate_at_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getExchangeRateAt(uint32) -> 0xc1dcdb0b
+			// era
+			let input = hex! {"
+				c1dcdb0b
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			// no record
+			let res = HomaPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, [0u8; 32].to_vec());
+
+			assert_ok!(Homa::force_bump_current_era(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				1
+			));
+
+			// encoded value of FixedU128::saturating_from_rational(1,10);
+			let expected_output = hex! {"00000000000000000000000000000000 0000000000000000016345785d8a0000"}.to_vec();
+
+			let res = HomaPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output);
+		});
+	}
+
+	#[test]
+	fn get_realised_apy_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Homa::force_bump_current_era(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				1
+			));
+			assert_ok!(Homa::force_bump_current_era(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				1
+			));
+
+			// getRealisedAPY(uint32) -> 0xd1499841
+			// eras
+			let input = hex! {"
+				d1499841
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+
+			// exchange rate has not changed
+			let res = HomaPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, [0u8; 32].to_vec());
+		});
+	}
+
+	#[test]
+	fn get_projected_exchange_rate_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			assert_ok!(Homa::update_homa_params(
+				RuntimeOrigin::signed(HomaAdmin::get()),
+				None,
+				Some(FixedU128::saturating_from_rational(1, 10)),
+				Some(FixedU128::saturating_from_rational(1, 2)),
+				None,
+			));
+
+			// getProjectedExchangeRate() -> 0xe6c4d5b4
+			let input = hex! {"e6c4d5b4"};
+
+			// encoded value of FixedU128::saturating_from_rational(105,1000);
+			let expected_output = hex! {"00000000000000000000000000000000 0000000000000000017508f1956a8000"}.to_vec();
+
+			let res = HomaPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output);
+		});
+	}
+
 	#[test]
 	fn get_fast_match_fee_works() {
 		new_test_ext().execute_with(|| {
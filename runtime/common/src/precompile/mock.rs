@@ -18,7 +18,7 @@
 
 #![cfg(any(test, feature = "wasm-bench"))]
 
-use crate::{AllPrecompiles, Ratio, RuntimeBlockWeights, Weight};
+use crate::{AllPrecompiles, Ratio, RuntimeBlockWeights, Weight, XtokensTransferFee};
 use frame_support::{
 	ord_parameter_types, parameter_types,
 	traits::{
@@ -30,12 +30,12 @@ use frame_support::{
 };
 use frame_system::{offchain::SendTransactionTypes, EnsureRoot, EnsureSignedBy};
 use module_cdp_engine::CollateralCurrencyIds;
-use module_evm::{EvmChainId, EvmTask};
+use module_evm::{EvmChainId, EvmHardfork, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 use module_support::{
-	mocks::MockStableAsset, AddressMapping as AddressMappingT, AuctionManager, CrowdloanVaultXcm, DEXIncentives,
-	DispatchableTask, EmergencyShutdown, ExchangeRate, ExchangeRateProvider, FractionalRate, HomaSubAccountXcm, PoolId,
-	PriceProvider, Rate, SpecificJointsSwap,
+	mocks::MockStableAsset, AddressMapping as AddressMappingT, AuctionManager, BuyWeightRate, CrowdloanVaultXcm,
+	DEXIncentives, DispatchableTask, EmergencyShutdown, ExchangeRate, FractionalRate, HomaSubAccountXcm,
+	LiquidStakingPools, PoolId, PriceProvider, Rate, SpecificJointsSwap,
 };
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key, MultiCurrency, MultiReservableCurrency};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -54,7 +54,7 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use xcm::{prelude::*, v3::Xcm};
-use xcm_builder::FixedWeightBounds;
+use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, SignedToAccountId32};
 
 pub type AccountId = AccountId32;
 type Key = CurrencyId;
@@ -186,6 +186,7 @@ impl module_currencies::Config for Test {
 	type GasToWeight = ();
 	type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
 	type OnDust = ();
+	type AssetStatusProvider = ();
 }
 
 impl module_evm_bridge::Config for Test {
@@ -198,6 +199,7 @@ impl module_asset_registry::Config for Test {
 	type StakingCurrencyId = GetStakingCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Test>;
 	type RegisterOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type ForeignAssetRegistrationDeposit = ConstU128<1000>;
 	type WeightInfo = ();
 }
 
@@ -257,6 +259,7 @@ impl orml_nft::Config for Test {
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::one();
+	pub MaxRateDeviation: Ratio = Ratio::saturating_from_rational(1, 10);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"aca/trsy");
 	pub const TransactionPaymentPalletId: PalletId = PalletId(*b"aca/fees");
 	pub KaruraTreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
@@ -283,6 +286,9 @@ impl module_transaction_payment::Config for Test {
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Test>;
+	type DEXPriceSource = ();
+	type RateRefreshPeriod = ConstU32<10>;
+	type MaxRateDeviation = MaxRateDeviation;
 	type WeightInfo = ();
 	type PalletId = TransactionPaymentPalletId;
 	type TreasuryAccount = KaruraTreasuryAccount;
@@ -290,6 +296,7 @@ impl module_transaction_payment::Config for Test {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AssetStatusProvider = ();
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -392,6 +399,7 @@ impl module_dex::Config for Test {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type AssetStatusProvider = ();
 }
 
 parameter_types! {
@@ -457,6 +465,7 @@ impl module_cdp_engine::Config for Test {
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Test>;
 	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
 	type WeightInfo = ();
+	type AssetStatusProvider = ();
 }
 
 pub struct MockAuctionManager;
@@ -565,6 +574,7 @@ pub type EvmErc20InfoMapping = module_asset_registry::EvmErc20InfoMapping<Test>;
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice_evm_addr();
+	pub const Hardfork: EvmHardfork = EvmHardfork::TransientStorage;
 	pub PrecompilesValue: AllPrecompiles<Test, module_transaction_pause::PausedPrecompileFilter<Test>, ()> = AllPrecompiles::<_, _, _>::mandala();
 }
 
@@ -595,11 +605,15 @@ impl module_evm::Config for Test {
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Test>;
 	type NetworkContractOrigin = EnsureSignedBy<NetworkContractAccount, AccountId>;
+	type Hardfork = Hardfork;
 	type NetworkContractSource = NetworkContractSource;
 	type DeveloperDeposit = ConstU128<1000>;
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type ContractUpgradeDelay = ConstU32<10>;
+	type SponsorshipDeposit = ConstU128<100>;
+	type StorageDepositCheckLimit = ConstU32<10>;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
 	type Task = ScheduledTasks;
@@ -613,13 +627,20 @@ impl module_evm_accounts::Config for Test {
 	type AddressMapping = EvmAddressMapping<Test>;
 	type ChainId = EvmChainId<Test>;
 	type TransferAll = ();
+	type ContractSignatureVerifier = ();
+	type MaxLinkedAddresses = ConstU32<10>;
+	type AddressState = ();
 	type WeightInfo = ();
 }
 
-pub struct MockLiquidStakingExchangeProvider;
-impl ExchangeRateProvider for MockLiquidStakingExchangeProvider {
-	fn get_exchange_rate() -> ExchangeRate {
-		ExchangeRate::saturating_from_rational(1, 2)
+pub struct MockLiquidStakingPools;
+impl LiquidStakingPools for MockLiquidStakingPools {
+	fn get_staking_currency_and_exchange_rate(liquid_currency_id: CurrencyId) -> Option<(CurrencyId, ExchangeRate)> {
+		if liquid_currency_id == LDOT {
+			Some((DOT, ExchangeRate::saturating_from_rational(1, 2)))
+		} else {
+			None
+		}
 	}
 }
 
@@ -646,7 +667,6 @@ parameter_type_with_key! {
 parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
-	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub MockRelayBlockNumberProvider: BlockNumber = 0;
 	pub RewardRatePerRelaychainBlock: Rate = Rate::zero();
 }
@@ -661,9 +681,8 @@ impl module_prices::Config for Test {
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
-	type GetLiquidCurrencyId = GetLiquidCurrencyId;
 	type LockOrigin = EnsureSignedBy<One, AccountId>;
-	type LiquidStakingExchangeRateProvider = MockLiquidStakingExchangeProvider;
+	type LiquidStakingPools = MockLiquidStakingPools;
 	type DEX = DexModule;
 	type Currency = Currencies;
 	type Erc20InfoMapping = EvmErc20InfoMapping;
@@ -716,6 +735,8 @@ parameter_types! {
 	pub const BondingDuration: EraIndex = 28;
 	pub const MintThreshold: Balance = 0;
 	pub const RedeemThreshold: Balance = 0;
+	pub const MaxExchangeRateHistory: u32 = 10;
+	pub const ErasPerYear: EraIndex = 365;
 }
 
 impl module_homa::Config for Test {
@@ -731,8 +752,11 @@ impl module_homa::Config for Test {
 	type BondingDuration = BondingDuration;
 	type MintThreshold = MintThreshold;
 	type RedeemThreshold = RedeemThreshold;
+	type MaxExchangeRateHistory = MaxExchangeRateHistory;
+	type ErasPerYear = ErasPerYear;
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type XcmInterface = MockHomaSubAccountXcm;
+	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
 	type WeightInfo = ();
 }
 
@@ -760,6 +784,7 @@ impl module_incentives::Config for Test {
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type Currency = Tokens;
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type EmissionVotes = ();
 	type PalletId = IncentivesPalletId;
 	type WeightInfo = ();
 }
@@ -911,6 +936,86 @@ impl orml_xtokens::Config for Test {
 	type ReserveProvider = AbsoluteReserveProvider;
 }
 
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
+}
+
+#[cfg(feature = "wasm-bench")]
+parameter_types! {
+	pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+
+impl pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = ();
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = ();
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type TrustedLockers = ();
+	type SovereignAccountOf = ();
+	type MaxLockers = ConstU32<8>;
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	#[cfg(feature = "wasm-bench")]
+	type ReachableDest = ReachableDest;
+}
+
+parameter_types! {
+	pub const ParachainAccount: AccountId = AccountId::new([0u8; 32]);
+	pub const ParachainId: module_relaychain::ParaId = module_relaychain::ParaId::new(2000);
+}
+
+pub struct SubAccountIndexMultiLocationConvertor;
+impl Convert<u16, MultiLocation> for SubAccountIndexMultiLocationConvertor {
+	fn convert(_sub_account_index: u16) -> MultiLocation {
+		(Parent, Parachain(2000)).into()
+	}
+}
+
+pub struct MockBuyWeightRate;
+impl BuyWeightRate for MockBuyWeightRate {
+	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
+		if location == MultiLocation::parent() {
+			Some(Ratio::saturating_from_rational(1, 10))
+		} else {
+			None
+		}
+	}
+}
+
+impl module_xcm_interface::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type StakingCurrencyId = StakingCurrencyId;
+	type ParachainAccount = ParachainAccount;
+	type RelayChainUnbondingSlashingSpans = ConstU32<28>;
+	type SovereignSubAccountLocationConvert = SubAccountIndexMultiLocationConvertor;
+	type RelayChainCallBuilder =
+		module_relaychain::RelayChainCallBuilder<ParachainId, module_relaychain::PolkadotRelayChainCall>;
+	type XcmTransfer = XTokens;
+	type SelfLocation = SelfLocation;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type XtokensTransferFee = XtokensTransferFee<Test>;
+	type BaseRate = ConstU128<1_000_000_000_000>;
+	type BuyWeightRate = MockBuyWeightRate;
+}
+
 parameter_types!(
 	pub CrowdloanVault: AccountId = AccountId::new([0u8; 32]);
 	pub const LiquidCrowdloanCurrencyId: CurrencyId = LCDOT;
@@ -1013,6 +1118,8 @@ frame_support::construct_runtime!(
 		Incentives: module_incentives,
 		Rewards: orml_rewards,
 		XTokens: orml_xtokens,
+		PolkadotXcm: pallet_xcm,
+		XcmInterface: module_xcm_interface,
 		StableAsset: nutsfinance_stable_asset,
 		LiquidCrowdloan: module_liquid_crowdloan,
 	}
@@ -23,18 +23,21 @@ use super::{
 use crate::WeightToGas;
 use frame_support::{
 	pallet_prelude::IsType,
-	traits::{Currency, Get},
+	traits::{Currency, Get, UnixTime},
 };
 use module_currencies::WeightInfo;
 use module_evm::{
 	precompiles::Precompile,
 	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
-	Context, ExitError, ExitRevert, ExitSucceed,
+	Context, ExitError, ExitRevert, ExitSucceed, Log,
 };
-use module_support::Erc20InfoMapping as Erc20InfoMappingT;
+use module_evm_utility_macro::keccak256;
+use module_support::{AddressMapping, Erc20InfoMapping as Erc20InfoMappingT};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::MultiCurrency as MultiCurrencyT;
-use primitives::{currency::DexShare, Balance, CurrencyId};
+use primitives::{currency::DexShare, evm::EvmAddress, to_bytes, Balance, CurrencyId};
+use sp_core::{H256, U256};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
 use sp_runtime::{traits::Convert, AccountId32, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -47,6 +50,12 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query total issuance.
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Transfer. Rest `input` bytes: `from`, `to`, `amount`.
+/// - Query allowance. Rest `input` bytes: `owner`, `spender`.
+/// - Approve, increase or decrease allowance. Rest `input` bytes: `owner`, `spender`, `amount`.
+/// - Transfer from. Rest `input` bytes: `spender`, `from`, `to`, `amount`.
+/// - EIP-2612 permit. Rest `input` bytes: `owner`, `spender`, `value`, `deadline`, `v`, `r`, `s`.
+/// - Query EIP-2612 nonces. Rest `input` bytes: `owner`.
+/// - Query EIP-712 domain separator.
 pub struct MultiCurrencyPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -60,14 +69,27 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	Transfer = "transfer(address,address,uint256)",
 	TransferToAccountId = "transferToAccountId(address,bytes32,uint256)",
+	QueryAllowance = "allowance(address,address)",
+	Approve = "approve(address,address,uint256)",
+	IncreaseAllowance = "increaseAllowance(address,address,uint256)",
+	DecreaseAllowance = "decreaseAllowance(address,address,uint256)",
+	TransferFrom = "transferFrom(address,address,address,uint256)",
+	Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+	QueryNonces = "nonces(address)",
+	QueryDomainSeparator = "DOMAIN_SEPARATOR()",
 }
 
 impl<Runtime> Precompile for MultiCurrencyPrecompile<Runtime>
 where
-	Runtime:
-		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
+	Runtime: module_currencies::Config
+		+ module_evm::Config
+		+ module_prices::Config
+		+ module_transaction_payment::Config
+		+ pallet_timestamp::Config,
 	Runtime::AccountId: IsType<AccountId32>,
 	module_currencies::Pallet<Runtime>: MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+	<Runtime as module_currencies::Config>::MultiCurrency:
+		MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
 {
 	fn execute(input: &[u8], target_gas: Option<u64>, context: &Context, _is_static: bool) -> PrecompileResult {
 		let input = Input::<
@@ -229,8 +251,208 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::QueryAllowance => {
+				let owner = input.account_id_at(1)?;
+				let spender = input.account_id_at(2)?;
+				let allowance = module_currencies::Pallet::<Runtime>::allowance(currency_id, &owner, &spender);
+				log::debug!(target: "evm", "multicurrency: owner: {:?}, spender: {:?}, allowance: {:?}", owner, spender, allowance);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(allowance),
+					logs: Default::default(),
+				})
+			}
+			Action::Approve | Action::IncreaseAllowance | Action::DecreaseAllowance => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let amount = input.balance_at(3)?;
+				log::debug!(target: "evm", "multicurrency: {:?} owner: {:?}, spender: {:?}, amount: {:?}", action, owner, spender, amount);
+
+				let owner_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&owner);
+				let spender_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&spender);
+				let allowance = match action {
+					Action::Approve => {
+						module_currencies::Pallet::<Runtime>::approve(
+							currency_id,
+							&owner_account,
+							&spender_account,
+							amount,
+						);
+						amount
+					}
+					Action::IncreaseAllowance => module_currencies::Pallet::<Runtime>::increase_allowance(
+						currency_id,
+						&owner_account,
+						&spender_account,
+						amount,
+					),
+					_ => module_currencies::Pallet::<Runtime>::decrease_allowance(
+						currency_id,
+						&owner_account,
+						&spender_account,
+						amount,
+					)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Multicurrency DecreaseAllowance failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?,
+				};
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: vec![Self::approval_log(context.caller, owner, spender, allowance)],
+				})
+			}
+			Action::TransferFrom => {
+				let spender = input.account_id_at(1)?;
+				let from = input.account_id_at(2)?;
+				let to = input.account_id_at(3)?;
+				let amount = input.balance_at(4)?;
+				log::debug!(target: "evm", "multicurrency: transferFrom spender: {:?}, from: {:?}, to: {:?}, amount: {:?}", spender, from, to, amount);
+
+				module_currencies::Pallet::<Runtime>::transfer_from(currency_id, &spender, &from, &to, amount)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Multicurrency TransferFrom failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::Permit => {
+				let owner = input.evm_address_at(1)?;
+				let spender = input.evm_address_at(2)?;
+				let value = input.balance_at(3)?;
+				let deadline = input.u256_at(4)?;
+				let v = input.u32_at(5)?;
+				let r = input.bytes32_at(6)?;
+				let s = input.bytes32_at(7)?;
+				log::debug!(target: "evm", "multicurrency: permit owner: {:?}, spender: {:?}, value: {:?}, deadline: {:?}", owner, spender, value, deadline);
+
+				let revert = |output: &str| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: output.into(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				};
+
+				let now = <pallet_timestamp::Pallet<Runtime> as UnixTime>::now().as_secs();
+				if deadline < U256::from(now) {
+					return Err(revert("Multicurrency Permit failed: expired deadline"));
+				}
+
+				let owner_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&owner);
+				let spender_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&spender);
+				let nonce = module_currencies::Pallet::<Runtime>::permit_nonces(currency_id, &owner_account);
+
+				let domain_separator =
+					Self::domain_separator(currency_id, context.caller).ok_or_else(|| revert("Get name failed"))?;
+				let mut permit_msg =
+					keccak256!("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+						.to_vec();
+				permit_msg.extend_from_slice(H256::from(owner).as_bytes());
+				permit_msg.extend_from_slice(H256::from(spender).as_bytes());
+				permit_msg.extend_from_slice(&to_bytes(value));
+				permit_msg.extend_from_slice(&to_bytes(nonce));
+				permit_msg.extend_from_slice(&to_bytes(deadline));
+
+				let mut msg = b"\x19\x01".to_vec();
+				msg.extend_from_slice(&domain_separator);
+				msg.extend_from_slice(&keccak_256(&permit_msg));
+
+				let mut sig = [0u8; 65];
+				sig[..32].copy_from_slice(&r);
+				sig[32..64].copy_from_slice(&s);
+				sig[64] = v
+					.try_into()
+					.map_err(|_| revert("Multicurrency Permit failed: invalid signature"))?;
+
+				let signer = secp256k1_ecdsa_recover(&sig, &keccak_256(&msg))
+					.map(|pubkey| EvmAddress::from(H256::from_slice(&keccak_256(&pubkey))))
+					.map_err(|_| revert("Multicurrency Permit failed: invalid signature"))?;
+				if signer != owner {
+					return Err(revert("Multicurrency Permit failed: invalid signature"));
+				}
+
+				module_currencies::Pallet::<Runtime>::use_permit_nonce(currency_id, &owner_account);
+				module_currencies::Pallet::<Runtime>::approve(currency_id, &owner_account, &spender_account, value);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: vec![Self::approval_log(context.caller, owner, spender, value)],
+				})
+			}
+			Action::QueryNonces => {
+				let owner = input.account_id_at(1)?;
+				let nonce = module_currencies::Pallet::<Runtime>::permit_nonces(currency_id, &owner);
+				log::debug!(target: "evm", "multicurrency: owner: {:?}, nonce: {:?}", owner, nonce);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(nonce),
+					logs: Default::default(),
+				})
+			}
+			Action::QueryDomainSeparator => {
+				let domain_separator =
+					Self::domain_separator(currency_id, context.caller).ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "Get name failed".into(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_fixed_bytes(&domain_separator),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+impl<Runtime> MultiCurrencyPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config,
+{
+	/// The ERC-20 `Approval(address,address,uint256)` log of the mirrored token.
+	fn approval_log(token: EvmAddress, owner: EvmAddress, spender: EvmAddress, amount: Balance) -> Log {
+		Log {
+			address: token,
+			topics: vec![
+				H256::from_slice(keccak256!("Approval(address,address,uint256)")),
+				H256::from(owner),
+				H256::from(spender),
+			],
+			data: Output::encode_uint(amount),
 		}
 	}
+
+	/// The EIP-712 domain separator of the mirrored token, used by the EIP-2612 permit.
+	fn domain_separator(currency_id: CurrencyId, token: EvmAddress) -> Option<[u8; 32]> {
+		let name = Runtime::Erc20InfoMapping::name(currency_id)?;
+
+		let mut domain_separator_msg =
+			keccak256!("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)").to_vec();
+		domain_separator_msg.extend_from_slice(&keccak_256(&name)); // name
+		domain_separator_msg.extend_from_slice(keccak256!("1")); // version
+		domain_separator_msg.extend_from_slice(&to_bytes(module_evm::Pallet::<Runtime>::chain_id())); // chain id
+		domain_separator_msg.extend_from_slice(H256::from(token).as_bytes()); // verifying contract
+		Some(keccak_256(&domain_separator_msg))
+	}
 }
 
 struct Pricer<R>(PhantomData<R>);
@@ -241,6 +463,8 @@ where
 		module_currencies::Config + module_evm::Config + module_prices::Config + module_transaction_payment::Config,
 {
 	const BASE_COST: u64 = 200;
+	// The gas cost of the ecrecover precompile.
+	const ECRECOVER_COST: u64 = 3_000;
 
 	fn cost(
 		input: &Input<
@@ -282,6 +506,59 @@ where
 
 				cost.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::QueryAllowance => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Currencies::Allowances (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::Approve | Action::IncreaseAllowance | Action::DecreaseAllowance => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// Currencies::Allowances (r: 1, w: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1),
+				))
+			}
+			Action::TransferFrom => {
+				let cost = InputPricer::<Runtime>::read_accounts(3);
+
+				// transfer weight
+				let weight = if currency_id == <Runtime as module_transaction_payment::Config>::NativeCurrencyId::get()
+				{
+					<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
+				} else {
+					<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()
+				};
+
+				// Currencies::Allowances (r: 1, w: 1)
+				cost.saturating_add(WeightToGas::convert(weight))
+					.saturating_add(WeightToGas::convert(
+						<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1),
+					))
+			}
+			Action::Permit => {
+				let cost = InputPricer::<Runtime>::read_accounts(2).saturating_add(Self::ECRECOVER_COST);
+				// Timestamp::Now (r: 1), EVM::ChainId (r: 1)
+				// Currencies::PermitNonces (r: 1, w: 1), Currencies::Allowances (w: 1)
+				cost.saturating_add(Self::erc20_info(currency_id))
+					.saturating_add(WeightToGas::convert(
+						<Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 2),
+					))
+			}
+			Action::QueryNonces => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Currencies::PermitNonces (r: 1)
+				cost.saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
+			Action::QueryDomainSeparator => {
+				// EVM::ChainId (r: 1)
+				Self::erc20_info(currency_id).saturating_add(WeightToGas::convert(
+					<Runtime as frame_system::Config>::DbWeight::get().reads(1),
+				))
+			}
 		};
 
 		Ok(Self::BASE_COST.saturating_add(read_currency).saturating_add(cost))
@@ -312,11 +589,12 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		aca_evm_address, alice, ausd_evm_address, bob, erc20_address_not_exists, lp_aca_ausd_evm_address, new_test_ext,
-		Balances, Test,
+		aca_evm_address, alice, ausd_evm_address, bob, bob_evm_addr, erc20_address_not_exists, lp_aca_ausd_evm_address,
+		new_test_ext, Balances, Test, Timestamp, ACA,
 	};
 	use frame_support::assert_noop;
 	use hex_literal::hex;
+	use sp_core::{ecdsa, Pair};
 
 	type MultiCurrencyPrecompile = crate::MultiCurrencyPrecompile<Test>;
 
@@ -635,4 +913,222 @@ mod tests {
 			);
 		})
 	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			// approve(address,address,uint256) -> 0xe1f21c67
+			// owner
+			// spender
+			// amount
+			let input = hex! {"
+				e1f21c67
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+			assert_eq!(
+				resp.logs,
+				vec![Log {
+					address: aca_evm_address(),
+					topics: vec![
+						H256::from(hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925")),
+						H256::from(hex!("0000000000000000000000001000000000000000000000000000000000000001")),
+						H256::from(hex!("0000000000000000000000001000000000000000000000000000000000000002")),
+					],
+					data: hex!("000000000000000000000000000000000000000000000000000000000000000a").to_vec(),
+				}]
+			);
+
+			// allowance(address,address) -> 0xdd62ed3e
+			// owner
+			// spender
+			let input = hex! {"
+				dd62ed3e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+
+			let expected_output = hex! {"
+				00000000000000000000000000000000 0000000000000000000000000000000a
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// transferFrom(address,address,address,uint256) -> 0x15dacbea
+			// spender
+			// from
+			// to
+			// amount
+			let input = hex! {"
+				15dacbea
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000004
+			"};
+
+			let from_balance = Balances::free_balance(alice());
+			let to_balance = Balances::free_balance(bob());
+
+			let resp = MultiCurrencyPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 0].to_vec());
+
+			assert_eq!(Balances::free_balance(alice()), from_balance - 4);
+			assert_eq!(Balances::free_balance(bob()), to_balance + 4);
+			assert_eq!(module_currencies::Pallet::<Test>::allowance(ACA, &alice(), &bob()), 6);
+
+			// decreaseAllowance(address,address,uint256) -> 0xd73b1dc9
+			// owner
+			// spender
+			// amount
+			let input = hex! {"
+				d73b1dc9
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000007
+			"};
+
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&input, Some(100_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency DecreaseAllowance failed: InsufficientAllowance".into(),
+					cost: target_gas_limit(Some(100_000)).unwrap(),
+				}
+			);
+
+			// increaseAllowance(address,address,uint256) -> 0x6c43a2ca
+			// owner
+			// spender
+			// amount
+			let input = hex! {"
+				6c43a2ca
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp = MultiCurrencyPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.logs[0].data, Output::encode_uint(7u128));
+			assert_eq!(module_currencies::Pallet::<Test>::allowance(ACA, &alice(), &bob()), 7);
+		})
+	}
+
+	#[test]
+	fn permit_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: aca_evm_address(),
+				apparent_value: Default::default(),
+			};
+
+			let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+			let sign = |msg: &[u8; 32]| -> [u8; 65] {
+				let mut sig = [0u8; 65];
+				sig.copy_from_slice(pair.sign_prehashed(msg).as_ref());
+				sig
+			};
+			let owner = EvmAddress::from(H256::from_slice(&keccak_256(
+				&secp256k1_ecdsa_recover(&sign(&[0u8; 32]), &[0u8; 32]).unwrap(),
+			)));
+			let owner_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&owner);
+			let spender = bob_evm_addr();
+			let deadline = U256::from(u64::MAX);
+
+			// DOMAIN_SEPARATOR() -> 0x3644e515
+			let input = hex! {"
+				3644e515
+			"};
+
+			let domain_separator = MultiCurrencyPrecompile::domain_separator(ACA, aca_evm_address()).unwrap();
+			let resp = MultiCurrencyPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, domain_separator.to_vec());
+
+			let mut permit_msg =
+				keccak256!("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
+					.to_vec();
+			permit_msg.extend_from_slice(H256::from(owner).as_bytes());
+			permit_msg.extend_from_slice(H256::from(spender).as_bytes());
+			permit_msg.extend_from_slice(&to_bytes(10u128));
+			permit_msg.extend_from_slice(&to_bytes(0u32));
+			permit_msg.extend_from_slice(&to_bytes(deadline));
+			let mut msg = b"\x19\x01".to_vec();
+			msg.extend_from_slice(&domain_separator);
+			msg.extend_from_slice(&keccak_256(&permit_msg));
+			let sig = sign(&keccak_256(&msg));
+
+			// permit(address,address,uint256,uint256,uint8,bytes32,bytes32) -> 0xd505accf
+			// owner
+			// spender
+			// value
+			// deadline
+			// v
+			// r
+			// s
+			let mut input = hex!("d505accf").to_vec();
+			input.extend_from_slice(H256::from(owner).as_bytes());
+			input.extend_from_slice(H256::from(spender).as_bytes());
+			input.extend_from_slice(&to_bytes(10u128));
+			input.extend_from_slice(&to_bytes(deadline));
+			input.extend_from_slice(&to_bytes(sig[64] + 27));
+			input.extend_from_slice(&sig[..64]);
+
+			let resp = MultiCurrencyPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				module_currencies::Pallet::<Test>::allowance(ACA, &owner_account, &bob()),
+				10
+			);
+
+			// nonces(address) -> 0x7ecebe00
+			// owner
+			let mut nonces_input = hex!("7ecebe00").to_vec();
+			nonces_input.extend_from_slice(H256::from(owner).as_bytes());
+
+			let resp = MultiCurrencyPrecompile::execute(&nonces_input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(1u32));
+
+			// replay
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&input, Some(100_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency Permit failed: invalid signature".into(),
+					cost: target_gas_limit(Some(100_000)).unwrap(),
+				}
+			);
+
+			// expired
+			Timestamp::set_timestamp(2_000);
+			let mut expired_input = input[..100].to_vec();
+			expired_input.extend_from_slice(&to_bytes(1u32));
+			expired_input.extend_from_slice(&input[132..]);
+			assert_noop!(
+				MultiCurrencyPrecompile::execute(&expired_input, Some(100_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Multicurrency Permit failed: expired deadline".into(),
+					cost: target_gas_limit(Some(100_000)).unwrap(),
+				}
+			);
+		})
+	}
 }
@@ -17,31 +17,50 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{
-	input::{Input, InputT, Output},
+	input::{Input, InputPricer, InputT, Output},
 	target_gas_limit,
 };
-use frame_support::traits::tokens::nonfungibles::{Inspect, Transfer};
+use crate::WeightToGas;
+use ethabi::Token;
+use frame_support::traits::{
+	tokens::nonfungibles::{Inspect, Transfer},
+	Get,
+};
 use module_evm::{
 	precompiles::Precompile,
 	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
-	Context, ExitError, ExitRevert, ExitSucceed,
+	Context, ExitError, ExitRevert, ExitSucceed, Log,
 };
-use module_support::AddressMapping;
+use module_evm_utility_macro::keccak256;
+use module_nft::WeightInfo;
+use module_support::{evm::limits::erc721, AddressMapping, ExecutionMode, InvokeContext, EVM as EVMTrait};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::InspectExtended;
-use primitives::nft::NFTBalance;
-use sp_core::H160;
-use sp_runtime::RuntimeDebug;
+use primitives::{nft::NFTBalance, to_bytes};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Convert, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `NFT` impl precompile.
 ///
 /// `input` data starts with `action`.
 ///
+/// Tokens are identified by `class_id` and `token_id`, the `tokenId` of the `Transfer`, `Approval`
+/// logs is `class_id << 64 | token_id`.
+///
 /// Actions:
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Approve. Rest `input` bytes: `caller`, `approved`, `class_id`, `token_id`.
+/// - Query approved. Rest `input` bytes: `class_id`, `token_id`.
+/// - Set approval for all. Rest `input` bytes: `owner`, `operator`, `class_id`, `approved`.
+/// - Query approved for all. Rest `input` bytes: `owner`, `operator`, `class_id`.
+/// - Transfer from. Rest `input` bytes: `spender`, `from`, `to`, `class_id`, `token_id`.
+/// - Safe transfer from. Rest `input` bytes: `spender`, `from`, `to`, `class_id`, `token_id`, `data`.
+/// - Query token URI. Rest `input` bytes: `class_id`, `token_id`.
+/// - Mint. Rest `input` bytes: `caller`, `to`, `class_id`, `metadata`.
+/// - Burn. Rest `input` bytes: `caller`, `class_id`, `token_id`.
 pub struct NFTPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -51,16 +70,28 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	Approve = "approve(address,address,uint256,uint256)",
+	QueryApproved = "getApproved(uint256,uint256)",
+	SetApprovalForAll = "setApprovalForAll(address,address,uint256,bool)",
+	QueryApprovedForAll = "isApprovedForAll(address,address,uint256)",
+	TransferFrom = "transferFrom(address,address,address,uint256,uint256)",
+	SafeTransferFrom = "safeTransferFrom(address,address,address,uint256,uint256,bytes)",
+	QueryTokenURI = "tokenURI(uint256,uint256)",
+	Mint = "mint(address,address,uint256,bytes)",
+	Burn = "burn(address,uint256,uint256)",
 }
 
 impl<Runtime> Precompile for NFTPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
+	Runtime: module_evm::Config
+		+ module_prices::Config
+		+ module_nft::Config
+		+ orml_nft::Config<ClassId = u32, TokenId = u64>,
 	module_nft::Pallet<Runtime>: InspectExtended<Runtime::AccountId, Balance = NFTBalance>
 		+ Inspect<Runtime::AccountId, ItemId = u64, CollectionId = u32>
 		+ Transfer<Runtime::AccountId>,
 {
-	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
+	fn execute(input: &[u8], target_gas: Option<u64>, context: &Context, _is_static: bool) -> PrecompileResult {
 		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
 			input,
 			target_gas_limit(target_gas),
@@ -88,7 +119,7 @@ where
 
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
-					cost: 0,
+					cost: gas_cost,
 					output: Output::encode_uint(balance),
 					logs: Default::default(),
 				})
@@ -99,16 +130,13 @@ where
 
 				log::debug!(target: "evm", "nft: query_owner class_id: {:?}, token_id: {:?}", class_id, token_id);
 
-				let owner: H160 = if let Some(o) = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id) {
-					Runtime::AddressMapping::get_evm_address(&o)
-						.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(&o))
-				} else {
-					Default::default()
-				};
+				let owner: H160 = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id)
+					.map(|o| Self::evm_address(&o))
+					.unwrap_or_default();
 
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
-					cost: 0,
+					cost: gas_cost,
 					output: Output::encode_address(owner),
 					logs: Default::default(),
 				})
@@ -131,13 +159,313 @@ where
 
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
-					cost: 0,
+					cost: gas_cost,
 					output: vec![],
 					logs: Default::default(),
 				})
 			}
+			Action::Approve => {
+				let caller = input.account_id_at(1)?;
+				let approved = input.evm_address_at(2)?;
+				let class_id = input.u32_at(3)?;
+				let token_id = input.u64_at(4)?;
+
+				log::debug!(target: "evm", "nft: approve caller: {:?}, approved: {:?}, class_id: {:?}, token_id: {:?}", caller, approved, class_id, token_id);
+
+				let approved_account = if approved.is_zero() {
+					None
+				} else {
+					Some(Runtime::AddressMapping::get_account_id(&approved))
+				};
+				module_nft::Pallet::<Runtime>::approve(&caller, approved_account, (class_id, token_id)).map_err(
+					|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT Approve failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					},
+				)?;
+				let owner = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id)
+					.map(|o| Self::evm_address(&o))
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: vec![Log {
+						address: context.address,
+						topics: vec![
+							H256::from_slice(keccak256!("Approval(address,address,uint256)")),
+							owner.into(),
+							approved.into(),
+							Self::token_id_topic(class_id, token_id),
+						],
+						data: vec![],
+					}],
+				})
+			}
+			Action::QueryApproved => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+
+				log::debug!(target: "evm", "nft: query_approved class_id: {:?}, token_id: {:?}", class_id, token_id);
+
+				let approved: H160 = module_nft::Pallet::<Runtime>::token_approvals(class_id, token_id)
+					.map(|a| Self::evm_address(&a))
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_address(approved),
+					logs: Default::default(),
+				})
+			}
+			Action::SetApprovalForAll => {
+				let owner = input.evm_address_at(1)?;
+				let operator = input.evm_address_at(2)?;
+				let class_id = input.u32_at(3)?;
+				let approved = input.bool_at(4)?;
+
+				log::debug!(target: "evm", "nft: set_approval_for_all owner: {:?}, operator: {:?}, class_id: {:?}, approved: {:?}", owner, operator, class_id, approved);
+
+				module_nft::Pallet::<Runtime>::set_approval_for_all(
+					&Runtime::AddressMapping::get_account_id(&owner),
+					&Runtime::AddressMapping::get_account_id(&operator),
+					class_id,
+					approved,
+				);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: vec![Log {
+						address: context.address,
+						topics: vec![
+							H256::from_slice(keccak256!("ApprovalForAll(address,address,bool)")),
+							owner.into(),
+							operator.into(),
+						],
+						data: Output::encode_bool(approved),
+					}],
+				})
+			}
+			Action::QueryApprovedForAll => {
+				let owner = input.account_id_at(1)?;
+				let operator = input.account_id_at(2)?;
+				let class_id = input.u32_at(3)?;
+
+				log::debug!(target: "evm", "nft: query_approved_for_all owner: {:?}, operator: {:?}, class_id: {:?}", owner, operator, class_id);
+
+				let approved = module_nft::Pallet::<Runtime>::operator_approvals((class_id, owner), operator);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_bool(approved),
+					logs: Default::default(),
+				})
+			}
+			Action::TransferFrom | Action::SafeTransferFrom => {
+				let spender = input.evm_address_at(1)?;
+				let from = input.evm_address_at(2)?;
+				let to = input.evm_address_at(3)?;
+				let class_id = input.u32_at(4)?;
+				let token_id = input.u64_at(5)?;
+
+				log::debug!(target: "evm", "nft: transfer_from spender: {:?}, from: {:?}, to: {:?}, class_id: {:?}, token_id: {:?}", spender, from, to, class_id, token_id);
+
+				module_nft::Pallet::<Runtime>::transfer_from(
+					&Runtime::AddressMapping::get_account_id(&spender),
+					&Runtime::AddressMapping::get_account_id(&from),
+					&Runtime::AddressMapping::get_account_id(&to),
+					(class_id, token_id),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("NFT TransferFrom failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				if action == Action::SafeTransferFrom && module_evm::Pallet::<Runtime>::is_contract(&to) {
+					let data = input.bytes_at(6)?;
+					if !Self::check_on_erc721_received(context.address, spender, from, to, class_id, token_id, data) {
+						return Err(PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: "NFT SafeTransferFrom failed: transfer to non ERC721Receiver implementer".into(),
+							cost: target_gas_limit(target_gas).unwrap_or_default(),
+						});
+					}
+				}
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: vec![Self::transfer_log(context.address, from, to, class_id, token_id)],
+				})
+			}
+			Action::QueryTokenURI => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+
+				log::debug!(target: "evm", "nft: query_token_uri class_id: {:?}, token_id: {:?}", class_id, token_id);
+
+				let uri = module_nft::Pallet::<Runtime>::token_uri(class_id, token_id).ok_or_else(|| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "NFT TokenURI failed: TokenIdNotFound".into(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_bytes(&uri),
+					logs: Default::default(),
+				})
+			}
+			Action::Mint => {
+				let caller = input.account_id_at(1)?;
+				let to = input.evm_address_at(2)?;
+				let class_id = input.u32_at(3)?;
+				let metadata = input.bytes_at(4)?;
+
+				log::debug!(target: "evm", "nft: mint caller: {:?}, to: {:?}, class_id: {:?}", caller, to, class_id);
+
+				let token_ids = module_nft::Pallet::<Runtime>::mint_by_class_owner(
+					&caller,
+					&Runtime::AddressMapping::get_account_id(&to),
+					class_id,
+					metadata,
+					Default::default(),
+					1,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("NFT Mint failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+				let token_id = token_ids[0];
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(token_id),
+					logs: vec![Self::transfer_log(
+						context.address,
+						Default::default(),
+						to,
+						class_id,
+						token_id,
+					)],
+				})
+			}
+			Action::Burn => {
+				let caller = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+
+				log::debug!(target: "evm", "nft: burn caller: {:?}, class_id: {:?}, token_id: {:?}", caller, class_id, token_id);
+
+				let owner = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id)
+					.map(|o| Self::evm_address(&o))
+					.unwrap_or_default();
+				module_nft::Pallet::<Runtime>::burn_from(&caller, (class_id, token_id)).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT Burn failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: vec![Self::transfer_log(
+						context.address,
+						owner,
+						Default::default(),
+						class_id,
+						token_id,
+					)],
+				})
+			}
+		}
+	}
+}
+
+impl<Runtime> NFTPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_nft::Config,
+{
+	fn evm_address(who: &Runtime::AccountId) -> H160 {
+		Runtime::AddressMapping::get_evm_address(who)
+			.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(who))
+	}
+
+	/// The ERC-721 `tokenId` of the token: `class_id << 64 | token_id`.
+	fn erc721_token_id(class_id: u32, token_id: u64) -> U256 {
+		U256::from(class_id) << 64 | U256::from(token_id)
+	}
+
+	fn token_id_topic(class_id: u32, token_id: u64) -> H256 {
+		H256(to_bytes(Self::erc721_token_id(class_id, token_id)))
+	}
+
+	/// The ERC-721 `Transfer(address,address,uint256)` log.
+	fn transfer_log(address: H160, from: H160, to: H160, class_id: u32, token_id: u64) -> Log {
+		Log {
+			address,
+			topics: vec![
+				H256::from_slice(keccak256!("Transfer(address,address,uint256)")),
+				from.into(),
+				to.into(),
+				Self::token_id_topic(class_id, token_id),
+			],
+			data: vec![],
 		}
 	}
+
+	/// Call `onERC721Received(address,address,uint256,bytes)` of the receiver contract, returns
+	/// whether the receiver accepts the token.
+	fn check_on_erc721_received(
+		address: H160,
+		operator: H160,
+		from: H160,
+		to: H160,
+		class_id: u32,
+		token_id: u64,
+		data: Vec<u8>,
+	) -> bool {
+		let selector = keccak256!("onERC721Received(address,address,uint256,bytes)");
+		let mut input = selector[..4].to_vec();
+		input.extend_from_slice(&ethabi::encode(&[
+			Token::Address(operator),
+			Token::Address(from),
+			Token::Uint(Self::erc721_token_id(class_id, token_id)),
+			Token::Bytes(data),
+		]));
+
+		<module_evm::Pallet<Runtime> as EVMTrait<Runtime::AccountId>>::execute(
+			InvokeContext {
+				contract: to,
+				sender: address,
+				origin: operator,
+			},
+			input,
+			Default::default(),
+			erc721::ON_ERC721_RECEIVED.gas,
+			erc721::ON_ERC721_RECEIVED.storage,
+			ExecutionMode::Execute,
+		)
+		.map_or(false, |info| {
+			info.exit_reason.is_succeed() && info.value.get(..4) == Some(&selector[..4])
+		})
+	}
 }
 
 pub struct Pricer<R>(PhantomData<R>);
@@ -151,8 +479,380 @@ where
 	fn cost(
 		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
 	) -> Result<u64, PrecompileFailure> {
-		let _action = input.action()?;
-		// TODO: gas cost
-		Ok(Self::BASE_COST)
+		let action = input.action()?;
+		let cost = match action {
+			Action::QueryBalance => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				// OrmlNFT::TokensByOwner (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::QueryOwner => {
+				// OrmlNFT::Tokens (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+				Self::BASE_COST.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Transfer => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::transfer();
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Approve => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				// OrmlNFT::Tokens (r: 1)
+				// NFT::OperatorApprovals (r: 1)
+				// NFT::TokenApprovals (w: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 1);
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::QueryApproved => {
+				// NFT::TokenApprovals (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+				Self::BASE_COST.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SetApprovalForAll => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				// NFT::OperatorApprovals (w: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().writes(1);
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::QueryApprovedForAll => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				// NFT::OperatorApprovals (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::TransferFrom | Action::SafeTransferFrom => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(3);
+				// NFT::TokenApprovals (r: 1)
+				// NFT::OperatorApprovals (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::transfer()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(2));
+				let cost = Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight));
+
+				if action == Action::SafeTransferFrom {
+					// the callback of `onERC721Received` to the receiver contract
+					// EVM::Accounts (r: 1)
+					let is_contract = WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+					cost.saturating_add(is_contract)
+						.saturating_add(erc721::ON_ERC721_RECEIVED.gas)
+				} else {
+					cost
+				}
+			}
+			Action::QueryTokenURI => {
+				// OrmlNFT::Tokens (r: 1)
+				// OrmlNFT::Classes (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+				Self::BASE_COST.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Mint => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(2);
+				// OrmlNFT::Classes (r: 1)
+				// Proxy::Proxies (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::mint(1)
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(2));
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Burn => {
+				let read_accounts = InputPricer::<Runtime>::read_accounts(1);
+				// NFT::TokenApprovals (r: 1)
+				// NFT::OperatorApprovals (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				let weight = <Runtime as module_nft::Config>::WeightInfo::burn()
+					.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(3));
+				Self::BASE_COST
+					.saturating_add(read_accounts)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(cost)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, NFTModule, RuntimeOrigin, Test,
+	};
+	use frame_support::assert_ok;
+	use primitives::nft::{ClassProperty, Properties};
+
+	type NFTPrecompile = crate::precompile::NFTPrecompile<Test>;
+
+	fn context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		}
+	}
+
+	fn encode_input(action: Action, tokens: Vec<Token>) -> Vec<u8> {
+		let mut input = Into::<u32>::into(action).to_be_bytes().to_vec();
+		input.extend_from_slice(&ethabi::encode(&tokens));
+		input
+	}
+
+	fn create_class() {
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(alice()),
+			b"ipfs://class".to_vec(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+	}
+
+	fn mint(to: H160) -> PrecompileOutput {
+		let input = encode_input(
+			Action::Mint,
+			vec![
+				Token::Address(alice_evm_addr()),
+				Token::Address(to),
+				Token::Uint(0.into()),
+				Token::Bytes(b"ipfs://token".to_vec()),
+			],
+		);
+		NFTPrecompile::execute(&input, None, &context(), false).unwrap()
+	}
+
+	#[test]
+	fn mint_and_burn_works() {
+		new_test_ext().execute_with(|| {
+			create_class();
+
+			let resp = mint(bob_evm_addr());
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, Output::encode_uint(0u64));
+			assert_eq!(
+				resp.logs,
+				vec![NFTPrecompile::transfer_log(
+					Default::default(),
+					Default::default(),
+					bob_evm_addr(),
+					0,
+					0
+				)]
+			);
+
+			let input = encode_input(Action::QueryOwner, vec![Token::Uint(0.into()), Token::Uint(0.into())]);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.output, Output::encode_address(bob_evm_addr()));
+
+			let input = encode_input(
+				Action::QueryTokenURI,
+				vec![Token::Uint(0.into()), Token::Uint(0.into())],
+			);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.output, Output::encode_bytes(b"ipfs://token"));
+
+			// alice is not approved to burn the token of bob
+			let input = encode_input(
+				Action::Burn,
+				vec![
+					Token::Address(alice_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Uint(0.into()),
+				],
+			);
+			assert!(matches!(
+				NFTPrecompile::execute(&input, None, &context(), false),
+				Err(PrecompileFailure::Revert { .. })
+			));
+
+			let input = encode_input(
+				Action::Burn,
+				vec![
+					Token::Address(bob_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Uint(0.into()),
+				],
+			);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(module_nft::Pallet::<Test>::owner(&0, &0), None);
+
+			let input = encode_input(
+				Action::QueryTokenURI,
+				vec![Token::Uint(0.into()), Token::Uint(0.into())],
+			);
+			assert!(matches!(
+				NFTPrecompile::execute(&input, None, &context(), false),
+				Err(PrecompileFailure::Revert { .. })
+			));
+		});
+	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			create_class();
+			mint(alice_evm_addr());
+
+			let transfer_from = encode_input(
+				Action::TransferFrom,
+				vec![
+					Token::Address(bob_evm_addr()),
+					Token::Address(alice_evm_addr()),
+					Token::Address(bob_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Uint(0.into()),
+				],
+			);
+			assert!(matches!(
+				NFTPrecompile::execute(&transfer_from, None, &context(), false),
+				Err(PrecompileFailure::Revert { .. })
+			));
+
+			let input = encode_input(
+				Action::Approve,
+				vec![
+					Token::Address(alice_evm_addr()),
+					Token::Address(bob_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Uint(0.into()),
+				],
+			);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+
+			let input = encode_input(
+				Action::QueryApproved,
+				vec![Token::Uint(0.into()), Token::Uint(0.into())],
+			);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.output, Output::encode_address(bob_evm_addr()));
+
+			let resp = NFTPrecompile::execute(&transfer_from, None, &context(), false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				resp.logs,
+				vec![NFTPrecompile::transfer_log(
+					Default::default(),
+					alice_evm_addr(),
+					bob_evm_addr(),
+					0,
+					0
+				)]
+			);
+			assert_eq!(module_nft::Pallet::<Test>::owner(&0, &0), Some(bob()));
+
+			// the approval is cleared by the transfer
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.output, Output::encode_address(H160::default()));
+		});
+	}
+
+	#[test]
+	fn set_approval_for_all_works() {
+		new_test_ext().execute_with(|| {
+			create_class();
+			mint(bob_evm_addr());
+
+			let query = encode_input(
+				Action::QueryApprovedForAll,
+				vec![
+					Token::Address(bob_evm_addr()),
+					Token::Address(alice_evm_addr()),
+					Token::Uint(0.into()),
+				],
+			);
+			let resp = NFTPrecompile::execute(&query, None, &context(), false).unwrap();
+			assert_eq!(resp.output, Output::encode_bool(false));
+
+			let input = encode_input(
+				Action::SetApprovalForAll,
+				vec![
+					Token::Address(bob_evm_addr()),
+					Token::Address(alice_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Bool(true),
+				],
+			);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+
+			let resp = NFTPrecompile::execute(&query, None, &context(), false).unwrap();
+			assert_eq!(resp.output, Output::encode_bool(true));
+
+			// the operator transfers the token of bob
+			let input = encode_input(
+				Action::SafeTransferFrom,
+				vec![
+					Token::Address(alice_evm_addr()),
+					Token::Address(bob_evm_addr()),
+					Token::Address(alice_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Uint(0.into()),
+					Token::Bytes(vec![]),
+				],
+			);
+			let resp = NFTPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(module_nft::Pallet::<Test>::owner(&0, &0), Some(alice()));
+		});
+	}
+
+	#[test]
+	fn pricer_works() {
+		new_test_ext().execute_with(|| {
+			create_class();
+			mint(alice_evm_addr());
+
+			let transfer_from = encode_input(
+				Action::TransferFrom,
+				vec![
+					Token::Address(alice_evm_addr()),
+					Token::Address(alice_evm_addr()),
+					Token::Address(bob_evm_addr()),
+					Token::Uint(0.into()),
+					Token::Uint(0.into()),
+				],
+			);
+			let mut safe_transfer_from = transfer_from.clone();
+			safe_transfer_from[..4].copy_from_slice(&Into::<u32>::into(Action::SafeTransferFrom).to_be_bytes());
+			safe_transfer_from.extend_from_slice(&ethabi::encode(&[Token::Bytes(vec![])]));
+
+			let cost_of = |input: &[u8]| {
+				Pricer::<Test>::cost(&Input::<
+					Action,
+					<Test as frame_system::Config>::AccountId,
+					<Test as module_evm::Config>::AddressMapping,
+					<Test as module_evm::Config>::Erc20InfoMapping,
+				>::new(input, None))
+				.unwrap()
+			};
+
+			// SafeTransferFrom pays for the `onERC721Received` callback
+			assert!(cost_of(&safe_transfer_from) - cost_of(&transfer_from) > erc721::ON_ERC721_RECEIVED.gas);
+
+			let gas_cost = cost_of(&transfer_from);
+			assert!(matches!(
+				NFTPrecompile::execute(&transfer_from, Some(gas_cost - 1), &context(), false),
+				Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas
+				})
+			));
+			let resp = NFTPrecompile::execute(&transfer_from, Some(gas_cost), &context(), false).unwrap();
+			assert_eq!(resp.cost, gas_cost);
+		});
 	}
 }
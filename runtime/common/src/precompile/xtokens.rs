@@ -52,6 +52,7 @@ use xcm::{
 /// - TransferMultiCurrencies. Rest `input` bytes: `who`, `currencies`, `fee_item`, `dest`,
 ///   `weight`.
 /// - TransferMultiAssets. Rest `input` bytes: `who`, `assets`, `fee_item`, `dest`, `weight`.
+/// - EstimateTransferFee. Rest `input` bytes: `currency_id`, `amount`, `dest`.
 pub struct XtokensPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -64,11 +65,12 @@ pub enum Action {
 	TransferMultiAssetWithFee = "transferMultiAssetWithFee(address,bytes,bytes,bytes,bytes)",
 	TransferMultiCurrencies = "transferMultiCurrencies(address,(address,uint256)[],uint32,bytes,bytes)",
 	TransferMultiAssets = "transferMultiAssets(address,bytes,uint32,bytes,bytes)",
+	EstimateTransferFee = "estimateTransferFee(address,uint256,bytes)",
 }
 
 impl<Runtime> Precompile for XtokensPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + orml_xtokens::Config + module_prices::Config,
+	Runtime: module_evm::Config + orml_xtokens::Config + module_prices::Config + module_xcm_interface::Config,
 	orml_xtokens::Pallet<Runtime>: XcmTransfer<Runtime::AccountId, Balance, CurrencyId>,
 	<Runtime as orml_xtokens::Config>::CurrencyId: IsType<CurrencyId>,
 	<Runtime as orml_xtokens::Config>::Balance: IsType<Balance>,
@@ -444,6 +446,31 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::EstimateTransferFee => {
+				let currency_id = input.currency_id_at(1)?;
+				let amount = input.balance_at(2)?;
+
+				let dest_bytes: &[u8] = &input.bytes_at(3)?[..];
+				let dest: MultiLocation = decode_multi_location(dest_bytes).ok_or(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid dest".into(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				let fee = module_xcm_interface::Pallet::<Runtime>::estimate_transfer_fee(currency_id, amount, dest)
+					.ok_or(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "Xtoken EstimateTransferFee failed".into(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint_tuple(vec![fee.local_fee, fee.destination_fee, fee.amount_received]),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -464,7 +491,7 @@ struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
 where
-	Runtime: module_evm::Config + orml_xtokens::Config + module_prices::Config,
+	Runtime: module_evm::Config + orml_xtokens::Config + module_prices::Config + module_xcm_interface::Config,
 	<Runtime as orml_xtokens::Config>::CurrencyId: IsType<CurrencyId>,
 	<Runtime as orml_xtokens::Config>::Balance: IsType<Balance>,
 {
@@ -618,6 +645,23 @@ where
 
 				Self::BASE_COST.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::EstimateTransferFee => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// Destination fee, priced for the currencies buying weight:
+				// XcmInterface::XcmDestWeightAndFee (r: 1)
+				// CurrencyIdConvert, e.g. AssetRegistry::ForeignAssetLocations (r: 1)
+				// BuyWeightRates, walking up to 6 impls in the worst case: NativeCurrency (r: 1),
+				// TransactionFeePool (r: 2), ForeignAsset (r: 3), Erc20 (r: 1), StableAsset (r: 1) and
+				// LiquidCrowdloan (r: 1)
+				// Local fee:
+				// CurrencyIdConvert of `weight_of_transfer` (r: 1)
+				// TransactionPayment::NextFeeMultiplier (r: 1)
+				let read_fee = WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(13));
+
+				Self::BASE_COST.saturating_add(read_currency).saturating_add(read_fee)
+			}
 		};
 		Ok(cost)
 	}
@@ -628,10 +672,13 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{alice_evm_addr, new_test_ext, Test, BOB};
-	use frame_support::weights::Weight;
+	use frame_support::weights::{constants::ExtrinsicBaseWeight, Weight};
 	use hex_literal::hex;
 	use module_evm::ExitRevert;
+	use module_xcm_interface::XcmInterfaceOperation;
 	use orml_utilities::with_transaction_result;
+	use primitives::Multiplier;
+	use sp_runtime::FixedPointNumber;
 
 	type XtokensPrecompile = crate::precompile::XtokensPrecompile<Test>;
 
@@ -1048,4 +1095,83 @@ mod tests {
 			});
 		});
 	}
+
+	#[test]
+	fn estimate_transfer_fee_works() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			let dest: VersionedMultiLocation = VersionedMultiLocation::V3(MultiLocation::new(
+				1,
+				X1(Junction::AccountId32 {
+					network: None,
+					id: BOB.into(),
+				}),
+			));
+			assert_eq!(
+				dest.encode(),
+				hex!("03010101000202020202020202020202020202020202020202020202020202020202020202")
+			);
+
+			module_xcm_interface::XcmDestWeightAndFee::<Test>::insert(
+				XcmInterfaceOperation::XtokensTransfer,
+				(Weight::from_parts(1_000_000_000, 0), 2_000),
+			);
+			module_transaction_payment::NextFeeMultiplier::<Test>::put(Multiplier::saturating_from_integer(2));
+
+			// estimateTransferFee(address,uint256,bytes) -> 0x32c2c46d
+			// currency
+			// amount
+			// dest offset
+			// dest length
+			// dest
+			let input = hex! {"
+				32c2c46d
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 00000000000000000000000000000025
+				0301010100020202020202020202020202020202020202020202020202020202
+				0202020202000000000000000000000000000000000000000000000000000000
+			"};
+
+			let resp = XtokensPrecompile::execute(&input, None, &context, true).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			// local fee: the base fee, the length fee of the 58 bytes XTokens call, and the weight fee
+			// of BaseXcmWeight + UnitWeightCost * 2 instructions (WithdrawAsset and
+			// InitiateReserveWithdraw) adjusted by NextFeeMultiplier
+			let local_fee = ExtrinsicBaseWeight::get().ref_time() as u128 + 58 * 10 + 2 * 100_000_020;
+			// destination fee: the `XtokensTransfer` fee of the staking currency
+			assert_eq!(
+				resp.output,
+				Output::encode_uint_tuple(vec![local_fee, 2_000, 999_998_000])
+			);
+			// BASE_COST + read DOT + 13 reads of RocksDbWeight: 200 + 200 + 325_000_000 / 9000
+			assert_eq!(resp.cost, 36_511);
+
+			// estimateTransferFee(address,uint256,bytes) -> 0x32c2c46d
+			// currency: ACA, can't buy weight on the destination
+			let input = hex! {"
+				32c2c46d
+				000000000000000000000000 0000000000000000000100000000000000000000
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				00000000000000000000000000000000 00000000000000000000000000000060
+				00000000000000000000000000000000 00000000000000000000000000000025
+				0301010100020202020202020202020202020202020202020202020202020202
+				0202020202000000000000000000000000000000000000000000000000000000
+			"};
+
+			assert_eq!(
+				XtokensPrecompile::execute(&input, Some(100_000), &context, true),
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "Xtoken EstimateTransferFee failed".into(),
+					cost: 90_000,
+				})
+			);
+		});
+	}
 }
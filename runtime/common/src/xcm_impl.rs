@@ -18,18 +18,18 @@
 
 //! Common xcm implementation
 
-use frame_support::{traits::Get, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
-use module_support::BuyWeightRate;
+use frame_support::{dispatch::GetDispatchInfo, traits::Get, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
+use module_support::{AssetStatusProvider, BuyWeightRate, Ratio};
 use orml_traits::GetByKey;
 use parity_scale_codec::Encode;
-use primitives::{evm::EvmAddress, Balance, CurrencyId};
+use primitives::{currency::AssetStatus, evm::EvmAddress, Balance, CurrencyId};
 use sp_core::bounded::BoundedVec;
 use sp_runtime::{traits::Convert, FixedPointNumber, FixedU128};
 use sp_std::{marker::PhantomData, prelude::*};
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 use xcm_builder::TakeRevenue;
 use xcm_executor::{
-	traits::{DropAssets, WeightTrader},
+	traits::{DropAssets, TransactAsset, WeightTrader},
 	Assets,
 };
 
@@ -115,6 +115,45 @@ where
 	}
 }
 
+/// The native currency buys weight at the base rate.
+pub struct BuyWeightRateOfNativeCurrency<CurrencyIdConvert, NativeCurrencyId>(
+	PhantomData<(CurrencyIdConvert, NativeCurrencyId)>,
+);
+
+impl<CurrencyIdConvert, NativeCurrencyId> BuyWeightRate
+	for BuyWeightRateOfNativeCurrency<CurrencyIdConvert, NativeCurrencyId>
+where
+	CurrencyIdConvert: Convert<MultiLocation, Option<CurrencyId>>,
+	NativeCurrencyId: Get<CurrencyId>,
+{
+	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
+		(CurrencyIdConvert::convert(location) == Some(NativeCurrencyId::get())).then(Ratio::one)
+	}
+}
+
+/// The fee of the XTokens transfer extrinsic in native currency, computed by the transaction
+/// payment module with the length fee and the fee multiplier.
+pub struct XtokensTransferFee<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Convert<(CurrencyId, Balance, MultiLocation), Balance> for XtokensTransferFee<Runtime>
+where
+	Runtime: orml_xtokens::Config<CurrencyId = CurrencyId, Balance = Balance> + module_transaction_payment::Config,
+	<Runtime as module_transaction_payment::Config>::RuntimeCall: From<orml_xtokens::Call<Runtime>>,
+{
+	fn convert((currency_id, amount, dest): (CurrencyId, Balance, MultiLocation)) -> Balance {
+		let call: <Runtime as module_transaction_payment::Config>::RuntimeCall =
+			orml_xtokens::Call::<Runtime>::transfer {
+				currency_id,
+				amount,
+				dest: Box::new(dest.into()),
+				dest_weight_limit: WeightLimit::Unlimited,
+			}
+			.into();
+		let info = call.get_dispatch_info();
+		module_transaction_payment::Pallet::<Runtime>::compute_fee(call.encoded_size() as u32, &info, 0)
+	}
+}
+
 /// Simple fee calculator that requires payment in a single fungible at a fixed rate.
 ///
 /// - The `FixedRate` constant should be the concrete fungible ID and the amount of it
@@ -275,6 +314,82 @@ impl<
 	}
 }
 
+/// Asset transactor which enforces the asset status lifecycle on top of `Transactor`:
+/// deprecated assets can't be deposited, frozen assets can't be deposited, withdrawn or
+/// transferred.
+pub struct AssetStatusTransactor<Transactor, CurrencyIdConvert, StatusProvider>(
+	PhantomData<(Transactor, CurrencyIdConvert, StatusProvider)>,
+);
+
+impl<Transactor, CurrencyIdConvert, StatusProvider> AssetStatusTransactor<Transactor, CurrencyIdConvert, StatusProvider>
+where
+	CurrencyIdConvert: Convert<MultiLocation, Option<CurrencyId>>,
+	StatusProvider: AssetStatusProvider<CurrencyId>,
+{
+	fn asset_status(what: &MultiAsset) -> AssetStatus {
+		match what.id {
+			Concrete(location) => CurrencyIdConvert::convert(location)
+				.map(StatusProvider::asset_status)
+				.unwrap_or_default(),
+			Abstract(_) => AssetStatus::Active,
+		}
+	}
+}
+
+impl<Transactor, CurrencyIdConvert, StatusProvider> TransactAsset
+	for AssetStatusTransactor<Transactor, CurrencyIdConvert, StatusProvider>
+where
+	Transactor: TransactAsset,
+	CurrencyIdConvert: Convert<MultiLocation, Option<CurrencyId>>,
+	StatusProvider: AssetStatusProvider<CurrencyId>,
+{
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, context: Option<&XcmContext>) -> XcmResult {
+		if Self::asset_status(what) != AssetStatus::Active {
+			return Err(XcmError::FailedToTransactAsset("asset is not active"));
+		}
+		Transactor::deposit_asset(what, who, context)
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		if Self::asset_status(what) == AssetStatus::Frozen {
+			return Err(XcmError::FailedToTransactAsset("asset is frozen"));
+		}
+		Transactor::withdraw_asset(what, who, maybe_context)
+	}
+
+	fn internal_transfer_asset(
+		what: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		if Self::asset_status(what) == AssetStatus::Frozen {
+			return Err(XcmError::FailedToTransactAsset("asset is frozen"));
+		}
+		Transactor::internal_transfer_asset(what, from, to, context)
+	}
+}
+
 /// Convert `AccountKey20` to `AccountId`
 pub struct AccountKey20Aliases<Network, AccountId, AddressMapping>(PhantomData<(Network, AccountId, AddressMapping)>);
 impl<Network, AccountId, AddressMapping> xcm_executor::traits::ConvertLocation<AccountId>
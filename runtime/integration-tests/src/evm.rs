@@ -21,6 +21,7 @@ use crate::setup::*;
 use frame_support::{
 	assert_ok,
 	dispatch::{DispatchClass, DispatchInfo, Pays},
+	traits::NamedReservableCurrency,
 };
 use module_asset_registry::EvmErc20InfoMapping;
 use module_evm_accounts::EvmAddressMapping;
@@ -806,6 +807,263 @@ fn test_default_evm_address_in_evm_accounts_module() {
 		});
 }
 
+#[test]
+fn link_address_in_evm_accounts_module() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY, 1_000_000_000 * dollar(NATIVE_CURRENCY)),
+			(bob(), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+			(
+				// evm alice
+				MockAddressMapping::get_account_id(&alice_evm_addr()),
+				NATIVE_CURRENCY,
+				1_000_000_000 * dollar(NATIVE_CURRENCY),
+			),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_erc20_contracts();
+
+			let alice_address = EvmAccounts::eth_address(&alice_key());
+			let bob_address = EvmAccounts::eth_address(&bob_key());
+			assert_ok!(EvmAccounts::claim_account(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				alice_address,
+				EvmAccounts::eth_sign(&alice_key(), &AccountId::from(ALICE))
+			));
+
+			// the address holding ERC20 tokens can't be linked
+			<EVM as EVMTrait<AccountId>>::set_origin(alice());
+			assert_ok!(Currencies::transfer(
+				RuntimeOrigin::signed(EvmAddressMapping::<Runtime>::get_account_id(&alice_evm_addr())),
+				sp_runtime::MultiAddress::Id(bob()),
+				CurrencyId::Erc20(erc20_address_0()),
+				10
+			));
+			assert_noop!(
+				EvmAccounts::link_address(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					bob_address,
+					EvmAccounts::eth_sign_link(&bob_key(), &AccountId::from(ALICE), 0)
+				),
+				module_evm_accounts::Error::<Runtime>::EthAddressHasErc20Balance
+			);
+
+			<EVM as EVMTrait<AccountId>>::set_origin(bob());
+			assert_ok!(Currencies::transfer(
+				RuntimeOrigin::signed(bob()),
+				sp_runtime::MultiAddress::Id(alice()),
+				CurrencyId::Erc20(erc20_address_0()),
+				10
+			));
+			assert_ok!(EvmAccounts::link_address(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				bob_address,
+				EvmAccounts::eth_sign_link(&bob_key(), &AccountId::from(ALICE), 0)
+			));
+			assert_eq!(
+				EvmAddressMapping::<Runtime>::get_account_id(&bob_address),
+				AccountId::from(ALICE)
+			);
+			assert_eq!(Balances::free_balance(bob()), 0);
+
+			// the calls of the account run as its primary address
+			let mut input = hex_literal::hex!("70a08231").to_vec();
+			input.extend_from_slice(H256::from(bob_address).as_bytes());
+			assert_ok!(EVM::call(
+				RuntimeOrigin::signed(EvmAddressMapping::<Runtime>::get_account_id(&bob_address)),
+				erc20_address_0(),
+				input,
+				0,
+				100_000,
+				0,
+				vec![]
+			));
+			assert!(matches!(
+				System::events().last().unwrap().event,
+				RuntimeEvent::EVM(module_evm::Event::Executed { from, .. }) if from == alice_address
+			));
+		});
+}
+
+fn deploy_eip1271_contract() -> EvmAddress {
+	// A smart contract wallet accepting the signatures equal to the hash:
+	//
+	// function isValidSignature(bytes32 hash, bytes signature) returns (bytes4) {
+	//     if (signature[0:32] == hash) return 0x1626ba7e;
+	//     return 0x00000000;
+	// }
+	let code = hex_literal::hex!("603980600b6000396000f360003560e01c631626ba7e14601357600080fd5b60043560643514602857600060005260206000f35b631626ba7e60e01b60005260206000f3").to_vec();
+	assert_ok!(EVM::create(
+		RuntimeOrigin::signed(alice()),
+		code,
+		0,
+		1_000_000,
+		100_000,
+		vec![]
+	));
+
+	let contract = if let RuntimeEvent::EVM(module_evm::Event::Created { contract, .. }) =
+		System::events().last().unwrap().event
+	{
+		contract
+	} else {
+		panic!("deploy contract failed");
+	};
+	assert_ok!(EVM::publish_free(RuntimeOrigin::root(), contract));
+	contract
+}
+
+#[test]
+fn claim_contract_account_with_eip1271_contract() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+			(AccountId::from(ALICE), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			let contract = deploy_eip1271_contract();
+			let contract_account_id = EvmAddressMapping::<Runtime>::get_account_id(&contract);
+			let deposit =
+				Balances::reserved_balance_named(&module_evm::RESERVE_ID_STORAGE_DEPOSIT, &contract_account_id);
+			assert!(deposit > 0);
+
+			let hash = EvmAccounts::eip712_claim_hash(&AccountId::from(ALICE));
+			assert_noop!(
+				EvmAccounts::claim_contract_account(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					contract,
+					H256::zero().as_bytes().to_vec()
+				),
+				module_evm_accounts::Error::<Runtime>::InvalidSignature
+			);
+			// the signature of another account
+			assert_noop!(
+				EvmAccounts::claim_contract_account(
+					RuntimeOrigin::signed(AccountId::from(BOB)),
+					contract,
+					hash.to_vec()
+				),
+				module_evm_accounts::Error::<Runtime>::InvalidSignature
+			);
+
+			assert_ok!(EvmAccounts::claim_contract_account(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				contract,
+				hash.to_vec()
+			));
+			assert_eq!(
+				EvmAddressMapping::<Runtime>::get_account_id(&contract),
+				AccountId::from(ALICE)
+			);
+
+			// the storage deposit moves to the new account of the contract
+			assert_eq!(
+				Balances::reserved_balance_named(&module_evm::RESERVE_ID_STORAGE_DEPOSIT, &contract_account_id),
+				0
+			);
+			assert_eq!(
+				Balances::reserved_balance_named(&module_evm::RESERVE_ID_STORAGE_DEPOSIT, &AccountId::from(ALICE)),
+				deposit
+			);
+		});
+}
+
+#[cfg(feature = "with-mandala-runtime")]
+#[test]
+fn eip1271_signed_extrinsic_works() {
+	use frame_support::dispatch::GetDispatchInfo;
+	use mandala_runtime::{ContractSignatureVerifier, ConvertEthereumTx, SignedExtra};
+	use primitives::{
+		signature::{AcalaMultiSignature, VerifyContractSignature},
+		unchecked_extrinsic::{eip712_message_hash, MAX_CONTRACT_SIGNATURE_LEN},
+	};
+	use sp_runtime::{
+		traits::{Checkable, Convert, Extrinsic},
+		transaction_validity::InvalidTransaction,
+	};
+
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+			(AccountId::from(ALICE), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+		])
+		.build()
+		.execute_with(|| {
+			let contract = deploy_eip1271_contract();
+
+			let call = RuntimeCall::EVM(module_evm::Call::eth_call {
+				action: module_evm::TransactionAction::Call(contract),
+				input: vec![],
+				value: 0,
+				gas_limit: 21_000,
+				storage_limit: 0,
+				valid_until: 30,
+				access_list: vec![],
+			});
+			let extra: SignedExtra = (
+				frame_system::CheckNonZeroSender::<Runtime>::new(),
+				frame_system::CheckSpecVersion::<Runtime>::new(),
+				frame_system::CheckTxVersion::<Runtime>::new(),
+				frame_system::CheckGenesis::<Runtime>::new(),
+				frame_system::CheckEra::<Runtime>::from(sp_runtime::generic::Era::Immortal),
+				runtime_common::CheckNonce::<Runtime>::from(1),
+				frame_system::CheckWeight::<Runtime>::new(),
+				module_evm::SetEvmOrigin::<Runtime>::new(),
+				module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+			);
+			let (eth_msg, _) = ConvertEthereumTx::convert((call.clone(), extra.clone())).unwrap();
+			let hash = eip712_message_hash(eth_msg);
+
+			let new_uxt = |signature: Vec<u8>| {
+				UncheckedExtrinsic::new(
+					call.clone(),
+					Some((
+						sp_runtime::MultiAddress::Address20(contract.into()),
+						AcalaMultiSignature::AcalaEip1271(contract, signature),
+						extra.clone(),
+					)),
+				)
+				.unwrap()
+			};
+			let context = frame_system::ChainContext::<Runtime>::default();
+
+			assert_eq!(
+				new_uxt(H256::zero().as_bytes().to_vec()).check(&context).err(),
+				Some(InvalidTransaction::BadProof.into())
+			);
+			assert_eq!(
+				new_uxt(vec![0u8; MAX_CONTRACT_SIGNATURE_LEN + 1]).check(&context).err(),
+				Some(InvalidTransaction::BadProof.into())
+			);
+
+			// the contract must claim an account first
+			assert_eq!(
+				new_uxt(hash.to_vec()).check(&context).err(),
+				Some(InvalidTransaction::BadProof.into())
+			);
+			assert_ok!(EvmAccounts::claim_contract_account(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				contract,
+				EvmAccounts::eip712_claim_hash(&AccountId::from(ALICE)).to_vec()
+			));
+
+			// the weight of verifying the signature is charged on top of the call
+			let weight = call.get_dispatch_info().weight + ContractSignatureVerifier::weight();
+			let uxt = new_uxt(hash.to_vec());
+			assert_eq!(uxt.get_dispatch_info().weight, weight);
+
+			let checked = uxt.check(&context).unwrap();
+			assert_eq!(
+				checked.signed.as_ref().map(|(who, _)| who.clone()),
+				Some(AccountId::from(ALICE))
+			);
+			assert_eq!(checked.signature_weight, ContractSignatureVerifier::weight());
+			assert_eq!(checked.get_dispatch_info().weight, weight);
+		});
+}
+
 #[test]
 fn transaction_payment_module_works_with_evm_contract() {
 	let erc20_token = CurrencyId::Erc20(erc20_address_0());
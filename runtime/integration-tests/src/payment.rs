@@ -20,9 +20,11 @@ use crate::setup::*;
 use crate::stable_asset::enable_stable_asset;
 use frame_support::{
 	dispatch::{DispatchClass, DispatchInfo, Pays, PostDispatchInfo},
+	traits::WithdrawReasons,
 	weights::Weight,
 };
 use module_support::AggregatedSwapPath;
+use primitives::transaction_payment::FeeCurrency;
 use sp_runtime::{
 	traits::{AccountIdConversion, SignedExtension, UniqueSaturatedInto},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
@@ -372,13 +374,18 @@ fn charge_transaction_payment_and_threshold_works() {
 
 #[test]
 fn with_fee_currency_call_works() {
-	with_fee_call_works(with_fee_currency_call(LIQUID_CURRENCY), false);
+	with_fee_call_works(
+		with_fee_currency_call(LIQUID_CURRENCY),
+		FeeCurrency::Currency(LIQUID_CURRENCY),
+		false,
+	);
 }
 
 #[test]
 fn with_fee_path_call_works() {
 	with_fee_call_works(
 		with_fee_path_call(vec![LIQUID_CURRENCY, USD_CURRENCY, NATIVE_CURRENCY]),
+		FeeCurrency::SwapPath(vec![LIQUID_CURRENCY, USD_CURRENCY, NATIVE_CURRENCY]),
 		false,
 	);
 }
@@ -389,11 +396,16 @@ fn with_fee_aggregated_path_call_works() {
 		AggregatedSwapPath::<CurrencyId>::Taiga(0, 0, 1),
 		AggregatedSwapPath::<CurrencyId>::Dex(vec![LIQUID_CURRENCY, USD_CURRENCY, NATIVE_CURRENCY]),
 	];
-	with_fee_call_works(with_fee_aggregated_path_call(aggregated_path), true);
+	with_fee_call_works(
+		with_fee_aggregated_path_call(aggregated_path.clone()),
+		FeeCurrency::AggregatedPath(RELAY_CHAIN_CURRENCY, aggregated_path),
+		true,
+	);
 }
 
 fn with_fee_call_works(
 	with_fee_call: <Runtime as module_transaction_payment::Config>::RuntimeCall,
+	fee_currency: FeeCurrency<AggregatedSwapPath<CurrencyId>>,
 	is_aggregated_call: bool,
 ) {
 	let init_amount = 100 * dollar(LIQUID_CURRENCY);
@@ -449,6 +461,25 @@ fn with_fee_call_works(
 				),
 				TransactionValidityError::Invalid(InvalidTransaction::Payment)
 			);
+			assert_eq!(
+				TransactionPayment::quote_fee(&AccountId::from(BOB), CALL, 50, 0, FeeCurrency::Auto),
+				None
+			);
+
+			// the quote is what is taken from Bob, including the top up of native token to keep Bob alive.
+			let fee = TransactionPayment::compute_fee(50, &INFO, 0);
+			let quote = TransactionPayment::quote_fee_in_currency(
+				&AccountId::from(BOB),
+				fee_currency,
+				fee,
+				WithdrawReasons::TRANSACTION_PAYMENT,
+			)
+			.unwrap();
+			assert_eq!(
+				quote.top_up,
+				<Currencies as MultiCurrency<AccountId>>::minimum_balance(NATIVE_CURRENCY)
+			);
+			let supply_balance = Currencies::free_balance(quote.currency_id, &AccountId::from(BOB));
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment::<Runtime>>::from(0).validate(
 					&AccountId::from(BOB),
@@ -457,6 +488,10 @@ fn with_fee_call_works(
 					50
 				)
 			);
+			assert_eq!(
+				Currencies::free_balance(quote.currency_id, &AccountId::from(BOB)),
+				supply_balance - quote.amount
+			);
 			if is_aggregated_call {
 				assert!(System::events().iter().any(|r| matches!(
 					r.event,
@@ -486,6 +521,12 @@ fn with_fee_call_works(
 			);
 
 			// Charlie have USD currency.
+			let charlie_quote = TransactionPayment::quote_fee_in_currency(
+				&AccountId::from(CHARLIE),
+				FeeCurrency::Currency(USD_CURRENCY),
+				fee,
+				WithdrawReasons::TRANSACTION_PAYMENT,
+			);
 			assert_ok!(
 				<module_transaction_payment::ChargeTransactionPayment::<Runtime>>::from(0).validate(
 					&AccountId::from(CHARLIE),
@@ -507,5 +548,6 @@ fn with_fee_call_works(
 				to: ausd_acc.clone(),
 				amount,
 			}));
+			assert_eq!(charlie_quote.map(|quote| quote.amount), Some(amount));
 		});
 }
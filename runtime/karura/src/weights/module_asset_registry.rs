@@ -153,4 +153,68 @@ impl<T: frame_system::Config> module_asset_registry::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:0)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetStatuses` (r:0 w:1)
+	// Proof: `AssetRegistry::AssetStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_asset_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1176`
+		//  Estimated: `4641`
+		// Minimum execution time: 17_482 nanoseconds.
+		Weight::from_parts(18_037_000, 4641)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `AssetRegistry::ForeignAssetLocations` (r:1 w:1)
+	// Proof: `AssetRegistry::ForeignAssetLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::LocationToCurrencyIds` (r:1 w:2)
+	// Proof: `AssetRegistry::LocationToCurrencyIds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn migrate_foreign_asset_location() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1263`
+		//  Estimated: `4728`
+		// Minimum execution time: 24_915 nanoseconds.
+		Weight::from_parts(25_708_000, 4728)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `AssetRegistry::ForeignAssetLocations` (r:1 w:0)
+	// Proof: `AssetRegistry::ForeignAssetLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::UnverifiedForeignAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::UnverifiedForeignAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn verify_foreign_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1471`
+		//  Estimated: `4936`
+		// Minimum execution time: 31_604 nanoseconds.
+		Weight::from_parts(32_518_000, 4936)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `AssetRegistry::ForeignAssetLocations` (r:1 w:1)
+	// Proof: `AssetRegistry::ForeignAssetLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::UnverifiedForeignAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::UnverifiedForeignAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::LocationToCurrencyIds` (r:0 w:1)
+	// Proof: `AssetRegistry::LocationToCurrencyIds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:0 w:1)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetStatuses` (r:0 w:1)
+	// Proof: `AssetRegistry::AssetStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_foreign_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1523`
+		//  Estimated: `4936`
+		// Minimum execution time: 44_187 nanoseconds.
+		Weight::from_parts(45_362_000, 4936)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
 }
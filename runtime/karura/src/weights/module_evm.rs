@@ -294,4 +294,118 @@ impl<T: frame_system::Config> module_evm::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Accounts` (r:1 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::ContractUpgrades` (r:1 w:1)
+	// Proof: `EVM::ContractUpgrades` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `EVM::CodeInfos` (r:1 w:1)
+	// Proof: `EVM::CodeInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Codes` (r:0 w:1)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::UpgradeableContracts` (r:1 w:0)
+	// Proof: `EVM::UpgradeableContracts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 61440]`.
+	fn propose_contract_upgrade(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2815`
+		//  Estimated: `15721`
+		// Minimum execution time: 98_412 nanoseconds.
+		Weight::from_parts(97_203_118, 15721)
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(5_341, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Accounts` (r:1 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::ContractUpgrades` (r:1 w:1)
+	// Proof: `EVM::ContractUpgrades` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `EVM::CodeInfos` (r:1 w:1)
+	// Proof: `EVM::CodeInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EvmAccounts::Accounts` (r:1 w:0)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Codes` (r:0 w:1)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_contract_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3102`
+		//  Estimated: `15861`
+		// Minimum execution time: 84_120 nanoseconds.
+		Weight::from_parts(85_932_000, 15861)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `EVM::ContractUpgrades` (r:1 w:1)
+	// Proof: `EVM::ContractUpgrades` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Codes` (r:1 w:2)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::CodeInfos` (r:2 w:2)
+	// Proof: `EVM::CodeInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EvmAccounts::Accounts` (r:2 w:0)
+	// Proof: `EvmAccounts::Accounts` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Accounts` (r:1 w:1)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EVM::ContractStorageSizes` (r:1 w:1)
+	// Proof: `EVM::ContractStorageSizes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::UpgradeableContracts` (r:1 w:0)
+	// Proof: `EVM::UpgradeableContracts` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn apply_contract_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `66391`
+		//  Estimated: `115407`
+		// Minimum execution time: 497_305 nanoseconds.
+		Weight::from_parts(503_618_000, 115407)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `EVM::Accounts` (r:1 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Sponsorships` (r:1 w:1)
+	// Proof: `EVM::Sponsorships` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1716`
+		//  Estimated: `12549`
+		// Minimum execution time: 61_204 nanoseconds.
+		Weight::from_parts(62_517_000, 12549)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `EVM::Accounts` (r:1 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::ContractStorageSizes` (r:1 w:0)
+	// Proof: `EVM::ContractStorageSizes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:1 w:1)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EVM::InsufficientStorageDeposits` (r:0 w:1)
+	// Proof: `EVM::InsufficientStorageDeposits` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn top_up_storage_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2946`
+		//  Estimated: `14382`
+		// Minimum execution time: 72_318 nanoseconds.
+		Weight::from_parts(73_904_000, 14382)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -276,4 +276,31 @@ impl<T: frame_system::Config> module_homa::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: Homa ToBondPool (r:1 w:1)
+	// Proof Skipped: Homa ToBondPool (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Homa TotalStakingBonded (r:1 w:0)
+	// Proof Skipped: Homa TotalStakingBonded (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Homa TotalVoidLiquid (r:1 w:0)
+	// Proof Skipped: Homa TotalVoidLiquid (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Homa FastMatchFeeRate (r:1 w:0)
+	// Proof Skipped: Homa FastMatchFeeRate (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Proof: Dex LiquidityPool (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	fn instant_redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3912`
+		//  Estimated: `14211`
+		// Minimum execution time: 139_210 nanoseconds.
+		Weight::from_parts(141_843_000, 14211)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
 }
@@ -37,8 +37,8 @@ use parity_scale_codec::{Decode, Encode};
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use primitives::evm::is_system_contract;
 use runtime_common::{
-	local_currency_location, native_currency_location, AcalaDropAssets, EnsureRootOrHalfGeneralCouncil,
-	EnsureRootOrThreeFourthsGeneralCouncil,
+	local_currency_location, native_currency_location, AcalaDropAssets, AssetStatusTransactor,
+	BuyWeightRateOfNativeCurrency, EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil,
 };
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 use xcm_builder::{EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, SignedToAccountId32};
@@ -151,6 +151,15 @@ pub type Trader = (
 	FixedRateOfFungible<TaiPerSecond, ToTreasury>,
 );
 
+/// The rates of buying weight, used to estimate the destination fee of XTokens transfers.
+pub type BuyWeightRates = (
+	BuyWeightRateOfNativeCurrency<CurrencyIdConvert, GetNativeCurrencyId>,
+	BuyWeightRateOfTransactionFeePool<Runtime, CurrencyIdConvert>,
+	BuyWeightRateOfForeignAsset<Runtime>,
+	BuyWeightRateOfErc20<Runtime>,
+	BuyWeightRateOfStableAsset<Runtime>,
+);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
@@ -301,15 +310,19 @@ impl orml_xtokens::Config for Runtime {
 
 pub type LocalAssetTransactor = (
 	XNFT,
-	MultiCurrencyAdapter<
-		Currencies,
-		UnknownTokens,
-		IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-		AccountId,
-		LocationToAccountId,
-		CurrencyId,
+	AssetStatusTransactor<
+		MultiCurrencyAdapter<
+			Currencies,
+			UnknownTokens,
+			IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+			AccountId,
+			LocationToAccountId,
+			CurrencyId,
+			CurrencyIdConvert,
+			DepositToAlternative<KaruraTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+		>,
 		CurrencyIdConvert,
-		DepositToAlternative<KaruraTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+		AssetIdMaps<Runtime>,
 	>,
 );
 
@@ -24,7 +24,7 @@ use frame_system::RawOrigin;
 use module_evm::EvmAddress;
 use module_support::AddressMapping;
 use orml_benchmarking::runtime_benchmarks;
-use primitives::currency::AssetMetadata;
+use primitives::currency::{AssetIds, AssetMetadata, AssetStatus};
 use sp_std::{boxed::Box, str::FromStr, vec};
 use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
@@ -145,6 +145,82 @@ runtime_benchmarks! {
 
 		AssetRegistry::register_native_asset(RawOrigin::Root.into(), currency_id, Box::new(asset_metadata.clone()))?;
 	}: _(RawOrigin::Root, currency_id, Box::new(asset_metadata))
+
+	set_asset_status {
+		let currency_id = CurrencyId::LiquidCrowdloan(0);
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		AssetRegistry::register_native_asset(RawOrigin::Root.into(), currency_id, Box::new(asset_metadata))?;
+	}: _(RawOrigin::Root, AssetIds::NativeAssetId(currency_id), AssetStatus::Frozen)
+	verify {
+		assert_eq!(AssetRegistry::asset_statuses(AssetIds::NativeAssetId(currency_id)), AssetStatus::Frozen);
+	}
+
+	migrate_foreign_asset_location {
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let new_location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(2000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		AssetRegistry::register_foreign_asset(RawOrigin::Root.into(), Box::new(location), Box::new(asset_metadata))?;
+	}: _(RawOrigin::Root, 0, Box::new(new_location.clone()))
+	verify {
+		assert_eq!(AssetRegistry::foreign_asset_locations(0), Some(new_location.try_into().unwrap()));
+	}
+
+	verify_foreign_asset {
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		set_balance(NATIVE, &alice(), 1_000_000 * dollar(NATIVE));
+		AssetRegistry::register_unverified_foreign_asset(RawOrigin::Signed(alice()).into(), Box::new(location), Box::new(asset_metadata))?;
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert_eq!(AssetRegistry::unverified_foreign_assets(0), None);
+	}
+
+	reject_foreign_asset {
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+
+		set_balance(NATIVE, &alice(), 1_000_000 * dollar(NATIVE));
+		AssetRegistry::register_unverified_foreign_asset(RawOrigin::Signed(alice()).into(), Box::new(location), Box::new(asset_metadata))?;
+		AssetRegistry::set_asset_status(RawOrigin::Root.into(), AssetIds::ForeignAssetId(0), AssetStatus::Frozen)?;
+	}: _(RawOrigin::Root, 0)
+	verify {
+		assert_eq!(AssetRegistry::foreign_asset_locations(0), None);
+	}
 }
 
 #[cfg(test)]
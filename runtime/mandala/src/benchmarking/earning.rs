@@ -18,13 +18,17 @@
 
 use super::utils::{dollar, set_balance, NATIVE};
 use crate::{
-	AccountId, DispatchResult, Earning, Get, NativeTokenExistentialDeposit, Parameters, Runtime, RuntimeOrigin,
-	RuntimeParameters, System,
+	AccountId, Currencies, DispatchResult, Earning, Get, Incentives, NativeTokenExistentialDeposit, Parameters,
+	Rewards, Runtime, RuntimeOrigin, RuntimeParameters, System,
 };
 use frame_benchmarking::whitelisted_caller;
 use frame_system::RawOrigin;
+use module_support::PoolId;
 use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use primitives::{CurrencyId, DexShare, TokenSymbol};
 use sp_runtime::Permill;
+use sp_std::prelude::*;
 
 fn make_max_unbonding_chunk(who: AccountId) -> DispatchResult {
 	System::set_block_number(0);
@@ -39,6 +43,29 @@ fn make_max_unbonding_chunk(who: AccountId) -> DispatchResult {
 	Ok(())
 }
 
+fn dex_votes(n: u32) -> Vec<(PoolId, Permill)> {
+	(0..n)
+		.map(|i| {
+			(
+				PoolId::Dex(CurrencyId::DexShare(
+					DexShare::ForeignAsset(i as u16),
+					DexShare::Token(TokenSymbol::AUSD),
+				)),
+				Permill::from_rational(1, n),
+			)
+		})
+		.collect()
+}
+
+fn make_ve_lock_with_max_votes(who: AccountId) -> DispatchResult {
+	System::set_block_number(1);
+	set_balance(NATIVE, &who, 100 * dollar(NATIVE));
+	let max_duration = <Runtime as module_earning::Config>::MaxVeLockDuration::get();
+	Earning::ve_lock(RuntimeOrigin::signed(who.clone()), 10 * dollar(NATIVE), max_duration)?;
+	let max_votes: u32 = <Runtime as module_earning::Config>::MaxDexVotes::get();
+	Earning::vote_dex_incentives(RuntimeOrigin::signed(who), dex_votes(max_votes))
+}
+
 runtime_benchmarks! {
 	{Runtime, module_earning}
 
@@ -74,6 +101,68 @@ runtime_benchmarks! {
 		// large number to unlock all chunks
 		System::set_block_number(1_000_000);
 	}: _(RawOrigin::Signed(caller))
+
+	set_auto_compound {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, dollar(NATIVE));
+		Earning::bond(RuntimeOrigin::signed(caller.clone()), dollar(NATIVE))?;
+	}: _(RawOrigin::Signed(caller), true)
+
+	auto_compound {
+		let caller: AccountId = whitelisted_caller();
+		let pool_id = PoolId::Earning(NATIVE);
+		set_balance(NATIVE, &caller, 10 * dollar(NATIVE));
+		Parameters::set_parameter(
+			RawOrigin::Root.into(),
+			RuntimeParameters::Earning(module_earning::Parameters::AutoCompoundFee(module_earning::AutoCompoundFee, Some(Permill::from_percent(1))))
+		)?;
+		Earning::bond(RuntimeOrigin::signed(caller.clone()), dollar(NATIVE))?;
+		Earning::set_auto_compound(RuntimeOrigin::signed(caller.clone()), true)?;
+		Currencies::deposit(NATIVE, &Incentives::account_id(), 80 * dollar(NATIVE))?;
+		Rewards::accumulate_reward(&pool_id, NATIVE, 80 * dollar(NATIVE))?;
+	}: {
+		Earning::do_auto_compound(&caller)?;
+	}
+
+	ve_lock {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+		let max_duration = <Runtime as module_earning::Config>::MaxVeLockDuration::get();
+	}: _(RawOrigin::Signed(caller), 10 * dollar(NATIVE), max_duration)
+
+	ve_increase_amount {
+		let caller: AccountId = whitelisted_caller();
+		make_ve_lock_with_max_votes(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 10 * dollar(NATIVE))
+
+	ve_extend {
+		let caller: AccountId = whitelisted_caller();
+		make_ve_lock_with_max_votes(caller.clone())?;
+		System::set_block_number(2);
+		let max_duration = <Runtime as module_earning::Config>::MaxVeLockDuration::get();
+	}: _(RawOrigin::Signed(caller), max_duration)
+
+	ve_withdraw {
+		let caller: AccountId = whitelisted_caller();
+		Parameters::set_parameter(
+			RawOrigin::Root.into(),
+			RuntimeParameters::Earning(module_earning::Parameters::VeEarlyExitPenalty(module_earning::VeEarlyExitPenalty, Some(Permill::from_percent(10))))
+		)?;
+		make_ve_lock_with_max_votes(caller.clone())?;
+		System::set_block_number(2);
+	}: _(RawOrigin::Signed(caller))
+
+	ve_checkpoint {
+		let caller: AccountId = whitelisted_caller();
+		make_ve_lock_with_max_votes(caller.clone())?;
+		System::set_block_number(2);
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone())
+
+	vote_dex_incentives {
+		let c in 0 .. <Runtime as module_earning::Config>::MaxDexVotes::get();
+		let caller: AccountId = whitelisted_caller();
+		make_ve_lock_with_max_votes(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), dex_votes(c))
 }
 
 #[cfg(test)]
@@ -16,9 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, EvmAccounts, Runtime, RuntimeEvent, RuntimeOrigin, System, EVM};
+use crate::{AccountId, Balances, EvmAccounts, Runtime, RuntimeEvent, RuntimeOrigin, System, EVM};
 
 use super::utils::{dollar, set_balance, NATIVE};
+use frame_support::traits::NamedReservableCurrency;
 use frame_system::RawOrigin;
 use module_evm::MaxCodeSize;
 use module_support::AddressMapping;
@@ -244,6 +245,83 @@ runtime_benchmarks! {
 
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	propose_contract_upgrade {
+		let c in 0..MaxCodeSize::get();
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::publish_upgradeable_contract(RuntimeOrigin::signed(alice_account_id()), contract).map_err(|e| e.error)?;
+
+		let new_contract = vec![0; c as usize];
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(alice_account_id()), contract, new_contract)
+
+	cancel_contract_upgrade {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::propose_contract_upgrade(
+			RuntimeOrigin::signed(alice_account_id()),
+			contract,
+			vec![0; MaxCodeSize::get() as usize],
+		)
+		.map_err(|e| e.error)?;
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	apply_contract_upgrade {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		set_balance(NATIVE, &bob_account_id(), 1_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		EVM::publish_upgradeable_contract(RuntimeOrigin::signed(alice_account_id()), contract).map_err(|e| e.error)?;
+		EVM::propose_contract_upgrade(
+			RuntimeOrigin::signed(alice_account_id()),
+			contract,
+			vec![0; MaxCodeSize::get() as usize],
+		)
+		.map_err(|e| e.error)?;
+		System::set_block_number(
+			System::block_number() + <Runtime as module_evm::Config>::ContractUpgradeDelay::get(),
+		);
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(bob_account_id()), contract)
+
+	set_sponsorship {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		let bob_address = EvmAccounts::eth_address(&bob());
+
+		whitelist_account!(alice_account);
+	}: _(
+		RawOrigin::Signed(alice_account_id()),
+		contract,
+		bob_address,
+		Some(module_evm::SponsorQuota { gas: 1_000_000, storage: 1_000 })
+	)
+
+	top_up_storage_deposit {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		let contract_account = <Runtime as module_evm::Config>::AddressMapping::get_account_id(&contract);
+		<Balances as NamedReservableCurrency<_>>::unreserve_all_named(
+			&module_evm::RESERVE_ID_STORAGE_DEPOSIT,
+			&contract_account,
+		);
+
+		whitelist_account!(alice_account);
+	}: _(RawOrigin::Signed(alice_account_id()), contract)
 }
 
 #[cfg(test)]
@@ -54,6 +54,18 @@ runtime_benchmarks! {
 	claim_default_account {
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller))
+
+	link_address {
+		let caller: AccountId = whitelisted_caller();
+		EvmAccounts::claim_account(RawOrigin::Signed(caller.clone()).into(), EvmAccounts::eth_address(&alice()), EvmAccounts::eth_sign(&alice(), &caller))?;
+		set_balance(NATIVE, &bob_account_id(), 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller.clone()), EvmAccounts::eth_address(&bob()), EvmAccounts::eth_sign_link(&bob(), &caller, 0))
+
+	unlink_address {
+		let caller: AccountId = whitelisted_caller();
+		EvmAccounts::claim_account(RawOrigin::Signed(caller.clone()).into(), EvmAccounts::eth_address(&alice()), EvmAccounts::eth_sign(&alice(), &caller))?;
+		EvmAccounts::link_address(RawOrigin::Signed(caller.clone()).into(), EvmAccounts::eth_address(&bob()), EvmAccounts::eth_sign_link(&bob(), &caller, 0))?;
+	}: _(RawOrigin::Signed(caller), EvmAccounts::eth_address(&bob()))
 }
 
 #[cfg(test)]
@@ -18,7 +18,7 @@
 
 use crate::{AccountId, ActiveSubAccountsIndexList, Balance, Currencies, Homa, Rate, RelaychainDataProvider, Runtime};
 
-use super::utils::{set_balance, LIQUID, STAKING};
+use super::utils::{inject_liquidity, set_balance, LIQUID, STAKING};
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
@@ -144,6 +144,28 @@ runtime_benchmarks! {
 	}: _(RawOrigin::Root, updates)
 
 	reset_current_era {}: _(RawOrigin::Root, 1)
+
+	instant_redeem {
+		let caller: AccountId = whitelisted_caller();
+		let minter: AccountId = account("minter", 0, SEED);
+		let maker: AccountId = account("maker", 0, SEED);
+		let mint_amount = 1_000_000_000_000;
+
+		set_balance(STAKING, &minter, mint_amount * 2);
+		Homa::update_homa_params(
+			RawOrigin::Root.into(),
+			Some(mint_amount * 10),
+			Some(Rate::saturating_from_rational(1, 10000)),
+			None,
+			None,
+		)?;
+		Homa::mint(RawOrigin::Signed(minter).into(), mint_amount)?;
+		inject_liquidity(maker, LIQUID, STAKING, mint_amount * 100, mint_amount * 10, false)?;
+
+		// the redeem amount exceeds the limit of fast match, the remainder will be swapped on DEX.
+		let redeem_amount = mint_amount * 20;
+		set_balance(LIQUID, &caller, redeem_amount);
+	}: _(RawOrigin::Signed(caller), redeem_amount, 0)
 }
 
 #[cfg(test)]
@@ -22,13 +22,16 @@ use crate::{
 	RuntimeEvent, RuntimeOrigin, StableAsset, System, TransactionPayment, TreasuryPalletId,
 };
 use frame_benchmarking::{account, whitelisted_caller};
-use frame_support::{assert_ok, traits::OnFinalize};
+use frame_support::{
+	assert_ok,
+	traits::{NamedReservableCurrency, OnFinalize},
+};
 use frame_system::RawOrigin;
 use module_support::{AggregatedSwapPath, DEXManager, Ratio, SwapLimit};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 use primitives::currency::AssetMetadata;
-use sp_runtime::traits::{AccountIdConversion, One};
+use sp_runtime::traits::{AccountIdConversion, One, Zero};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -224,6 +227,46 @@ runtime_benchmarks! {
 		];
 	}: _(RawOrigin::Signed(caller.clone()), fee_aggregated_path, call)
 
+	with_fee_sponsor {
+		System::set_block_number(1);
+
+		let caller: AccountId = whitelisted_caller();
+		let sponsor: AccountId = account("sponsor", 0, SEED);
+		let call = Box::new(frame_system::Call::remark { remark: vec![] }.into());
+	}: _(RawOrigin::Signed(caller), sponsor, call)
+
+	fund_sponsor_budget {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller.clone()), 10 * dollar(NATIVE))
+	verify {
+		assert_eq!(
+			<Runtime as module_transaction_payment::Config>::Currency::reserved_balance_named(&module_transaction_payment::SPONSOR_ID, &caller),
+			10 * dollar(NATIVE)
+		);
+	}
+
+	withdraw_sponsor_budget {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+		TransactionPayment::fund_sponsor_budget(RuntimeOrigin::signed(caller.clone()), 10 * dollar(NATIVE))?;
+	}: _(RawOrigin::Signed(caller.clone()), 10 * dollar(NATIVE))
+	verify {
+		assert!(<Runtime as module_transaction_payment::Config>::Currency::reserved_balance_named(&module_transaction_payment::SPONSOR_ID, &caller).is_zero());
+	}
+
+	set_sponsored_call {
+		let caller: AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), 0, Some(0), true)
+
+	set_sponsor_quota {
+		let caller: AccountId = whitelisted_caller();
+		let user: AccountId = account("user", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), user.clone(), Some(dollar(NATIVE)))
+	verify {
+		assert_eq!(TransactionPayment::sponsor_quotas(&caller, &user), Some(dollar(NATIVE)));
+	}
+
 	on_finalize {
 	}: {
 		TransactionPayment::on_finalize(System::block_number());
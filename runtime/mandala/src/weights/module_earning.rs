@@ -129,4 +129,192 @@ impl<T: frame_system::Config> module_earning::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: Earning Ledger (r:1 w:0)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning AutoCompound (r:0 w:1)
+	// Proof Skipped: Earning AutoCompound (max_values: None, max_size: None, mode: Measured)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2322`
+		//  Estimated: `5787`
+		// Minimum execution time: 16_904 nanoseconds.
+		Weight::from_parts(17_515_000, 5787)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Incentives PendingMultiRewards (r:1 w:1)
+	// Proof Skipped: Incentives PendingMultiRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives ClaimRewardDeductionRates (r:1 w:0)
+	// Proof Skipped: Incentives ClaimRewardDeductionRates (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Parameters Parameters (r:1 w:0)
+	// Proof: Parameters Parameters (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Earning Ledger (r:1 w:1)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	fn auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3603`
+		//  Estimated: `32108`
+		// Minimum execution time: 128_716 nanoseconds.
+		Weight::from_parts(131_904_000, 32108)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: Earning VeLocks (r:1 w:1)
+	// Proof Skipped: Earning VeLocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning Ledger (r:1 w:0)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVotes (r:1 w:0)
+	// Proof Skipped: Earning DexVotes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Balances Freezes (r:1 w:0)
+	// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn ve_lock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2642`
+		//  Estimated: `6107`
+		// Minimum execution time: 46_083 nanoseconds.
+		Weight::from_parts(47_561_000, 6107)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Earning VeLocks (r:1 w:1)
+	// Proof Skipped: Earning VeLocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning Ledger (r:1 w:0)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVotes (r:1 w:0)
+	// Proof Skipped: Earning DexVotes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Balances Freezes (r:1 w:0)
+	// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn ve_increase_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2734`
+		//  Estimated: `6199`
+		// Minimum execution time: 48_215 nanoseconds.
+		Weight::from_parts(49_770_000, 6199)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Earning VeLocks (r:1 w:1)
+	// Proof Skipped: Earning VeLocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning Ledger (r:1 w:0)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVotes (r:1 w:0)
+	// Proof Skipped: Earning DexVotes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Balances Freezes (r:1 w:0)
+	// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn ve_extend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2734`
+		//  Estimated: `6199`
+		// Minimum execution time: 46_992 nanoseconds.
+		Weight::from_parts(48_404_000, 6199)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Earning VeLocks (r:1 w:1)
+	// Proof Skipped: Earning VeLocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Parameters Parameters (r:1 w:0)
+	// Proof: Parameters Parameters (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVotes (r:1 w:1)
+	// Proof Skipped: Earning DexVotes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning Ledger (r:1 w:0)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Balances Freezes (r:1 w:0)
+	// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn ve_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2952`
+		//  Estimated: `6417`
+		// Minimum execution time: 63_187 nanoseconds.
+		Weight::from_parts(64_935_000, 6417)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: Earning VeLocks (r:1 w:1)
+	// Proof Skipped: Earning VeLocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning Ledger (r:1 w:0)
+	// Proof Skipped: Earning Ledger (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVotes (r:1 w:0)
+	// Proof Skipped: Earning DexVotes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Balances Freezes (r:1 w:0)
+	// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn ve_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2734`
+		//  Estimated: `6199`
+		// Minimum execution time: 41_650 nanoseconds.
+		Weight::from_parts(42_918_000, 6199)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Earning VeLocks (r:1 w:0)
+	// Proof Skipped: Earning VeLocks (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVotes (r:1 w:1)
+	// Proof Skipped: Earning DexVotes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning DexVoteWeights (r:10 w:10)
+	// Proof Skipped: Earning DexVoteWeights (max_values: None, max_size: None, mode: Measured)
+	// Storage: Earning TotalDexVoteWeight (r:1 w:1)
+	// Proof Skipped: Earning TotalDexVoteWeight (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `c` is `[0, 10]`.
+	fn vote_dex_incentives(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2516 + c * (52 ±0)`
+		//  Estimated: `5981 + c * (2527 ±0)`
+		// Minimum execution time: 14_385 nanoseconds.
+		Weight::from_parts(15_407_826, 5981)
+			// Standard Error: 4_820
+			.saturating_add(Weight::from_parts(4_117_302, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(c.into()))
+	}
 }
@@ -152,4 +152,57 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn with_fee_sponsor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `666`
+		//  Estimated: `0`
+		// Minimum execution time: 9_312 nanoseconds.
+		Weight::from_parts(9_687_000, 0)
+	}
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn fund_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1438`
+		//  Estimated: `3633`
+		// Minimum execution time: 31_472 nanoseconds.
+		Weight::from_parts(32_106_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1574`
+		//  Estimated: `3633`
+		// Minimum execution time: 32_058 nanoseconds.
+		Weight::from_parts(32_811_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: TransactionPayment SponsoredCalls (r:0 w:1)
+	// Proof: TransactionPayment SponsoredCalls (max_values: None, max_size: Some(51), added: 2526, mode: MaxEncodedLen)
+	fn set_sponsored_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_874 nanoseconds.
+		Weight::from_parts(13_302_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: TransactionPayment SponsorQuotas (r:0 w:1)
+	// Proof: TransactionPayment SponsorQuotas (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn set_sponsor_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_105 nanoseconds.
+		Weight::from_parts(13_569_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -39,7 +39,8 @@ use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use primitives::evm::is_system_contract;
 use runtime_common::{
 	local_currency_location, native_currency_location, xcm_impl::AccountKey20Aliases, AcalaDropAssets,
-	EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
+	AssetStatusTransactor, BuyWeightRateOfNativeCurrency, EnsureRootOrHalfGeneralCouncil,
+	EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
 };
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 pub use xcm_builder::{
@@ -145,6 +146,15 @@ pub type Trader = (
 	FixedRateOfAsset<BaseRate, ToTreasury, BuyWeightRateOfStableAsset<Runtime>>,
 );
 
+/// The rates of buying weight, used to estimate the destination fee of XTokens transfers.
+pub type BuyWeightRates = (
+	BuyWeightRateOfNativeCurrency<CurrencyIdConvert, GetNativeCurrencyId>,
+	BuyWeightRateOfTransactionFeePool<Runtime, CurrencyIdConvert>,
+	BuyWeightRateOfForeignAsset<Runtime>,
+	BuyWeightRateOfErc20<Runtime>,
+	BuyWeightRateOfStableAsset<Runtime>,
+);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
@@ -259,15 +269,19 @@ impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type ExecuteOverweightOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
-pub type LocalAssetTransactor = MultiCurrencyAdapter<
-	Currencies,
-	UnknownTokens,
-	IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-	AccountId,
-	LocationToAccountId,
-	CurrencyId,
+pub type LocalAssetTransactor = AssetStatusTransactor<
+	MultiCurrencyAdapter<
+		Currencies,
+		UnknownTokens,
+		IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+		AccountId,
+		LocationToAccountId,
+		CurrencyId,
+		CurrencyIdConvert,
+		DepositToAlternative<TreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	>,
 	CurrencyIdConvert,
-	DepositToAlternative<TreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	AssetIdMaps<Runtime>,
 >;
 
 pub struct CurrencyIdConvert;